@@ -0,0 +1,76 @@
+//! Generate the embedded services tables from `data/nmap-services`
+
+use std::{collections::HashSet, env, fs, path::Path};
+
+fn main() {
+    let src = "data/nmap-services";
+    println!("cargo:rerun-if-changed={src}");
+
+    let content = fs::read_to_string(src).unwrap();
+    let mut services = vec![];
+    for line in content.lines() {
+        let line = line.split('#').next().unwrap();
+        let mut fields = line.split_whitespace();
+        let (Some(name), Some(port_protocol)) = (fields.next(), fields.next()) else {
+            continue;
+        };
+        let frequency: f64 = fields.next().map(|v| v.parse().unwrap()).unwrap_or(0.0);
+        let (port, protocol) = port_protocol.split_once('/').unwrap();
+        services.push((
+            protocol.to_string(),
+            port.parse::<u16>().unwrap(),
+            name.to_string(),
+            frequency,
+        ));
+    }
+    // Stable, so the order of the data file is kept for ports of the same frequency
+    services.sort_by(|a, b| b.3.total_cmp(&a.3));
+
+    let code = ["tcp", "udp"]
+        .iter()
+        .map(|protocol| {
+            let services = services
+                .iter()
+                .filter(|v| v.0 == *protocol)
+                .map(|(_, port, name, frequency)| (*port, name.as_str(), *frequency))
+                .collect::<Vec<_>>();
+            table(protocol, &services)
+        })
+        .collect::<String>();
+    let dest = Path::new(&env::var("OUT_DIR").unwrap()).join("services.rs");
+    fs::write(dest, code).unwrap();
+}
+
+/// Code of the tables of a protocol, from its services ordered by open frequency
+fn table(protocol: &str, services: &[(u16, &str, f64)]) -> String {
+    let mut seen = HashSet::new();
+    let mut map = phf_codegen::Map::new();
+    let mut top_ports = vec![];
+    let mut frequencies = vec![];
+    for (port, name, frequency) in services {
+        if seen.insert(*port) {
+            map.entry(*port, &format!("{name:?}"));
+            top_ports.push(port.to_string());
+            frequencies.push(format!("{frequency:?}"));
+        }
+    }
+    // The data file may come without open frequencies
+    if services.iter().all(|(_, _, frequency)| *frequency == 0.0) {
+        frequencies.clear();
+    }
+    let upper = protocol.to_uppercase();
+    format!(
+        "/// Service names of {protocol} ports\n\
+        static {upper}_SERVICES: phf::Map<u16, &'static str> = {};\n\n\
+        /// {} ports ordered by open frequency\n\
+        static {upper}_TOP_PORTS: [u16; {}] = [{}];\n\n\
+        /// Open frequencies of `{upper}_TOP_PORTS`, empty if unknown\n\
+        static {upper}_FREQUENCIES: [f64; {}] = [{}];\n\n",
+        map.build(),
+        upper[..1].to_string() + &protocol[1..],
+        top_ports.len(),
+        top_ports.join(", "),
+        frequencies.len(),
+        frequencies.join(", ")
+    )
+}
@@ -0,0 +1,151 @@
+use std::{collections::HashMap, fmt, fs, net::IpAddr, path::Path, sync::Mutex};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    addresses::Host,
+    scanner::{OpenPort, Status},
+};
+
+/// Open ports of a previous scan, loaded from its `--json` output
+#[derive(Debug)]
+pub struct Baseline {
+    /// Services of open ports by host and port
+    open: HashMap<(String, u16), String>,
+    changes: Mutex<Vec<Change>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BaselineFile {
+    open: Vec<OpenPort>,
+}
+
+/// A port whose state differs from the baseline
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Change {
+    pub host: String,
+    pub ip: IpAddr,
+    pub port: u16,
+    pub kind: ChangeKind,
+    pub service: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_service: Option<String>,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ChangeKind {
+    Opened,
+    /// Closed or filtered
+    Closed,
+    ServiceChanged,
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (host, port, service) = (&self.host, self.port, &self.service);
+        match self.kind {
+            ChangeKind::Opened => write!(f, "+ {host} {port} {service} opened"),
+            ChangeKind::Closed => write!(f, "- {host} {port} {service} {}", self.status),
+            ChangeKind::ServiceChanged => write!(
+                f,
+                "~ {host} {port} {service} was {}",
+                self.previous_service.as_deref().unwrap_or_default()
+            ),
+        }
+    }
+}
+
+impl Baseline {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read baseline '{}', {err}", path.display()))?;
+        Self::parse(&content).map_err(|err| format!("Invalid baseline '{}', {err}", path.display()))
+    }
+
+    fn parse(content: &str) -> Result<Self, String> {
+        let file: BaselineFile = serde_json::from_str(content).map_err(|err| err.to_string())?;
+        Ok(Self::new(file.open))
+    }
+
+    pub fn new(open_ports: Vec<OpenPort>) -> Self {
+        let open = open_ports
+            .into_iter()
+            .map(|v| ((v.host, v.port), v.service))
+            .collect();
+        Self {
+            open,
+            changes: Mutex::new(vec![]),
+        }
+    }
+
+    /// Compare the result of probing `port` of `host` with the baseline
+    ///
+    /// Ports that failed to be probed are not reported as closed.
+    pub fn record(&self, host: &Host, port: u16, status: &Status, service: &str) {
+        let previous = self.open.get(&(host.label.clone(), port));
+        let (kind, previous_service) = match (status, previous) {
+            (Status::Open, None) => (ChangeKind::Opened, None),
+            (Status::Open, Some(v)) if v != service => (ChangeKind::ServiceChanged, Some(v)),
+            (Status::Closed | Status::Filtered, Some(_)) => (ChangeKind::Closed, None),
+            _ => return,
+        };
+        self.changes.lock().unwrap().push(Change {
+            host: host.label.clone(),
+            ip: host.ip,
+            port,
+            kind,
+            service: service.to_string(),
+            previous_service: previous_service.cloned(),
+            status: status.to_string(),
+        });
+    }
+
+    pub fn changes(&self) -> Vec<Change> {
+        let mut changes = self.changes.lock().unwrap().clone();
+        changes.sort();
+        changes
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_baseline() {
+        let baseline = Baseline::parse(
+            r#"{
+  "open": [
+    { "host": "web1", "ip": "10.0.0.5", "port": 22, "service": "ssh", "category": "private" },
+    { "host": "web1", "ip": "10.0.0.5", "port": 80, "service": "http", "category": "private" },
+    { "host": "web1", "ip": "10.0.0.5", "port": 8080, "service": "http-proxy", "category": "private" },
+    { "host": "web2", "ip": "10.0.0.6", "port": 22, "service": "ssh", "category": "private" }
+  ],
+  "summary": {}
+}"#,
+        )
+        .unwrap();
+        let web1 = Host::fixture("10.0.0.5", "web1");
+        baseline.record(&web1, 22, &Status::Filtered, "ssh");
+        baseline.record(&web1, 80, &Status::Open, "http");
+        baseline.record(&web1, 443, &Status::Open, "https");
+        baseline.record(&web1, 8080, &Status::Open, "http-alt");
+        baseline.record(&web1, 8443, &Status::Closed, "https-alt");
+        let changes = baseline
+            .changes()
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            [
+                "- web1 22 ssh filtered",
+                "+ web1 443 https opened",
+                "~ web1 8080 http-alt was http-proxy",
+            ]
+        );
+        assert!(Baseline::parse("10.0.0.5 22 ssh private").is_err());
+    }
+}
@@ -0,0 +1,236 @@
+use std::{
+    collections::BTreeSet,
+    fs,
+    io::{self, Write},
+    net::IpAddr,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{addresses::Target, scanner::OpenPort, summary::Summary};
+
+/// FNV-1a parameters
+const FNV_OFFSET: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Progress and results of a scan, saved to resume it
+///
+/// Probes are numbered in the order of targets, their hosts and ports.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Checkpoint {
+    /// Targets, target files and ports as given, for reference
+    pub targets: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<PathBuf>,
+    pub ports: String,
+    /// Fingerprint of the probe sequence, see `fingerprint`
+    #[serde(default)]
+    pub fingerprint: u64,
+    /// Probes before it are done
+    pub next: u64,
+    /// Done probes after `next`
+    pub done: Vec<u64>,
+    /// Totals of the done probes, hosts are not counted
+    pub summary: Summary,
+    pub up_hosts: Vec<(String, IpAddr)>,
+    pub open_ports: Vec<OpenPort>,
+}
+
+impl Checkpoint {
+    /// Checkpoint of a scan that has not started
+    pub fn new(targets: Vec<String>, files: Vec<PathBuf>, ports: String, fingerprint: u64) -> Self {
+        Self {
+            targets,
+            files,
+            ports,
+            fingerprint,
+            ..Default::default()
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read checkpoint '{}', {err}", path.display()))?;
+        serde_json::from_str(&content)
+            .map_err(|err| format!("Invalid checkpoint '{}', {err}", path.display()))
+    }
+
+    /// Write to a temporary file first so an interrupted write keeps the previous checkpoint
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        fs::write(&tmp, serde_json::to_vec(self)?)?;
+        fs::rename(&tmp, path)
+    }
+
+    /// Write to `path` unless it exists
+    pub fn save_new(&self, path: &Path) -> io::Result<()> {
+        let file = fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(path)?;
+        let mut writer = io::BufWriter::new(file);
+        serde_json::to_writer(&mut writer, self)?;
+        writer.flush()
+    }
+
+    /// Whether it is a checkpoint of a scan of the same probe sequence
+    pub fn is_scan_of(&self, other: &Checkpoint) -> bool {
+        self.fingerprint == other.fingerprint
+    }
+
+    /// Number of done probes
+    pub fn done_count(&self) -> u64 {
+        self.next + self.done.len() as u64
+    }
+}
+
+/// Fingerprint of the probes of `targets` on `ports`, in the order they are numbered
+///
+/// Targets read from files are included, so changes of their content are detected.
+pub fn fingerprint(targets: &[Target], ports: &[u16]) -> u64 {
+    let mut hasher = Fnv(FNV_OFFSET);
+    hasher.write_ports(ports);
+    for target in targets {
+        let label = target.addr.label();
+        hasher.write(&(label.len() as u64).to_le_bytes());
+        hasher.write(label.as_bytes());
+        match &target.ports {
+            Some(ports) => {
+                hasher.write(&[1]);
+                hasher.write_ports(ports);
+            }
+            None => hasher.write(&[0]),
+        }
+    }
+    hasher.0
+}
+
+/// FNV-1a hasher, unlike `DefaultHasher` its hashes are the same for every build
+struct Fnv(u64);
+
+impl Fnv {
+    fn write(&mut self, bytes: &[u8]) {
+        for v in bytes {
+            self.0 = (self.0 ^ *v as u64).wrapping_mul(FNV_PRIME);
+        }
+    }
+
+    fn write_ports(&mut self, ports: &[u16]) {
+        self.write(&(ports.len() as u64).to_le_bytes());
+        for port in ports {
+            self.write(&port.to_le_bytes());
+        }
+    }
+}
+
+/// Done probes, by a low-water mark and the done probes after it
+#[derive(Debug, Default)]
+pub struct Tracker {
+    next: u64,
+    done: BTreeSet<u64>,
+}
+
+impl Tracker {
+    pub fn new(next: u64, done: &[u64]) -> Self {
+        Self {
+            next,
+            done: done.iter().copied().collect(),
+        }
+    }
+
+    pub fn is_done(&self, index: u64) -> bool {
+        index < self.next || self.done.contains(&index)
+    }
+
+    pub fn complete(&mut self, index: u64) {
+        if index != self.next {
+            self.done.insert(index);
+            return;
+        }
+        self.next += 1;
+        while self.done.remove(&self.next) {
+            self.next += 1;
+        }
+    }
+
+    pub fn position(&self) -> (u64, Vec<u64>) {
+        (self.next, self.done.iter().copied().collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::addresses::parse_targets;
+
+    fn targets(addresses: &[&str]) -> Vec<Target> {
+        parse_targets(&addresses.iter().map(|v| v.to_string()).collect::<Vec<_>>()).unwrap()
+    }
+
+    #[test]
+    fn test_tracker() {
+        let mut tracker = Tracker::new(2, &[4]);
+        assert!(tracker.is_done(1));
+        assert!(!tracker.is_done(2));
+        assert!(tracker.is_done(4));
+        tracker.complete(3);
+        assert_eq!(tracker.position(), (2, vec![3, 4]));
+        tracker.complete(2);
+        assert_eq!(tracker.position(), (5, vec![]));
+        tracker.complete(7);
+        assert_eq!(tracker.position(), (5, vec![7]));
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        let web = targets(&["10.0.0.0/24", "web1:443"]);
+        assert_eq!(fingerprint(&web, &[22, 80]), fingerprint(&web, &[22, 80]));
+        // Probes are numbered in the order of ports, not by their value
+        assert_ne!(fingerprint(&web, &[22, 80]), fingerprint(&web, &[80, 22]));
+        assert_ne!(
+            fingerprint(&web, &[22, 80]),
+            fingerprint(&targets(&["10.0.0.0/24", "web1:8443"]), &[22, 80])
+        );
+        assert_ne!(
+            fingerprint(&web, &[22, 80]),
+            fingerprint(&targets(&["web1:443", "10.0.0.0/24"]), &[22, 80])
+        );
+    }
+
+    #[test]
+    fn test_checkpoint() {
+        let path = std::env::temp_dir().join(format!("opscan-checkpoint-{}", std::process::id()));
+        let web_fingerprint = fingerprint(&targets(&["10.0.0.0/24"]), &[22, 80]);
+        let mut checkpoint = Checkpoint::new(
+            vec!["10.0.0.0/24".into()],
+            vec![],
+            "22,80".into(),
+            web_fingerprint,
+        );
+        checkpoint.next = 100;
+        checkpoint.done = vec![102];
+        checkpoint.summary.closed = 98;
+        checkpoint.summary.open = 3;
+        checkpoint.up_hosts = vec![("10.0.0.5".into(), "10.0.0.5".parse().unwrap())];
+        checkpoint.save(&path).unwrap();
+        let loaded = Checkpoint::load(&path).unwrap();
+        let err = checkpoint.save_new(&path).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        fs::remove_file(&path).unwrap();
+        assert!(loaded.is_scan_of(&checkpoint));
+        assert_eq!(loaded.done_count(), 101);
+        assert_eq!((loaded.summary.open, loaded.summary.closed), (3, 98));
+        assert_eq!(loaded.up_hosts, checkpoint.up_hosts);
+        let other_fingerprint = fingerprint(&targets(&["10.0.0.0/24"]), &[22]);
+        let other = Checkpoint::new(
+            vec!["10.0.0.0/24".into()],
+            vec![],
+            "22".into(),
+            other_fingerprint,
+        );
+        assert!(!other.is_scan_of(&checkpoint));
+        assert!(Checkpoint::load(&path).is_err());
+    }
+}
@@ -0,0 +1,76 @@
+//! Config file
+
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    sync::OnceLock,
+};
+
+use serde::Deserialize;
+
+use crate::ports::parse_ports;
+
+static CONFIG: OnceLock<Config> = OnceLock::new();
+
+/// Config loaded from `$OPSCAN_CONFIG` or `<config dir>/opscan/config.toml`
+///
+/// ```toml
+/// [groups]
+/// k8s = "6443,10250,2379-2380"
+/// monitoring = "9090,9100,3000"
+/// ```
+#[derive(Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct Config {
+    /// User-defined named port groups
+    groups: HashMap<String, String>,
+    #[serde(skip)]
+    group_ports: HashMap<String, Vec<u16>>,
+}
+
+impl Config {
+    /// Load config file and make it available to `get`
+    pub fn init() -> Result<(), String> {
+        let config = match config_path() {
+            Some(path) if path.exists() => Self::load(&path)
+                .map_err(|err| format!("Failed to load config '{}', {err}", path.display()))?,
+            _ => Self::default(),
+        };
+        let _ = CONFIG.set(config);
+        Ok(())
+    }
+
+    fn load(path: &Path) -> Result<Self, String> {
+        let content = std::fs::read_to_string(path).map_err(|err| err.to_string())?;
+        let mut config: Self = toml::from_str(&content).map_err(|err| err.to_string())?;
+        // Groups are resolved before the config is set, so they cannot refer to each other
+        for (name, spec) in &config.groups {
+            let ports = parse_ports(spec).map_err(|err| format!("{err} in group '{name}'"))?;
+            config.group_ports.insert(name.to_lowercase(), ports);
+        }
+        Ok(config)
+    }
+
+    /// Get the ports of user-defined group
+    pub fn group(name: &str) -> Option<&'static [u16]> {
+        CONFIG
+            .get()?
+            .group_ports
+            .get(&name.to_lowercase())
+            .map(|v| v.as_slice())
+    }
+}
+
+fn config_path() -> Option<PathBuf> {
+    if let Some(path) = std::env::var_os("OPSCAN_CONFIG") {
+        return Some(path.into());
+    }
+    let config_dir = if cfg!(windows) {
+        std::env::var_os("APPDATA").map(PathBuf::from)
+    } else {
+        std::env::var_os("XDG_CONFIG_HOME")
+            .map(PathBuf::from)
+            .or_else(|| std::env::var_os("HOME").map(|v| PathBuf::from(v).join(".config")))
+    };
+    config_dir.map(|v| v.join("opscan").join("config.toml"))
+}
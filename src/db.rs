@@ -0,0 +1,600 @@
+use std::{
+    collections::{BTreeSet, HashMap, HashSet},
+    fmt,
+    net::IpAddr,
+    path::{Path, PathBuf},
+    time::SystemTime,
+};
+
+use rusqlite::{params, types::Type, Connection};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    addresses::{parse_target, Host, TargetAddr},
+    event::{format_time, Event, EventKind},
+    ports::parse_ports,
+    scanner::OpenPort,
+    summary::Summary,
+};
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS runs (
+    id INTEGER PRIMARY KEY,
+    started_at TEXT NOT NULL,
+    finished_at TEXT NOT NULL,
+    config TEXT NOT NULL,
+    hosts INTEGER NOT NULL,
+    hosts_up INTEGER NOT NULL,
+    open INTEGER NOT NULL,
+    closed INTEGER NOT NULL,
+    filtered INTEGER NOT NULL,
+    errors INTEGER NOT NULL
+);
+CREATE TABLE IF NOT EXISTS up_hosts (
+    run_id INTEGER NOT NULL REFERENCES runs (id),
+    host TEXT NOT NULL,
+    ip TEXT NOT NULL
+);
+CREATE TABLE IF NOT EXISTS open_ports (
+    run_id INTEGER NOT NULL REFERENCES runs (id),
+    host TEXT NOT NULL,
+    ip TEXT NOT NULL,
+    port INTEGER NOT NULL,
+    service TEXT NOT NULL,
+    hostname TEXT
+);
+CREATE INDEX IF NOT EXISTS up_hosts_host ON up_hosts (host, ip, run_id);
+CREATE INDEX IF NOT EXISTS open_ports_run ON open_ports (run_id, host, ip);
+";
+
+/// History of scans in a SQLite database
+#[derive(Debug)]
+pub struct Db {
+    conn: Connection,
+}
+
+/// Settings of a run, stored as JSON
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RunConfig {
+    pub targets: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<PathBuf>,
+    pub ports: String,
+    /// In milliseconds
+    pub timeout: u64,
+    pub concurrency: usize,
+}
+
+/// A recorded run
+#[derive(Debug)]
+pub struct Run {
+    pub id: i64,
+    pub started_at: String,
+    pub config: RunConfig,
+    pub hosts: u64,
+    pub hosts_up: u64,
+    pub open: u64,
+}
+
+impl fmt::Display for Run {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut targets = self.config.targets.clone();
+        targets.extend(self.config.files.iter().map(|v| v.display().to_string()));
+        write!(
+            f,
+            "#{} {} {}: {} hosts ({} up), {} open",
+            self.id,
+            self.started_at,
+            targets.join(" "),
+            self.hosts,
+            self.hosts_up,
+            self.open
+        )
+    }
+}
+
+/// Open ports of a host that changed between runs
+#[derive(Debug, PartialEq, Eq)]
+pub struct HostChange {
+    pub host: String,
+    pub ip: IpAddr,
+    /// Start time of the last run with a change
+    pub last_changed: String,
+    /// Ports in the order they opened (true) or closed (false)
+    pub ports: Vec<(u16, bool)>,
+}
+
+impl fmt::Display for HostChange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.last_changed, self.host, self.ip)?;
+        for (port, opened) in &self.ports {
+            write!(f, " {}{port}", if *opened { '+' } else { '-' })?;
+        }
+        Ok(())
+    }
+}
+
+/// Ports a run scanned, from its config
+struct Scanned {
+    /// None if the port spec cannot be parsed, every port is then taken as scanned
+    ports: Option<HashSet<u16>>,
+    /// Targets of the command line, with their own ports
+    targets: Vec<(TargetAddr, Option<HashSet<u16>>)>,
+}
+
+impl Scanned {
+    fn new(config: &RunConfig) -> Self {
+        let targets = config
+            .targets
+            .iter()
+            .filter_map(|v| parse_target(v).ok())
+            .map(|v| (v.addr, v.ports.map(|v| v.iter().copied().collect())))
+            .collect();
+        Self {
+            ports: parse_ports(&config.ports).ok().map(HashSet::from_iter),
+            targets,
+        }
+    }
+
+    /// Whether the run scanned `port` of `host`
+    ///
+    /// Hosts of target files, whose lines are not recorded, are taken as scanned on the ports of
+    /// the run.
+    fn contains(&self, host: &Host, port: u16) -> bool {
+        let in_ports =
+            |ports: &Option<HashSet<u16>>| ports.as_ref().is_none_or(|v| v.contains(&port));
+        let mut targets = self
+            .targets
+            .iter()
+            .filter(|(v, _)| v.contains(host))
+            .peekable();
+        if targets.peek().is_none() {
+            return in_ports(&self.ports);
+        }
+        targets.any(|(_, ports)| match ports {
+            Some(ports) => ports.contains(&port),
+            None => in_ports(&self.ports),
+        })
+    }
+}
+
+impl Db {
+    pub fn open(path: &Path) -> Result<Self, String> {
+        let conn = Connection::open(path)
+            .and_then(|conn| conn.execute_batch(SCHEMA).map(|_| conn))
+            .map_err(|err| format!("Failed to open database '{}', {err}", path.display()))?;
+        Ok(Self { conn })
+    }
+
+    /// Record a run finished at `finished`, returning its id
+    pub fn record(
+        &mut self,
+        config: &RunConfig,
+        summary: &Summary,
+        open_ports: &[OpenPort],
+        up_hosts: &HashSet<(String, IpAddr)>,
+        finished: SystemTime,
+    ) -> Result<i64, String> {
+        let started = finished.checked_sub(summary.elapsed).unwrap_or(finished);
+        let config = serde_json::to_string(config).map_err(|err| err.to_string())?;
+        let tx = self.conn.transaction().map_err(db_error)?;
+        tx.execute(
+            "INSERT INTO runs (started_at, finished_at, config, hosts, hosts_up, open, closed, filtered, errors)
+            VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            params![
+                format_time(started),
+                format_time(finished),
+                config,
+                summary.hosts,
+                summary.hosts_up,
+                summary.open,
+                summary.closed,
+                summary.filtered,
+                summary.errors.values().sum::<u64>(),
+            ],
+        )
+        .map_err(db_error)?;
+        let id = tx.last_insert_rowid();
+        {
+            let mut insert = tx
+                .prepare("INSERT INTO up_hosts (run_id, host, ip) VALUES (?1, ?2, ?3)")
+                .map_err(db_error)?;
+            for (host, ip) in up_hosts {
+                insert
+                    .execute(params![id, host, ip.to_string()])
+                    .map_err(db_error)?;
+            }
+            let mut insert = tx
+                .prepare(
+                    "INSERT INTO open_ports (run_id, host, ip, port, service, hostname)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                )
+                .map_err(db_error)?;
+            for v in open_ports {
+                insert
+                    .execute(params![
+                        id,
+                        v.host,
+                        v.ip.to_string(),
+                        v.port,
+                        v.service,
+                        v.hostname
+                    ])
+                    .map_err(db_error)?;
+            }
+        }
+        tx.commit().map_err(db_error)?;
+        Ok(id)
+    }
+
+    /// Latest runs, newest first
+    pub fn runs(&self, limit: usize) -> Result<Vec<Run>, String> {
+        let mut query = self
+            .conn
+            .prepare(
+                "SELECT id, started_at, config, hosts, hosts_up, open
+                FROM runs ORDER BY id DESC LIMIT ?1",
+            )
+            .map_err(db_error)?;
+        let rows = query
+            .query_map(params![limit], |row| {
+                let config = row.get::<_, String>(2)?;
+                Ok(Run {
+                    id: row.get(0)?,
+                    started_at: row.get(1)?,
+                    config: serde_json::from_str(&config).map_err(|err| {
+                        rusqlite::Error::FromSqlConversionFailure(2, Type::Text, Box::new(err))
+                    })?,
+                    hosts: row.get(3)?,
+                    hosts_up: row.get(4)?,
+                    open: row.get(5)?,
+                })
+            })
+            .map_err(db_error)?;
+        rows.collect::<Result<_, _>>().map_err(db_error)
+    }
+
+    /// Ports scanned by each run
+    fn scanned(&self) -> Result<HashMap<i64, Scanned>, String> {
+        let mut query = self
+            .conn
+            .prepare("SELECT id, config FROM runs")
+            .map_err(db_error)?;
+        let rows = query
+            .query_map([], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
+            })
+            .map_err(db_error)?;
+        let mut scanned = HashMap::new();
+        for row in rows {
+            let (id, config) = row.map_err(db_error)?;
+            let config: RunConfig = serde_json::from_str(&config)
+                .map_err(|err| format!("Invalid config of run #{id}, {err}"))?;
+            scanned.insert(id, Scanned::new(&config));
+        }
+        Ok(scanned)
+    }
+
+    /// Times `port` opened and closed on hosts of `target`, over the runs the hosts were up
+    /// and the port was scanned
+    pub fn port_events(&self, target: &TargetAddr, port: u16) -> Result<Vec<Event>, String> {
+        let scanned = self.scanned()?;
+        let mut query = self
+            .conn
+            .prepare(
+                "SELECT r.started_at, h.host, h.ip, o.service, h.run_id
+                FROM up_hosts h
+                JOIN runs r ON r.id = h.run_id
+                LEFT JOIN open_ports o
+                    ON o.run_id = h.run_id AND o.host = h.host AND o.ip = h.ip AND o.port = ?1
+                ORDER BY h.host, h.ip, h.run_id",
+            )
+            .map_err(db_error)?;
+        let rows = query
+            .query_map(params![port], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                    row.get::<_, i64>(4)?,
+                ))
+            })
+            .map_err(db_error)?;
+        let mut events = vec![];
+        // Host, IP and service of the previous run
+        let mut previous: Option<(String, IpAddr, Option<String>)> = None;
+        for row in rows {
+            let (time, label, ip, service, run) = row.map_err(db_error)?;
+            let ip: IpAddr = ip.parse().map_err(|_| format!("Invalid IP '{ip}'"))?;
+            let host = Host {
+                ip,
+                scope_id: 0,
+                label,
+                ports: None,
+                by_name: false,
+            };
+            if !target.contains(&host)
+                || !scanned.get(&run).is_some_and(|v| v.contains(&host, port))
+            {
+                continue;
+            }
+            let previous_service = match previous.take() {
+                Some((label, previous_ip, service)) if label == host.label && previous_ip == ip => {
+                    service
+                }
+                _ => None,
+            };
+            let kind = match (&previous_service, &service) {
+                (None, Some(_)) => Some(EventKind::PortOpened),
+                (Some(_), None) => Some(EventKind::PortClosed),
+                (Some(a), Some(b)) if a != b => Some(EventKind::ServiceChanged),
+                _ => None,
+            };
+            if let Some(kind) = kind {
+                events.push(Event {
+                    time,
+                    kind,
+                    host: host.label.clone(),
+                    ip,
+                    port: Some(port),
+                    service: service.clone().or_else(|| previous_service.clone()),
+                    previous_service: previous_service
+                        .filter(|_| kind == EventKind::ServiceChanged),
+                });
+            }
+            previous = Some((host.label, ip, service));
+        }
+        Ok(events)
+    }
+
+    /// Hosts whose open ports changed in runs started since `since`
+    ///
+    /// Each run is compared with the previous run the host was up in, on the ports both scanned.
+    pub fn changed_hosts(&self, since: SystemTime) -> Result<Vec<HostChange>, String> {
+        let scanned = self.scanned()?;
+        let mut query = self
+            .conn
+            .prepare(
+                "WITH sets AS (
+                    SELECT h.run_id, r.started_at, h.host, h.ip,
+                        (SELECT group_concat(o.port) FROM open_ports o
+                        WHERE o.run_id = h.run_id AND o.host = h.host AND o.ip = h.ip) AS ports
+                    FROM up_hosts h JOIN runs r ON r.id = h.run_id
+                ),
+                changes AS (
+                    SELECT *,
+                        lag(ports) OVER hosts AS previous,
+                        lag(run_id) OVER hosts AS previous_run
+                    FROM sets
+                    WINDOW hosts AS (PARTITION BY host, ip ORDER BY run_id)
+                )
+                SELECT started_at, host, ip, previous, ports, previous_run, run_id FROM changes
+                WHERE previous_run IS NOT NULL AND started_at >= ?1
+                ORDER BY host, ip, run_id",
+            )
+            .map_err(db_error)?;
+        let rows = query
+            .query_map(params![format_time(since)], |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                    row.get::<_, Option<String>>(4)?,
+                    row.get::<_, i64>(5)?,
+                    row.get::<_, i64>(6)?,
+                ))
+            })
+            .map_err(db_error)?;
+        let port_set = |ports: Option<String>| {
+            ports
+                .iter()
+                .flat_map(|v| v.split(',').filter_map(|v| v.parse::<u16>().ok()))
+                .collect::<BTreeSet<_>>()
+                .into_iter()
+                .collect::<Vec<_>>()
+        };
+        let mut changes: Vec<HostChange> = vec![];
+        for row in rows {
+            let (time, host, ip, previous, ports, previous_run, run) = row.map_err(db_error)?;
+            let ip: IpAddr = ip.parse().map_err(|_| format!("Invalid IP '{ip}'"))?;
+            let key = Host {
+                ip,
+                scope_id: 0,
+                label: host,
+                ports: None,
+                by_name: false,
+            };
+            let both_scanned = |port: &u16| {
+                [previous_run, run]
+                    .iter()
+                    .all(|v| scanned.get(v).is_some_and(|v| v.contains(&key, *port)))
+            };
+            let (previous, ports) = (port_set(previous), port_set(ports));
+            let diff = ports
+                .iter()
+                .filter(|v| !previous.contains(v))
+                .map(|v| (*v, true))
+                .chain(
+                    previous
+                        .iter()
+                        .filter(|v| !ports.contains(v))
+                        .map(|v| (*v, false)),
+                )
+                .filter(|(port, _)| both_scanned(port))
+                .collect::<Vec<_>>();
+            if diff.is_empty() {
+                continue;
+            }
+            let host = key.label;
+            match changes.last_mut() {
+                Some(change) if change.host == host && change.ip == ip => {
+                    change.ports.extend(diff);
+                    change.last_changed = time;
+                }
+                _ => changes.push(HostChange {
+                    host,
+                    ip,
+                    last_changed: time,
+                    ports: diff,
+                }),
+            }
+        }
+        changes.sort_by(|a, b| a.last_changed.cmp(&b.last_changed));
+        Ok(changes)
+    }
+}
+
+fn db_error(err: rusqlite::Error) -> String {
+    format!("Database error, {err}")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::addresses::parse_target;
+    use std::time::{Duration, UNIX_EPOCH};
+
+    fn record(db: &mut Db, day: u64, ports: &str, up: &[&str], open: &[(&str, u16, &str)]) {
+        let config = RunConfig {
+            targets: vec!["10.0.0.0/24".into()],
+            files: vec![],
+            ports: ports.into(),
+            timeout: 1000,
+            concurrency: 256,
+        };
+        let summary = Summary {
+            hosts: 256,
+            hosts_up: up.len(),
+            open: open.len() as u64,
+            elapsed: Duration::from_secs(60),
+            ..Default::default()
+        };
+        let open_ports = open
+            .iter()
+            .map(|(ip, port, service)| OpenPort {
+                host: ip.to_string(),
+                ip: ip.parse().unwrap(),
+                port: *port,
+                service: service.to_string(),
+                category: "private".into(),
+                hostname: None,
+            })
+            .collect::<Vec<_>>();
+        let up_hosts = up
+            .iter()
+            .map(|ip| (ip.to_string(), ip.parse().unwrap()))
+            .collect();
+        let finished = UNIX_EPOCH + Duration::from_secs(86400 * day + 60);
+        db.record(&config, &summary, &open_ports, &up_hosts, finished)
+            .unwrap();
+    }
+
+    const PORTS: &str = "22,80,443,6379";
+
+    #[test]
+    fn test_db() {
+        let mut db = Db::open(Path::new(":memory:")).unwrap();
+        let (a, b) = ("10.0.0.5", "10.0.0.6");
+        record(
+            &mut db,
+            0,
+            PORTS,
+            &[a, b],
+            &[(a, 22, "ssh"), (b, 22, "ssh")],
+        );
+        record(
+            &mut db,
+            1,
+            PORTS,
+            &[a, b],
+            &[(a, 22, "ssh"), (a, 6379, "redis")],
+        );
+        record(
+            &mut db,
+            2,
+            PORTS,
+            &[b],
+            &[(b, 22, "ssh"), (b, 443, "https")],
+        );
+        record(
+            &mut db,
+            3,
+            PORTS,
+            &[a, b],
+            &[(a, 22, "ssh"), (b, 22, "ssh"), (b, 443, "https")],
+        );
+
+        let runs = db.runs(2).unwrap();
+        assert_eq!(runs.iter().map(|v| v.id).collect::<Vec<_>>(), [4, 3]);
+        assert_eq!(
+            runs[0].to_string(),
+            "#4 1970-01-04T00:00:00Z 10.0.0.0/24: 256 hosts (2 up), 3 open"
+        );
+        assert_eq!(runs[0].config.ports, "22,80,443,6379");
+
+        let target = parse_target("10.0.0.0/24").unwrap().addr;
+        let events = db
+            .port_events(&target, 6379)
+            .unwrap()
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            events,
+            [
+                "1970-01-02T00:00:00Z port-opened 10.0.0.5 6379 redis",
+                "1970-01-04T00:00:00Z port-closed 10.0.0.5 6379 redis",
+            ]
+        );
+        let target = parse_target("10.0.0.6").unwrap().addr;
+        assert!(db.port_events(&target, 6379).unwrap().is_empty());
+
+        let changes = db
+            .changed_hosts(UNIX_EPOCH + Duration::from_secs(86400 * 2))
+            .unwrap()
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            changes,
+            [
+                "1970-01-03T00:00:00Z 10.0.0.6 10.0.0.6 +22 +443",
+                "1970-01-04T00:00:00Z 10.0.0.5 10.0.0.5 -6379",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_db_scanned_ports() {
+        let mut db = Db::open(Path::new(":memory:")).unwrap();
+        let a = "10.0.0.5";
+        record(
+            &mut db,
+            0,
+            "22,80",
+            &[a],
+            &[(a, 22, "ssh"), (a, 80, "http")],
+        );
+        // Port 80 is not scanned rather than closed
+        record(&mut db, 1, "22", &[a], &[(a, 22, "ssh")]);
+        record(&mut db, 2, "22,80", &[a], &[(a, 22, "ssh")]);
+
+        let target = parse_target(a).unwrap().addr;
+        let events = db.port_events(&target, 80).unwrap();
+        assert_eq!(
+            events.iter().map(|v| v.to_string()).collect::<Vec<_>>(),
+            [
+                "1970-01-01T00:00:00Z port-opened 10.0.0.5 80 http",
+                "1970-01-03T00:00:00Z port-closed 10.0.0.5 80 http"
+            ]
+        );
+        assert!(db.changed_hosts(UNIX_EPOCH).unwrap().is_empty());
+        record(&mut db, 3, "22,80", &[a], &[(a, 80, "http")]);
+        assert_eq!(
+            db.changed_hosts(UNIX_EPOCH).unwrap()[0].to_string(),
+            "1970-01-04T00:00:00Z 10.0.0.5 10.0.0.5 +80 -22"
+        );
+    }
+}
@@ -0,0 +1,165 @@
+use std::{
+    fmt,
+    net::IpAddr,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use serde::Serialize;
+
+use crate::addresses::Host;
+
+/// A port or host event, printed by watch and sent to hooks
+#[derive(Debug, Clone, Serialize)]
+pub struct Event {
+    /// UTC time in RFC 3339
+    pub time: String,
+    pub kind: EventKind,
+    pub host: String,
+    pub ip: IpAddr,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub service: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_service: Option<String>,
+}
+
+impl Event {
+    /// Event of `port` of `host` found open
+    pub fn open(host: &Host, port: u16, service: &str, time: SystemTime) -> Self {
+        Self {
+            time: format_time(time),
+            kind: EventKind::Open,
+            host: host.label.clone(),
+            ip: host.ip,
+            port: Some(port),
+            service: Some(service.to_string()),
+            previous_service: None,
+        }
+    }
+
+    /// Environment variables describing the event, passed to commands
+    pub fn env(&self) -> Vec<(&'static str, String)> {
+        let mut env = vec![
+            ("OPSCAN_EVENT", self.kind.as_str().to_string()),
+            ("OPSCAN_TIME", self.time.clone()),
+            ("OPSCAN_HOST", self.host.clone()),
+            ("OPSCAN_IP", self.ip.to_string()),
+        ];
+        if let Some(port) = self.port {
+            env.push(("OPSCAN_PORT", port.to_string()));
+        }
+        if let Some(service) = &self.service {
+            env.push(("OPSCAN_SERVICE", service.clone()));
+        }
+        if let Some(previous_service) = &self.previous_service {
+            env.push(("OPSCAN_PREVIOUS_SERVICE", previous_service.clone()));
+        }
+        env
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum EventKind {
+    /// Port found open by a scan
+    Open,
+    HostUp,
+    HostDown,
+    PortOpened,
+    PortClosed,
+    ServiceChanged,
+}
+
+impl EventKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventKind::Open => "open",
+            EventKind::HostUp => "host-up",
+            EventKind::HostDown => "host-down",
+            EventKind::PortOpened => "port-opened",
+            EventKind::PortClosed => "port-closed",
+            EventKind::ServiceChanged => "service-changed",
+        }
+    }
+
+    /// Whether the port of the event is open
+    pub fn is_open(&self) -> bool {
+        matches!(self, EventKind::Open | EventKind::PortOpened)
+    }
+}
+
+impl fmt::Display for Event {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.time, self.kind.as_str(), self.host)?;
+        match (self.port, &self.service) {
+            (Some(port), Some(service)) => write!(f, " {port} {service}")?,
+            _ => write!(f, " {}", self.ip)?,
+        }
+        if let Some(previous_service) = &self.previous_service {
+            write!(f, " was {previous_service}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Format as RFC 3339 in UTC, e.g. `2023-02-01T08:30:00Z`
+pub fn format_time(time: SystemTime) -> String {
+    let secs = time
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let (days, secs) = (secs / 86400, secs % 86400);
+    // Civil from days, see http://howardhinnant.github.io/date_algorithms.html
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    format!(
+        "{year:04}-{month:02}-{day:02}T{:02}:{:02}:{:02}Z",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    #[test]
+    fn test_event() {
+        let host = Host::fixture("10.0.0.5", "web1");
+        let event = Event::open(&host, 22, "ssh", UNIX_EPOCH);
+        assert_eq!(event.to_string(), "1970-01-01T00:00:00Z open web1 22 ssh");
+        assert!(event.kind.is_open());
+        assert_eq!(
+            event.env(),
+            [
+                ("OPSCAN_EVENT", "open".to_string()),
+                ("OPSCAN_TIME", "1970-01-01T00:00:00Z".to_string()),
+                ("OPSCAN_HOST", "web1".to_string()),
+                ("OPSCAN_IP", "10.0.0.5".to_string()),
+                ("OPSCAN_PORT", "22".to_string()),
+                ("OPSCAN_SERVICE", "ssh".to_string()),
+            ]
+        );
+        assert_eq!(
+            serde_json::to_string(&event).unwrap(),
+            r#"{"time":"1970-01-01T00:00:00Z","kind":"open","host":"web1","ip":"10.0.0.5","port":22,"service":"ssh"}"#
+        );
+    }
+
+    #[test]
+    fn test_format_time() {
+        assert_eq!(format_time(UNIX_EPOCH), "1970-01-01T00:00:00Z");
+        let time = UNIX_EPOCH + Duration::from_secs(951782400 + 3661);
+        assert_eq!(format_time(time), "2000-02-29T01:01:01Z");
+    }
+}
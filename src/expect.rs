@@ -0,0 +1,179 @@
+use std::{collections::HashSet, fmt, sync::Mutex};
+
+use crate::{
+    addresses::{parse_target, Host, Ports, TargetAddr},
+    ports::parse_ports,
+    scanner::Status,
+};
+
+/// State a port is expected to be in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expected {
+    Open,
+    /// Closed or filtered
+    Closed,
+}
+
+impl fmt::Display for Expected {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expected::Open => write!(f, "open"),
+            Expected::Closed => write!(f, "closed"),
+        }
+    }
+}
+
+/// Ports expected to be open or closed on every host or on the hosts of a target
+#[derive(Debug)]
+pub struct Expectation {
+    /// Applies to every host if none
+    pub target: Option<TargetAddr>,
+    pub ports: Ports,
+    pub expected: Expected,
+}
+
+impl Expectation {
+    /// Parse ports e.g. `22,3389`, or a target with ports e.g. `web1:443`, `10.0.0.0/24:22`
+    pub fn parse(value: &str, expected: Expected) -> Result<Self, String> {
+        if let Ok(ports) = parse_ports(value) {
+            return Ok(Self {
+                target: None,
+                ports: ports.into(),
+                expected,
+            });
+        }
+        let target = parse_target(value)?;
+        let ports = target
+            .ports
+            .ok_or_else(|| format!("Missing ports in expectation '{value}'"))?;
+        Ok(Self {
+            target: Some(target.addr),
+            ports,
+            expected,
+        })
+    }
+
+    fn applies(&self, host: &Host, port: u16) -> bool {
+        self.ports.contains(&port) && self.target.as_ref().is_none_or(|v| v.contains(host))
+    }
+}
+
+/// Checks probe results against expectations
+#[derive(Debug)]
+pub struct Assertions {
+    expectations: Vec<Expectation>,
+    failures: Mutex<Vec<String>>,
+    /// Ports of target expectations that some host was probed on
+    probed: Mutex<HashSet<(usize, u16)>>,
+}
+
+impl Assertions {
+    pub fn new(expectations: Vec<Expectation>) -> Self {
+        Self {
+            expectations,
+            failures: Mutex::new(vec![]),
+            probed: Mutex::new(HashSet::new()),
+        }
+    }
+
+    /// Ports of the expectations that apply to every host
+    pub fn global_ports(&self) -> impl Iterator<Item = u16> + '_ {
+        self.expectations
+            .iter()
+            .filter(|v| v.target.is_none())
+            .flat_map(|v| v.ports.iter().copied())
+    }
+
+    /// Check the result of probing `port` of `host`
+    pub fn record(&self, host: &Host, port: u16, status: &Status) {
+        for (i, expectation) in self.expectations.iter().enumerate() {
+            if !expectation.applies(host, port) {
+                continue;
+            }
+            if expectation.target.is_some() {
+                self.probed.lock().unwrap().insert((i, port));
+            }
+            let ok = matches!(
+                (expectation.expected, status),
+                (Expected::Open, Status::Open)
+                    | (Expected::Closed, Status::Closed | Status::Filtered)
+            );
+            if !ok {
+                let found = match status {
+                    Status::Error(err) => format!("{status}: {err}"),
+                    _ => status.to_string(),
+                };
+                self.failures.lock().unwrap().push(format!(
+                    "{} {port} expected {}, found {found}",
+                    host.label, expectation.expected
+                ));
+            }
+        }
+    }
+
+    /// Failed assertions, including ports of target expectations that were never probed
+    pub fn failures(&self) -> Vec<String> {
+        let mut failures = self.failures.lock().unwrap().clone();
+        failures.sort();
+        let probed = self.probed.lock().unwrap();
+        for (i, expectation) in self.expectations.iter().enumerate() {
+            let Some(target) = &expectation.target else {
+                continue;
+            };
+            for port in expectation.ports.iter() {
+                if !probed.contains(&(i, *port)) {
+                    failures.push(format!(
+                        "{} {port} expected {}, but was not scanned",
+                        target.label(),
+                        expectation.expected
+                    ));
+                }
+            }
+        }
+        failures
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io;
+
+    #[test]
+    fn test_assertions() {
+        let expectations = vec![
+            Expectation::parse("443", Expected::Open).unwrap(),
+            Expectation::parse("22,3389", Expected::Closed).unwrap(),
+            Expectation::parse("web1:8443", Expected::Open).unwrap(),
+            Expectation::parse("10.0.0.0/24:5432", Expected::Closed).unwrap(),
+        ];
+        assert!(Expectation::parse("web1", Expected::Open).is_err());
+        let assertions = Assertions::new(expectations);
+        assert_eq!(
+            assertions.global_ports().collect::<Vec<_>>(),
+            [443, 22, 3389]
+        );
+        let web1 = Host::fixture("10.0.0.5", "web1");
+        assertions.record(&web1, 443, &Status::Open);
+        assertions.record(&web1, 22, &Status::Filtered);
+        assertions.record(&web1, 3389, &Status::Open);
+        assertions.record(&web1, 5432, &Status::Closed);
+        let db1 = Host::fixture("10.0.1.5", "db1");
+        assertions.record(&db1, 443, &Status::Closed);
+        assertions.record(&db1, 8443, &Status::Open);
+        assertions.record(
+            &db1,
+            22,
+            &Status::Error(io::Error::from(io::ErrorKind::AddrNotAvailable)),
+        );
+        assert_eq!(
+            assertions.failures(),
+            [
+                "db1 22 expected closed, found error: address not available",
+                "db1 443 expected open, found closed",
+                "web1 3389 expected closed, found open",
+                "web1 8443 expected open, but was not scanned",
+            ]
+        );
+    }
+}
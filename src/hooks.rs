@@ -0,0 +1,354 @@
+use std::{io, process::Stdio, str::FromStr, sync::Arc, sync::Mutex, time::Duration};
+
+use tokio::{
+    io::{AsyncRead, AsyncWrite, AsyncWriteExt},
+    net::TcpStream,
+    process::Command,
+    sync::Semaphore,
+    task::JoinHandle,
+};
+use tokio_rustls::{
+    rustls::{ClientConfig, OwnedTrustAnchor, RootCertStore, ServerName},
+    TlsConnector,
+};
+
+use crate::{event::Event, http};
+
+/// Hooks run at the same time, the rest wait for their turn
+const MAX_RUNNING: usize = 16;
+/// Attempts to deliver an event to the webhook
+const MAX_ATTEMPTS: u32 = 3;
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Commands and webhooks notified of events
+#[derive(Debug)]
+pub struct Hooks {
+    /// Shell command run for events of open ports
+    exec: Option<String>,
+    webhook: Option<Webhook>,
+    running: Arc<Semaphore>,
+    tasks: Mutex<Vec<JoinHandle<()>>>,
+}
+
+impl Hooks {
+    /// None if there is nothing to notify
+    pub fn new(exec: Option<String>, webhook: Option<Webhook>) -> Option<Self> {
+        if exec.is_none() && webhook.is_none() {
+            return None;
+        }
+        Some(Self {
+            exec,
+            webhook,
+            running: Arc::new(Semaphore::new(MAX_RUNNING)),
+            tasks: Mutex::new(vec![]),
+        })
+    }
+
+    /// Notify of `event` in the background, failures are printed as warnings by `warn`
+    pub fn fire(&self, event: Event, warn: impl Fn(&str) + Clone + Send + 'static) {
+        let mut tasks = self.tasks.lock().unwrap();
+        // Only the notifications still running are waited for by `finish`
+        tasks.retain(|v| !v.is_finished());
+        if let Some(command) = self.exec.clone().filter(|_| event.kind.is_open()) {
+            let (running, event, warn) = (self.running.clone(), event.clone(), warn.clone());
+            tasks.push(tokio::spawn(async move {
+                let _permit = running.acquire_owned().await;
+                if let Err(err) = exec(&command, &event).await {
+                    warn(&format!("warning: command for {event} failed: {err}"));
+                }
+            }));
+        }
+        if let Some(webhook) = self.webhook.clone() {
+            let running = self.running.clone();
+            tasks.push(tokio::spawn(async move {
+                let _permit = running.acquire_owned().await;
+                if let Err(err) = webhook.post(&event).await {
+                    warn(&format!("warning: webhook {} failed: {err}", webhook.url));
+                }
+            }));
+        }
+    }
+
+    /// Wait for the notifications fired so far
+    pub async fn finish(&self) {
+        let tasks = std::mem::take(&mut *self.tasks.lock().unwrap());
+        for task in tasks {
+            let _ = task.await;
+        }
+    }
+}
+
+/// Run `command` by the shell with the event in environment variables
+async fn exec(command: &str, event: &Event) -> Result<(), String> {
+    #[cfg(windows)]
+    let mut cmd = {
+        let mut cmd = Command::new("cmd");
+        cmd.arg("/C");
+        cmd
+    };
+    #[cfg(not(windows))]
+    let mut cmd = {
+        let mut cmd = Command::new("sh");
+        cmd.arg("-c");
+        cmd
+    };
+    let status = cmd
+        .arg(command)
+        .envs(event.env())
+        .stdin(Stdio::null())
+        .status()
+        .await
+        .map_err(|err| err.to_string())?;
+    match status.success() {
+        true => Ok(()),
+        false => Err(status.to_string()),
+    }
+}
+
+/// HTTP endpoint that events are posted to as JSON
+#[derive(Debug, Clone)]
+pub struct Webhook {
+    url: String,
+    tls: bool,
+    /// Host and optional port, sent as the Host header
+    authority: String,
+    host: String,
+    port: u16,
+    path: String,
+    /// Delay before the first retry, doubled for the next one
+    retry_delay: Duration,
+}
+
+impl FromStr for Webhook {
+    type Err = String;
+
+    /// Parse `http://host[:port][/path]` or `https://host[:port][/path]`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid webhook '{s}'");
+        let (scheme, rest) = s.split_once("://").ok_or_else(invalid)?;
+        let (tls, default_port) = match scheme.to_ascii_lowercase().as_str() {
+            "http" => (false, 80),
+            "https" => (true, 443),
+            _ => return Err(format!("Unsupported webhook scheme '{scheme}'")),
+        };
+        let (authority, path) = match rest.find(['/', '?']) {
+            Some(i) if rest[i..].starts_with('?') => (&rest[..i], format!("/{}", &rest[i..])),
+            Some(i) => (&rest[..i], rest[i..].to_string()),
+            None => (rest, "/".to_string()),
+        };
+        if authority.is_empty() || authority.contains('@') {
+            return Err(invalid());
+        }
+        let (host, port) = match authority.rfind(':') {
+            Some(i) if !authority[i..].contains(']') => {
+                let port = authority[i + 1..].parse().map_err(|_| invalid())?;
+                (&authority[..i], port)
+            }
+            _ => (authority, default_port),
+        };
+        Ok(Self {
+            url: s.to_string(),
+            tls,
+            authority: authority.to_string(),
+            host: host
+                .trim_start_matches('[')
+                .trim_end_matches(']')
+                .to_string(),
+            port,
+            path,
+            retry_delay: Duration::from_secs(1),
+        })
+    }
+}
+
+impl Webhook {
+    /// Post `event`, retrying on connection failures and server errors
+    pub async fn post(&self, event: &Event) -> io::Result<()> {
+        let body = serde_json::to_string(event)?;
+        let mut delay = self.retry_delay;
+        for attempt in 1.. {
+            let result = tokio::time::timeout(WEBHOOK_TIMEOUT, self.send(&body))
+                .await
+                .unwrap_or_else(|_| Err(io::ErrorKind::TimedOut.into()));
+            let retry = match &result {
+                Ok(429 | 500..=599) | Err(_) => attempt < MAX_ATTEMPTS,
+                _ => false,
+            };
+            if !retry {
+                return match result? {
+                    200..=299 => Ok(()),
+                    code => Err(io::Error::other(format!("replied {code}"))),
+                };
+            }
+            tokio::time::sleep(delay).await;
+            delay *= 2;
+        }
+        unreachable!()
+    }
+
+    /// Send one request, returning the status code of the response
+    async fn send(&self, body: &str) -> io::Result<u16> {
+        let stream = TcpStream::connect((self.host.as_str(), self.port)).await?;
+        if !self.tls {
+            return self.request(stream, body).await;
+        }
+        let name = ServerName::try_from(self.host.as_str())
+            .map_err(|_| io::Error::other("invalid server name"))?;
+        let stream = tls_connector().connect(name, stream).await?;
+        self.request(stream, body).await
+    }
+
+    async fn request<S: AsyncRead + AsyncWrite + Unpin>(
+        &self,
+        mut stream: S,
+        body: &str,
+    ) -> io::Result<u16> {
+        let request = format!(
+            "POST {} HTTP/1.1\r\nHost: {}\r\nUser-Agent: opscan/{}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+            self.path,
+            self.authority,
+            env!("CARGO_PKG_VERSION"),
+            body.len()
+        );
+        stream.write_all(request.as_bytes()).await?;
+        stream.flush().await?;
+        http::read_status(&mut stream).await
+    }
+}
+
+fn tls_connector() -> TlsConnector {
+    let mut roots = RootCertStore::empty();
+    roots.add_server_trust_anchors(webpki_roots::TLS_SERVER_ROOTS.0.iter().map(|v| {
+        OwnedTrustAnchor::from_subject_spki_name_constraints(v.subject, v.spki, v.name_constraints)
+    }));
+    let config = ClientConfig::builder()
+        .with_safe_defaults()
+        .with_root_certificates(roots)
+        .with_no_client_auth();
+    TlsConnector::from(Arc::new(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        addresses::Host,
+        testing::{exchange, runtime},
+    };
+    use std::time::UNIX_EPOCH;
+    use tokio::net::TcpListener;
+
+    fn event() -> Event {
+        let host = Host::fixture("10.0.0.5", "web1");
+        Event::open(&host, 22, "ssh", UNIX_EPOCH)
+    }
+
+    /// Answer a connection with each of `statuses`, returning the requests
+    async fn stand_in(listener: TcpListener, statuses: Vec<&'static str>) -> Vec<String> {
+        let mut requests = vec![];
+        for status in statuses {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let reply = format!("HTTP/1.1 {status}\r\nContent-Length: 0\r\n\r\n");
+            let request = exchange(&mut stream, |v| v.ends_with(b"}"), reply.as_bytes()).await;
+            requests.push(String::from_utf8(request).unwrap());
+        }
+        requests
+    }
+
+    fn post(statuses: Vec<&'static str>) -> (io::Result<()>, Vec<String>) {
+        runtime().block_on(async {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let port = listener.local_addr().unwrap().port();
+            let mut webhook: Webhook = format!("http://127.0.0.1:{port}/hooks/scan?token=x")
+                .parse()
+                .unwrap();
+            webhook.retry_delay = Duration::from_millis(1);
+            let server = tokio::spawn(stand_in(listener, statuses));
+            let result = webhook.post(&event()).await;
+            (result, server.await.unwrap())
+        })
+    }
+
+    #[test]
+    fn test_parse_webhook() {
+        let webhook: Webhook = "https://hooks.example.com".parse().unwrap();
+        assert!(webhook.tls);
+        assert_eq!(
+            (webhook.host.as_str(), webhook.port),
+            ("hooks.example.com", 443)
+        );
+        assert_eq!(webhook.path, "/");
+        let webhook: Webhook = "http://[::1]:8080?a=1".parse().unwrap();
+        assert_eq!((webhook.host.as_str(), webhook.port), ("::1", 8080));
+        assert_eq!(webhook.authority, "[::1]:8080");
+        assert_eq!(webhook.path, "/?a=1");
+        assert!("ftp://example.com".parse::<Webhook>().is_err());
+        assert!("http://example.com:x/".parse::<Webhook>().is_err());
+    }
+
+    #[test]
+    fn test_webhook() {
+        let (result, requests) = post(vec!["503 Service Unavailable", "200 OK"]);
+        result.unwrap();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[1].starts_with("POST /hooks/scan?token=x HTTP/1.1\r\nHost: 127.0.0.1:"));
+        assert!(requests[1].ends_with(&format!(
+            "\r\n\r\n{}",
+            serde_json::to_string(&event()).unwrap()
+        )));
+        let (result, requests) = post(vec!["500 Internal Server Error"; 3]);
+        assert!(result.is_err());
+        assert_eq!(requests.len(), 3);
+        let (result, requests) = post(vec!["404 Not Found"]);
+        assert_eq!(result.unwrap_err().to_string(), "replied 404");
+        assert_eq!(requests.len(), 1);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_exec() {
+        let path = std::env::temp_dir().join(format!("opscan-exec-{}", std::process::id()));
+        let command = format!(
+            "echo \"$OPSCAN_EVENT $OPSCAN_HOST $OPSCAN_PORT $OPSCAN_SERVICE\" > {}",
+            path.display()
+        );
+        runtime().block_on(async {
+            exec(&command, &event()).await.unwrap();
+            assert_eq!(
+                exec("exit 3", &event()).await.unwrap_err(),
+                "exit status: 3"
+            );
+        });
+        assert_eq!(
+            std::fs::read_to_string(&path).unwrap(),
+            "open web1 22 ssh\n"
+        );
+        std::fs::remove_file(path).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_fire() {
+        let hooks = Hooks::new(Some("exit 3".into()), None).unwrap();
+        let warnings = Arc::new(Mutex::new(vec![]));
+        let warn = |warnings: &Arc<Mutex<Vec<String>>>| {
+            let warnings = warnings.clone();
+            move |line: &str| warnings.lock().unwrap().push(line.to_string())
+        };
+        runtime().block_on(async {
+            hooks.fire(event(), warn(&warnings));
+            while !hooks.tasks.lock().unwrap()[0].is_finished() {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+            // Finished notifications are not kept
+            hooks.fire(event(), warn(&warnings));
+            assert_eq!(hooks.tasks.lock().unwrap().len(), 1);
+            hooks.finish().await;
+        });
+        assert_eq!(
+            *warnings.lock().unwrap(),
+            ["warning: command for 1970-01-01T00:00:00Z open web1 22 ssh failed: exit status: 3";
+                2]
+        );
+    }
+}
@@ -0,0 +1,24 @@
+use std::io;
+
+use tokio::io::{AsyncRead, AsyncReadExt};
+
+/// Largest HTTP response head we are willing to read
+const MAX_RESPONSE_HEAD: usize = 8192;
+
+/// Read the head of an HTTP response, returning its status code
+///
+/// The head is read byte by byte so nothing past it is consumed, e.g. the start of a tunnel.
+pub async fn read_status<S: AsyncRead + Unpin>(stream: &mut S) -> io::Result<u16> {
+    let mut head = Vec::new();
+    while !head.ends_with(b"\r\n\r\n") {
+        if head.len() >= MAX_RESPONSE_HEAD {
+            return Err(io::Error::other("http response is too large"));
+        }
+        head.push(stream.read_u8().await?);
+    }
+    String::from_utf8_lossy(&head)
+        .split_whitespace()
+        .nth(1)
+        .and_then(|v| v.parse::<u16>().ok())
+        .ok_or_else(|| io::Error::other("invalid http response"))
+}
@@ -0,0 +1,234 @@
+//! Listening sockets of local host, read from /proc
+
+use std::{
+    collections::HashMap,
+    fs,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    time::Duration,
+};
+
+use futures::{stream, StreamExt};
+use tokio::net::TcpStream;
+
+use crate::ports::{service_name, Protocol};
+
+/// State of listening tcp sockets in /proc/net/tcp
+const TCP_LISTEN: &str = "0A";
+/// State of unconnected udp sockets in /proc/net/udp
+const UDP_CLOSE: &str = "07";
+
+/// A listening socket
+#[derive(Debug)]
+pub struct Listener {
+    pub protocol: Protocol,
+    pub addr: SocketAddr,
+    pub inode: u64,
+}
+
+/// Get listening sockets from /proc/net/{tcp,tcp6,udp,udp6}
+pub fn listeners() -> Result<Vec<Listener>, String> {
+    let mut listeners = vec![];
+    for (file, protocol) in [
+        ("tcp", Protocol::Tcp),
+        ("tcp6", Protocol::Tcp),
+        ("udp", Protocol::Udp),
+        ("udp6", Protocol::Udp),
+    ] {
+        let path = format!("/proc/net/{file}");
+        match fs::read_to_string(&path) {
+            Ok(content) => listeners.extend(parse_proc_net(&content, protocol)),
+            // IPv6 may be disabled
+            Err(_) if file.ends_with('6') => {}
+            Err(err) => return Err(format!("Failed to read '{path}', {err}")),
+        }
+    }
+    listeners.sort_by_key(|v| (v.protocol == Protocol::Udp, v.addr.port(), v.addr.is_ipv6()));
+    Ok(listeners)
+}
+
+/// Parse lines like `0: 0100007F:1F90 00000000:0000 0A ... 12345 ...`
+fn parse_proc_net(content: &str, protocol: Protocol) -> Vec<Listener> {
+    let state = match protocol {
+        Protocol::Tcp => TCP_LISTEN,
+        Protocol::Udp => UDP_CLOSE,
+    };
+    content
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            if fields.len() < 10 || fields[3] != state {
+                return None;
+            }
+            let addr = parse_hex_addr(fields[1])?;
+            let remote = parse_hex_addr(fields[2])?;
+            if !remote.ip().is_unspecified() {
+                return None;
+            }
+            let inode = fields[9].parse().ok()?;
+            Some(Listener {
+                protocol,
+                addr,
+                inode,
+            })
+        })
+        .collect()
+}
+
+/// Parse `0100007F:1F90`, IPs are printed as 32-bit words in host byte order
+fn parse_hex_addr(s: &str) -> Option<SocketAddr> {
+    let (ip, port) = s.split_once(':')?;
+    let port = u16::from_str_radix(port, 16).ok()?;
+    let ip = match ip.len() {
+        8 => IpAddr::V4(Ipv4Addr::from(
+            u32::from_str_radix(ip, 16).ok()?.to_ne_bytes(),
+        )),
+        32 => {
+            let mut octets = [0; 16];
+            for i in 0..4 {
+                let word = u32::from_str_radix(&ip[i * 8..i * 8 + 8], 16).ok()?;
+                octets[i * 4..i * 4 + 4].copy_from_slice(&word.to_ne_bytes());
+            }
+            IpAddr::V6(Ipv6Addr::from(octets))
+        }
+        _ => return None,
+    };
+    Some(SocketAddr::new(ip, port))
+}
+
+/// Map socket inodes to the pid and name of owning processes
+///
+/// Sockets of processes of other users are missing unless running as root
+fn socket_processes() -> HashMap<u64, (u32, String)> {
+    let mut processes = HashMap::new();
+    let Ok(entries) = fs::read_dir("/proc") else {
+        return processes;
+    };
+    for entry in entries.flatten() {
+        let Some(pid) = entry
+            .file_name()
+            .to_str()
+            .and_then(|v| v.parse::<u32>().ok())
+        else {
+            continue;
+        };
+        let Ok(fds) = fs::read_dir(entry.path().join("fd")) else {
+            continue;
+        };
+        let name = fs::read_to_string(entry.path().join("comm")).unwrap_or_default();
+        for fd in fds.flatten() {
+            let Ok(link) = fs::read_link(fd.path()) else {
+                continue;
+            };
+            let inode = link
+                .to_str()
+                .and_then(|v| v.strip_prefix("socket:["))
+                .and_then(|v| v.strip_suffix(']'))
+                .and_then(|v| v.parse::<u64>().ok());
+            if let Some(inode) = inode {
+                processes
+                    .entry(inode)
+                    .or_insert_with(|| (pid, name.trim().to_string()));
+            }
+        }
+    }
+    processes
+}
+
+/// Print listening sockets, optionally verify tcp ones by connecting to them
+pub async fn run(listeners: Vec<Listener>, verify: Option<Duration>) {
+    let processes = socket_processes();
+    let max_addr_len = listeners
+        .iter()
+        .map(|v| v.addr.ip().to_string().len())
+        .max()
+        .unwrap_or_default();
+    let max_port_len = listeners
+        .iter()
+        .map(|v| v.addr.port().to_string().len())
+        .max()
+        .unwrap_or_default();
+    let max_name_len = listeners
+        .iter()
+        .map(|v| listener_service(v).len())
+        .max()
+        .unwrap_or_default();
+    let max_process_len = listeners
+        .iter()
+        .map(|v| process_label(&processes, v.inode).len())
+        .max()
+        .unwrap_or_default();
+
+    let reachables: Vec<Option<bool>> = stream::iter(&listeners)
+        .map(|listener| async move {
+            let timeout = verify?;
+            if listener.protocol != Protocol::Tcp {
+                return None;
+            }
+            let ip = match listener.addr.ip() {
+                IpAddr::V4(ip) if ip.is_unspecified() => IpAddr::V4(Ipv4Addr::LOCALHOST),
+                IpAddr::V6(ip) if ip.is_unspecified() => IpAddr::V6(Ipv6Addr::LOCALHOST),
+                ip => ip,
+            };
+            let addr = SocketAddr::new(ip, listener.addr.port());
+            let ret = tokio::time::timeout(timeout, TcpStream::connect(addr)).await;
+            Some(matches!(ret, Ok(Ok(_))))
+        })
+        .buffered(64)
+        .collect()
+        .await;
+
+    for (listener, reachable) in listeners.iter().zip(reachables) {
+        let protocol = listener.protocol;
+        let addr = listener.addr.ip();
+        let port = listener.addr.port();
+        let name = listener_service(listener);
+        let process = process_label(&processes, listener.inode);
+        match reachable {
+            Some(reachable) => {
+                let reachable = if reachable { "reachable" } else { "unreachable" };
+                println!(
+                    "{protocol} {addr:max_addr_len$} {port:<max_port_len$} {name:max_name_len$} {process:max_process_len$} {reachable}"
+                );
+            }
+            None => println!(
+                "{protocol} {addr:max_addr_len$} {port:<max_port_len$} {name:max_name_len$} {process}"
+            ),
+        }
+    }
+}
+
+fn listener_service(listener: &Listener) -> &'static str {
+    let name = service_name(listener.addr.port(), listener.protocol);
+    match listener.protocol {
+        Protocol::Tcp => name.unwrap_or("unknown"),
+        Protocol::Udp => name.unwrap_or(""),
+    }
+}
+
+fn process_label(processes: &HashMap<u64, (u32, String)>, inode: u64) -> String {
+    match processes.get(&inode) {
+        Some((pid, name)) => format!("{pid}/{name}"),
+        None => "-".into(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_proc_net() {
+        let content = "  sl  local_address rem_address   st tx_queue rx_queue tr tm->when retrnsmt   uid  timeout inode
+   0: 0100007F:1F90 00000000:0000 0A 00000000:00000000 00:00000000 00000000  1000        0 12345 1 0000000000000000 100 0 0 10 0
+   1: 0100007F:1F90 0100007F:D431 01 00000000:00000000 00:00000000 00000000  1000        0 12346 1 0000000000000000 100 0 0 10 0";
+        let listeners = parse_proc_net(content, Protocol::Tcp);
+        assert_eq!(listeners.len(), 1);
+        assert_eq!(listeners[0].addr, "127.0.0.1:8080".parse().unwrap());
+        assert_eq!(listeners[0].inode, 12345);
+        assert_eq!(
+            parse_hex_addr("00000000000000000000000001000000:0016"),
+            Some("[::1]:22".parse().unwrap())
+        );
+    }
+}
@@ -1,74 +1,588 @@
 mod addresses;
+mod baseline;
+mod checkpoint;
 mod cli;
+mod config;
+mod db;
+mod event;
+mod expect;
+mod hooks;
+mod http;
+#[cfg(target_os = "linux")]
+mod local;
+mod policy;
 mod ports;
+mod progress;
+mod proxy;
+mod report;
 mod scanner;
+mod summary;
+#[cfg(test)]
+mod testing;
+mod watch;
 
-use std::{net::SocketAddr, time::Duration};
+use std::{
+    fmt,
+    io::IsTerminal,
+    path::Path,
+    time::{Duration, Instant, SystemTime},
+};
 
-use addresses::parse_addresses;
+use addresses::{
+    parse_target, parse_targets, read_targets_file, resolve_hosts, resolve_targets, Lookup,
+    ReverseResolver, Target, TargetAddr,
+};
+use baseline::Baseline;
+use checkpoint::Checkpoint;
 use clap::Parser;
-use cli::Cli;
-use ports::{FULL_PORTS, TOP1000_PORTS};
+use cli::{Cli, Command, HistoryQuery, ScanArgs, WatchArgs};
+use config::Config;
+use db::{Db, RunConfig};
+use expect::{Assertions, Expectation, Expected};
+use hooks::Hooks;
+use policy::Policy;
+use ports::{collect_ports, coverage, format_ports, PortValue, Protocol, ServicesFile};
+use progress::ProgressMode;
+use report::Report;
+use scanner::{Bind, Scanner};
+use watch::{jitter, State};
+
+/// Exit code when an assertion fails or the policy is violated
+const EXIT_ASSERTION_FAILED: i32 = 1;
+/// Exit code on invalid arguments or probe errors, same as clap's usage errors
+const EXIT_ERROR: i32 = 2;
+/// Exit code when there are no IPs to scan
+const EXIT_NO_TARGETS: i32 = 3;
+/// Exit code when the scan is stopped by a signal, as shells report SIGINT
+const EXIT_INTERRUPTED: i32 = 130;
+/// File to save progress to when a scan without `--checkpoint` is interrupted, never overwritten
+const INTERRUPT_CHECKPOINT: &str = "opscan-checkpoint.json";
+
+/// Print `err` and exit with `EXIT_ERROR`
+fn exit_error(err: impl fmt::Display) -> ! {
+    println!("error: {err}");
+    std::process::exit(EXIT_ERROR)
+}
 
 fn main() {
     let cli = Cli::parse();
-    let default_addrs = vec!["127.0.0.1".into()];
-    let addrs = if cli.addresses.is_empty() {
-        &default_addrs
-    } else {
-        &cli.addresses
+    let scan = match &cli.command {
+        Some(Command::Watch(args)) => &args.scan,
+        Some(Command::History(_)) | None => &cli.scan,
+    };
+    if let Err(err) = init(scan) {
+        exit_error(err);
+    }
+    match &cli.command {
+        Some(Command::Watch(args)) => run_watch(args),
+        Some(Command::History(query)) => run_history(query),
+        None if cli.local => run_local(&cli),
+        None => run_scan(&cli),
+    }
+}
+
+/// Load services file and config, check arguments
+fn init(args: &ScanArgs) -> Result<(), String> {
+    if let Some(path) = &args.services_file {
+        ServicesFile::init(path)?;
+    }
+    Config::init()?;
+    if args.interface.is_some()
+        && !cfg!(any(
+            target_os = "android",
+            target_os = "fuchsia",
+            target_os = "linux"
+        ))
+    {
+        return Err("--interface is only supported on Linux".to_string());
+    }
+    Ok(())
+}
+
+fn run_scan(cli: &Cli) {
+    let args = &cli.scan;
+    let policy = cli
+        .policy
+        .as_ref()
+        .map(|v| Policy::load(v).unwrap_or_else(|err| exit_error(err)));
+    let baseline = cli
+        .baseline
+        .as_ref()
+        .map(|v| Baseline::load(v).unwrap_or_else(|err| exit_error(err)));
+    let mut db = args
+        .db
+        .as_ref()
+        .map(|v| Db::open(v).unwrap_or_else(|err| exit_error(err)));
+    // Scan the targets of the policy unless targets are given
+    let default_addrs = match &policy {
+        Some(policy) => policy.targets.clone(),
+        None => vec!["127.0.0.1".into()],
+    };
+    let mut targets = load_targets(args, &default_addrs).unwrap_or_else(|err| exit_error(err));
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    // Defaults may depend on the resolved IPs of hosts
+    let (private, lookup) = runtime.block_on(resolve(args, &mut targets));
+
+    let expectations = cli
+        .expect_open
+        .iter()
+        .map(|v| Expectation::parse(v, Expected::Open))
+        .chain(
+            cli.expect_closed
+                .iter()
+                .map(|v| Expectation::parse(v, Expected::Closed)),
+        )
+        .collect::<Result<Vec<_>, _>>();
+    let assertions = match expectations {
+        Ok(v) if v.is_empty() => None,
+        Ok(v) => Some(Assertions::new(v)),
+        Err(err) => exit_error(err),
+    };
+
+    // Globally expected and required ports are always scanned
+    let always_ports = assertions
+        .iter()
+        .flat_map(|v| v.global_ports())
+        .chain(policy.iter().flat_map(|v| v.required_ports()))
+        .collect::<Vec<_>>();
+    let ports = scan_ports(args, private, &always_ports).unwrap_or_else(|err| exit_error(err));
+
+    if cli.coverage {
+        match coverage(&ports, Protocol::Tcp) {
+            Ok(v) => {
+                println!(
+                    "{} ports cover {:.2}% of historically-open tcp ports",
+                    ports.len(),
+                    v * 100.0
+                );
+                return;
+            }
+            Err(err) => exit_error(err),
+        }
+    }
+
+    let ports_spec = format_ports(&ports);
+    let fingerprint = checkpoint::fingerprint(&targets, &ports);
+    let checkpoint_path = cli.resume.as_ref().or(cli.checkpoint.as_ref());
+    let new_checkpoint = || {
+        Checkpoint::new(
+            target_addrs(args, &default_addrs),
+            args.file.clone(),
+            ports_spec.clone(),
+            fingerprint,
+        )
     };
-    let (ips, private) = parse_addresses(addrs);
+    let checkpoint = checkpoint_path.map(|path| {
+        let checkpoint = new_checkpoint();
+        if cli.resume.is_none() {
+            return checkpoint;
+        }
+        match Checkpoint::load(path) {
+            Ok(v) if v.is_scan_of(&checkpoint) => v,
+            Ok(_) => exit_error(format!(
+                "Checkpoint '{}' is of a scan of other targets or ports",
+                path.display()
+            )),
+            Err(err) => exit_error(err),
+        }
+    });
+    let (scanner, summary) = runtime.block_on(async {
+        let mut scanner = new_scanner(args, ports, &targets, private).interruptible();
+        if db.is_some() {
+            scanner = scanner.collect_open_ports().collect_up_hosts();
+        }
+        match (checkpoint_path, checkpoint) {
+            (Some(path), Some(checkpoint)) => {
+                scanner = scanner.checkpoint(path.clone(), checkpoint);
+            }
+            // Same as the options that cannot be resumed, progress is not tracked if it could not
+            // be saved anyway
+            _ if assertions.is_none()
+                && policy.is_none()
+                && baseline.is_none()
+                && !Path::new(INTERRUPT_CHECKPOINT).exists() =>
+            {
+                scanner =
+                    scanner.checkpoint_on_interrupt(INTERRUPT_CHECKPOINT.into(), new_checkpoint());
+            }
+            _ => {}
+        }
+        if let Some(assertions) = assertions {
+            scanner = scanner.assertions(assertions);
+        }
+        if let Some(policy) = policy {
+            scanner = scanner.policy(policy);
+        }
+        if let Some(baseline) = baseline {
+            scanner = scanner.baseline(baseline);
+        }
+        if let Some(hooks) = new_hooks(args) {
+            scanner = scanner.hooks(hooks);
+        }
+        if args.json || cli.diff_only {
+            scanner = scanner.collect_open_ports().quiet();
+        }
+        match args.stats_every {
+            Some(secs) => {
+                let interval = Duration::from_secs(secs.max(1));
+                scanner = scanner.progress(ProgressMode::Every(interval));
+            }
+            None if std::io::stderr().is_terminal() => {
+                scanner = scanner.progress(ProgressMode::Live);
+            }
+            None => {}
+        }
+        let warn = |line: &str| scanner.eprintln(line);
+        let summary = scanner.run(resolve_targets(targets, lookup, &warn)).await;
+        (scanner, summary)
+    });
 
-    if ips.is_empty() {
+    let interrupted = scanner.interrupted();
+    if summary.hosts == 0 && !interrupted {
         println!("error: No IPs could be resolved, aborting scan.");
-        std::process::exit(1);
+        std::process::exit(EXIT_NO_TARGETS);
     }
-    let ports = if cli.ports.is_empty() {
-        if private {
-            FULL_PORTS.to_vec()
+    // Unscanned ports would fail the assertions and policy of an interrupted scan
+    let (failures, violations) = match interrupted {
+        true => (vec![], vec![]),
+        false => (scanner.failures(), scanner.violations()),
+    };
+    let changes = cli.baseline.is_some().then(|| scanner.changes());
+    if args.json {
+        let open_ports = scanner.open_ports();
+        let report = Report {
+            open: &open_ports,
+            changes: changes.as_deref(),
+            violations: &violations,
+            failures: &failures,
+            summary: &summary,
+            interrupted,
+        };
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+    } else {
+        for change in changes.iter().flatten() {
+            println!("{change}");
+        }
+        eprintln!("{summary}");
+        for violation in &violations {
+            eprintln!("violation: {violation}");
+        }
+        for failure in &failures {
+            eprintln!("assertion failed: {failure}");
+        }
+    }
+    if interrupted {
+        if db.is_some() {
+            eprintln!("warning: The scan is interrupted, not recorded in the database");
+        }
+        std::process::exit(EXIT_INTERRUPTED);
+    }
+    if let Some(db) = &mut db {
+        let config = run_config(args, &default_addrs, ports_spec, &scanner);
+        let open_ports = scanner.open_ports();
+        let up_hosts = scanner.up_hosts();
+        if let Err(err) = db.record(&config, &summary, &open_ports, &up_hosts, SystemTime::now()) {
+            exit_error(err);
+        }
+    }
+    if !failures.is_empty() || !violations.is_empty() {
+        std::process::exit(EXIT_ASSERTION_FAILED);
+    }
+    if !summary.errors.is_empty() {
+        std::process::exit(EXIT_ERROR);
+    }
+}
+
+/// Scan on an interval and print the changes between scans
+fn run_watch(args: &WatchArgs) {
+    let scan = &args.scan;
+    let runtime = tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .build()
+        .unwrap();
+    // Hooks are notified of the events rather than of every open port
+    let hooks = new_hooks(scan);
+    let mut db = scan
+        .db
+        .as_ref()
+        .map(|v| Db::open(v).unwrap_or_else(|err| exit_error(err)));
+    let default_addrs = ["127.0.0.1".to_string()];
+    let mut state: Option<State> = None;
+    loop {
+        let start = Instant::now();
+        // Targets are loaded every round to pick up changes of target files
+        let targets = load_targets(scan, &default_addrs);
+        let mut targets = targets.unwrap_or_else(|err| exit_error(err));
+        let (private, lookup) = runtime.block_on(resolve(scan, &mut targets));
+        let ports = scan_ports(scan, private, &[]).unwrap_or_else(|err| exit_error(err));
+        let ports_spec = format_ports(&ports);
+        let (scanner, summary) = runtime.block_on(async {
+            let mut scanner = new_scanner(scan, ports, &targets, private)
+                .collect_open_ports()
+                .collect_up_hosts()
+                .quiet();
+            if let Some(state) = &state {
+                scanner = scanner.baseline(state.baseline());
+            }
+            if let Some(secs) = scan.stats_every {
+                let interval = Duration::from_secs(secs.max(1));
+                scanner = scanner.progress(ProgressMode::Every(interval));
+            }
+            let warn = |line: &str| scanner.eprintln(line);
+            let summary = scanner.run(resolve_targets(targets, lookup, &warn)).await;
+            (scanner, summary)
+        });
+
+        if summary.hosts == 0 {
+            // Keep the state rather than reporting every host as down
+            eprintln!("warning: No IPs could be resolved, skipped the scan");
         } else {
-            TOP1000_PORTS.to_vec()
+            let next = State::new(scanner.open_ports(), scanner.up_hosts());
+            if let Some(db) = &mut db {
+                let config = run_config(scan, &default_addrs, ports_spec, &scanner);
+                let (open_ports, up_hosts) = next.parts();
+                if let Err(err) =
+                    db.record(&config, &summary, open_ports, up_hosts, SystemTime::now())
+                {
+                    eprintln!("warning: {err}");
+                }
+            }
+            match &state {
+                Some(previous) => {
+                    let events = previous.events(&next, scanner.changes(), SystemTime::now());
+                    // Hooks are spawned on the runtime
+                    let _guard = runtime.enter();
+                    for event in events {
+                        match scan.json {
+                            true => println!("{}", serde_json::to_string(&event).unwrap()),
+                            false => println!("{event}"),
+                        }
+                        if let Some(hooks) = &hooks {
+                            hooks.fire(event, |line| eprintln!("{line}"));
+                        }
+                    }
+                    if let Some(hooks) = &hooks {
+                        runtime.block_on(hooks.finish());
+                    }
+                }
+                None => eprintln!(
+                    "Watching {} hosts ({} up) with {} open ports",
+                    summary.hosts, summary.hosts_up, summary.open
+                ),
+            }
+            state = Some(next);
+        }
+
+        let interval = Duration::from_secs(args.interval);
+        let delay =
+            interval.saturating_sub(start.elapsed()) + jitter(Duration::from_secs(args.jitter));
+        std::thread::sleep(delay);
+    }
+}
+
+/// Print the results of a history query
+fn run_history(query: &HistoryQuery) {
+    let result = match query {
+        HistoryQuery::Runs { db, limit } => Db::open(db)
+            .and_then(|db| db.runs(*limit))
+            .map(|runs| runs.iter().map(|v| v.to_string()).collect()),
+        HistoryQuery::Port { db, target } => history_port(db, target),
+        HistoryQuery::Changed { db, days } => {
+            let since = days
+                .checked_mul(86400)
+                .and_then(|v| SystemTime::now().checked_sub(Duration::from_secs(v)))
+                .ok_or_else(|| format!("--days {days} is out of range"));
+            since.and_then(|since| {
+                Db::open(db)
+                    .and_then(|db| db.changed_hosts(since))
+                    .map(|changes| changes.iter().map(|v| v.to_string()).collect())
+            })
         }
-    } else {
-        let mut ports: Vec<u16> = cli.ports.iter().flat_map(|v| v.values()).collect();
-        ports.dedup();
-        ports
     };
+    match result {
+        Ok(lines) => {
+            for line in lines {
+                println!("{line}");
+            }
+        }
+        Err(err) => exit_error(err),
+    }
+}
+
+fn history_port(db: &Path, target: &str) -> Result<Vec<String>, String> {
+    let parsed = parse_target(target)?;
+    let ports = parsed
+        .ports
+        .ok_or_else(|| format!("No ports in '{target}', e.g. 10.0.0.5:6379"))?;
+    let db = Db::open(db)?;
+    let mut lines = vec![];
+    for port in ports.iter() {
+        let events = db.port_events(&parsed.addr, *port)?;
+        lines.extend(events.iter().map(|v| v.to_string()));
+    }
+    if lines.is_empty() {
+        lines.push(format!("{target} was never seen open"));
+    }
+    Ok(lines)
+}
 
-    let mut addrs: Vec<(SocketAddr, String)> = vec![];
+/// Addresses to scan, `default_addrs` if no targets are given
+fn target_addrs(args: &ScanArgs, default_addrs: &[String]) -> Vec<String> {
+    match args.addresses.is_empty() && args.file.is_empty() {
+        true => default_addrs.to_vec(),
+        false => args.addresses.clone(),
+    }
+}
+
+/// Targets from the target files and addresses, or from `default_addrs` if there are none
+fn load_targets(args: &ScanArgs, default_addrs: &[String]) -> Result<Vec<Target>, String> {
+    let addrs = if args.addresses.is_empty() && args.file.is_empty() {
+        default_addrs
+    } else {
+        &args.addresses
+    };
+    let targets = args
+        .file
+        .iter()
+        .map(|v| read_targets_file(v))
+        .chain([parse_targets(addrs)])
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(targets.into_iter().flatten().collect())
+}
 
-    for (ip, addr) in &ips {
-        for port in &ports {
-            addrs.push((SocketAddr::new(*ip, *port), addr.to_string()));
+/// Ports to scan, followed by the `always_ports` that are missing
+fn scan_ports(args: &ScanArgs, private: bool, always_ports: &[u16]) -> Result<Vec<u16>, String> {
+    let mut values = args.ports.clone();
+    if let Some(ratio) = args.top_ratio {
+        values.push(PortValue::Ratio(ratio));
+    }
+    if values.iter().all(|v| v.is_exclude()) {
+        let default = if private {
+            PortValue::Range(1, 65535)
+        } else {
+            PortValue::Top(1000, Protocol::Tcp)
+        };
+        values.insert(0, default);
+    }
+    let mut ports = collect_ports(&values)?;
+    for port in always_ports {
+        if !ports.contains(port) {
+            ports.push(*port);
         }
     }
+    Ok(ports)
+}
+
+fn run_config(
+    args: &ScanArgs,
+    default_addrs: &[String],
+    ports: String,
+    scanner: &Scanner,
+) -> RunConfig {
+    RunConfig {
+        targets: target_addrs(args, default_addrs),
+        files: args.file.clone(),
+        ports,
+        timeout: scanner.timeout().as_millis() as u64,
+        concurrency: scanner.concurrency(),
+    }
+}
+
+/// Returns whether all targets are private and how the remaining host names are looked up
+///
+/// Host names are resolved up front only if a default depends on them, otherwise they are
+/// resolved while scanning. Through a proxy, they are resolved by the proxy and are not known
+/// to be private.
+async fn resolve(args: &ScanArgs, targets: &mut [Target]) -> (bool, Lookup) {
+    if args.proxy.is_some() {
+        let private = targets
+            .iter()
+            .all(|v| !matches!(v.addr, TargetAddr::Host(..)) && v.is_private());
+        return (private, Lookup::Proxy);
+    }
+    let is_host = |v: &Target| matches!(v.addr, TargetAddr::Host(_, None));
+    let private = targets
+        .iter()
+        .filter(|v| !is_host(v))
+        .all(|v| v.is_private());
+    let defaults = args.timeout.is_none()
+        || args.concurrency.is_none()
+        || (args.top_ratio.is_none() && args.ports.iter().all(|v| v.is_exclude()));
+    if !private || !defaults || !targets.iter().any(is_host) {
+        return (private, Lookup::Dns);
+    }
+    resolve_hosts(targets).await;
+    (targets.iter().all(|v| v.is_private()), Lookup::Done)
+}
 
-    let timeout = match (cli.timeout, private) {
+fn new_hooks(args: &ScanArgs) -> Option<Hooks> {
+    Hooks::new(args.on_open_exec.clone(), args.webhook.clone())
+}
+
+fn new_scanner(args: &ScanArgs, ports: Vec<u16>, targets: &[Target], private: bool) -> Scanner {
+    let timeout = match (args.timeout, private) {
         (Some(v), _) => v,
         (None, true) => 1000,
         (None, false) => 3000,
     };
     let timeout = Duration::from_millis(timeout as u64);
 
-    let count = addrs.len();
-    let concurrency = match (cli.concurrency, private) {
-        (Some(v), _) => v,
-        (None, true) => 65535,
-        (None, false) => 4096,
+    let concurrency = match (args.concurrency, private, &args.proxy) {
+        (Some(v), _, _) => v,
+        (None, _, Some(_)) => 256,
+        (None, true, None) => 65535,
+        (None, false, None) => 4096,
     };
 
-    let concurrency = (concurrency as usize).min(count);
+    let concurrency = (concurrency as usize).max(1);
     #[cfg(unix)]
     let concurrency = concurrency.min(nofile_limit() as usize);
 
-    let scanner = scanner::Scanner::new(&addrs, timeout, concurrency);
+    let bind = Bind {
+        ips: args.source_ip.clone(),
+        port: args.source_port,
+        interface: args.interface.clone(),
+    };
+    let mut scanner = Scanner::new(ports, timeout, concurrency, targets).bind(bind);
+    if let Some(proxy) = args.proxy.clone() {
+        scanner = scanner.proxy(proxy);
+    }
+    if args.reverse_dns {
+        let resolver = ReverseResolver::new().unwrap_or_else(|err| {
+            exit_error(format!(
+                "Failed to load the system resolver configuration: {err}"
+            ))
+        });
+        scanner = scanner.reverse_dns(resolver);
+    }
+    scanner
+}
 
+#[cfg(target_os = "linux")]
+fn run_local(cli: &Cli) {
+    let mut listeners = local::listeners().unwrap_or_else(|err| exit_error(err));
+    if !cli.scan.ports.is_empty() {
+        match collect_ports(&cli.scan.ports) {
+            Ok(ports) => listeners.retain(|v| ports.contains(&v.addr.port())),
+            Err(err) => exit_error(err),
+        }
+    }
+    let verify = cli
+        .verify
+        .then(|| Duration::from_millis(cli.scan.timeout.unwrap_or(1000) as u64));
     tokio::runtime::Builder::new_multi_thread()
         .enable_all()
         .build()
         .unwrap()
-        .block_on(scanner.run());
+        .block_on(local::run(listeners, verify));
+}
+
+#[cfg(not(target_os = "linux"))]
+fn run_local(_cli: &Cli) {
+    exit_error("--local is only supported on Linux");
 }
 
 #[cfg(unix)]
@@ -0,0 +1,297 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fmt, fs,
+    net::IpAddr,
+    path::Path,
+    sync::Mutex,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    addresses::{parse_target, Host, TargetAddr},
+    ports::parse_ports,
+    scanner::Status,
+};
+
+/// Port exposure policy, loaded from a TOML file like
+///
+/// ```toml
+/// [[rule]]
+/// name = "web"
+/// targets = ["10.0.0.0/24", "web1"]
+/// allow = "80"
+/// require = "443"
+/// ```
+#[derive(Debug)]
+pub struct Policy {
+    /// Targets of all rules, scanned when no targets are given
+    pub targets: Vec<String>,
+    rules: Vec<Rule>,
+    violations: Mutex<Vec<Violation>>,
+    /// Rule targets some host was probed on, with the required ports it was probed on
+    scanned: Mutex<HashMap<(usize, usize), HashSet<u16>>>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct PolicyFile {
+    #[serde(rename = "rule", default)]
+    rules: Vec<RuleFile>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuleFile {
+    name: String,
+    targets: Vec<String>,
+    /// Ports that may be open
+    #[serde(default)]
+    allow: String,
+    /// Ports that must be open
+    #[serde(default)]
+    require: String,
+}
+
+#[derive(Debug)]
+struct Rule {
+    name: String,
+    targets: Vec<TargetAddr>,
+    allow: HashSet<u16>,
+    require: Vec<u16>,
+}
+
+/// A port whose state breaks the policy
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+pub struct Violation {
+    pub host: String,
+    /// None if the target was not scanned
+    pub ip: Option<IpAddr>,
+    /// None if the target was not scanned and the rules require no ports
+    pub port: Option<u16>,
+    pub kind: ViolationKind,
+    /// Rules that apply to the host
+    pub rules: Vec<String>,
+    pub status: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ViolationKind {
+    /// Open but allowed by no rule
+    UnexpectedOpen,
+    /// Required by a rule but not open
+    RequiredClosed,
+    /// Target of a rule that was not scanned
+    NotScanned,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let (host, rules) = (&self.host, self.rules.join(", "));
+        let Some(port) = self.port else {
+            return write!(f, "{host} is targeted by {rules} but was not scanned");
+        };
+        match self.kind {
+            ViolationKind::UnexpectedOpen => {
+                write!(f, "{host} {port} is open but not allowed by {rules}")
+            }
+            ViolationKind::RequiredClosed => {
+                write!(
+                    f,
+                    "{host} {port} is required by {rules} but {}",
+                    self.status
+                )
+            }
+            ViolationKind::NotScanned => {
+                write!(
+                    f,
+                    "{host} {port} is required by {rules} but was not scanned"
+                )
+            }
+        }
+    }
+}
+
+impl Policy {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let content = fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read policy '{}', {err}", path.display()))?;
+        Self::parse(&content).map_err(|err| format!("Invalid policy '{}', {err}", path.display()))
+    }
+
+    fn parse(content: &str) -> Result<Self, String> {
+        let file: PolicyFile = toml::from_str(content).map_err(|err| err.to_string())?;
+        let mut targets = vec![];
+        let mut rules = vec![];
+        for rule in file.rules {
+            let mut rule_targets = vec![];
+            for target in &rule.targets {
+                let parsed = parse_target(target)?;
+                if parsed.ports.is_some() {
+                    return Err(format!(
+                        "Rule '{}' has ports in target '{target}'",
+                        rule.name
+                    ));
+                }
+                rule_targets.push(parsed.addr);
+                if !targets.contains(target) {
+                    targets.push(target.clone());
+                }
+            }
+            let ports = |spec: &str| match spec.trim() {
+                "" => Ok(vec![]),
+                spec => parse_ports(spec).map_err(|err| format!("Rule '{}', {err}", rule.name)),
+            };
+            rules.push(Rule {
+                allow: ports(&rule.allow)?.into_iter().collect(),
+                require: ports(&rule.require)?,
+                targets: rule_targets,
+                name: rule.name,
+            });
+        }
+        Ok(Self {
+            targets,
+            rules,
+            violations: Mutex::new(vec![]),
+            scanned: Mutex::new(HashMap::new()),
+        })
+    }
+
+    /// Ports required by any rule
+    pub fn required_ports(&self) -> impl Iterator<Item = u16> + '_ {
+        self.rules.iter().flat_map(|v| v.require.iter().copied())
+    }
+
+    /// Check the result of probing `port` of `host`, hosts no rule applies to are ignored
+    pub fn record(&self, host: &Host, port: u16, status: &Status) {
+        let mut rules = vec![];
+        for (i, rule) in self.rules.iter().enumerate() {
+            let mut applies = false;
+            for (j, target) in rule.targets.iter().enumerate() {
+                if !target.contains(host) {
+                    continue;
+                }
+                applies = true;
+                let mut scanned = self.scanned.lock().unwrap();
+                let ports = scanned.entry((i, j)).or_default();
+                if rule.require.contains(&port) {
+                    ports.insert(port);
+                }
+            }
+            if applies {
+                rules.push(rule);
+            }
+        }
+        if rules.is_empty() {
+            return;
+        }
+        let open = matches!(status, Status::Open);
+        let required = rules.iter().any(|v| v.require.contains(&port));
+        let allowed = required || rules.iter().any(|v| v.allow.contains(&port));
+        let kind = match (open, allowed, required) {
+            (true, false, _) => ViolationKind::UnexpectedOpen,
+            (false, _, true) => ViolationKind::RequiredClosed,
+            _ => return,
+        };
+        self.violations.lock().unwrap().push(Violation {
+            host: host.label.clone(),
+            ip: Some(host.ip),
+            port: Some(port),
+            kind,
+            rules: rules.iter().map(|v| v.name.clone()).collect(),
+            status: status.to_string(),
+        });
+    }
+
+    /// Violations, including rule targets and required ports that were never probed
+    pub fn violations(&self) -> Vec<Violation> {
+        let mut violations = self.violations.lock().unwrap().clone();
+        let scanned = self.scanned.lock().unwrap();
+        for (i, rule) in self.rules.iter().enumerate() {
+            for (j, target) in rule.targets.iter().enumerate() {
+                let ports = scanned.get(&(i, j));
+                let missing = rule
+                    .require
+                    .iter()
+                    .filter(|v| ports.is_none_or(|ports| !ports.contains(v)))
+                    .map(|v| Some(*v))
+                    .collect::<Vec<_>>();
+                let missing = match (ports, missing.is_empty()) {
+                    (None, true) => vec![None],
+                    _ => missing,
+                };
+                violations.extend(missing.into_iter().map(|port| Violation {
+                    host: target.label(),
+                    ip: None,
+                    port,
+                    kind: ViolationKind::NotScanned,
+                    rules: vec![rule.name.clone()],
+                    status: "not scanned".to_string(),
+                }));
+            }
+        }
+        violations.sort();
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy() {
+        let policy = Policy::parse(
+            r#"
+[[rule]]
+name = "web"
+targets = ["10.0.0.0/24", "web1"]
+allow = "80"
+require = "443"
+
+[[rule]]
+name = "ssh"
+targets = ["10.0.0.5", "db1"]
+allow = "22"
+
+[[rule]]
+name = "api"
+targets = ["web1", "web2"]
+require = "8443"
+"#,
+        )
+        .unwrap();
+        assert_eq!(
+            policy.targets,
+            ["10.0.0.0/24", "web1", "10.0.0.5", "db1", "web2"]
+        );
+        assert_eq!(policy.required_ports().collect::<Vec<_>>(), [443, 8443]);
+        let web1 = Host::fixture("10.0.1.1", "web1");
+        policy.record(&web1, 80, &Status::Open);
+        policy.record(&web1, 22, &Status::Open);
+        policy.record(&web1, 443, &Status::Filtered);
+        policy.record(&web1, 8443, &Status::Open);
+        let bastion = Host::fixture("10.0.0.5", "10.0.0.5");
+        policy.record(&bastion, 22, &Status::Open);
+        policy.record(&bastion, 443, &Status::Open);
+        let other = Host::fixture("192.168.0.1", "192.168.0.1");
+        policy.record(&other, 22, &Status::Open);
+        let violations = policy
+            .violations()
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            violations,
+            [
+                "db1 is targeted by ssh but was not scanned",
+                "web1 22 is open but not allowed by web, api",
+                "web1 443 is required by web, api but filtered",
+                "web2 8443 is required by api but was not scanned",
+            ]
+        );
+        assert!(Policy::parse("[[rule]]\nname = \"x\"\ntargets = [\"web1:80\"]").is_err());
+        assert!(Policy::parse("[[rule]]\nname = \"x\"\ntargets = []\nallow = \"foo\"").is_err());
+    }
+}
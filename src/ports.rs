@@ -1,8404 +1,273 @@
 //! Ports utility
 
-use std::{cmp::Ordering, str::FromStr};
+use std::{
+    cmp::Ordering,
+    collections::{hash_map::Entry, HashMap, HashSet},
+    path::Path,
+    str::FromStr,
+    sync::OnceLock,
+};
 
-use indexmap::IndexMap;
-use lazy_static::lazy_static;
+use crate::config::Config;
 
-lazy_static! {
-    /// Nmap top-N ports
-    ///
-    /// Use following commands to get list
-    /// curl -fsSL  https://raw.githubusercontent.com/nmap/nmap/master/nmap-services | awk '$2~/tcp$/' | sort -r -k3 | awk '{print $2" " $1}' | sed 's/\/tcp//' | awk '{print "("$1", \""$2"\"),"}'
-    pub static ref NAMP_TOP_PORTS: IndexMap<u16, &'static str> = {
-        let mut m = IndexMap::new();
-        m.insert(80, "http");
-        m.insert(23, "telnet");
-        m.insert(443, "https");
-        m.insert(21, "ftp");
-        m.insert(22, "ssh");
-        m.insert(25, "smtp");
-        m.insert(3389, "ms-wbt-sertver");
-        m.insert(110, "pop3");
-        m.insert(445, "microsoft-ds");
-        m.insert(139, "netbios-ssn");
-        m.insert(143, "imap");
-        m.insert(53, "domain");
-        m.insert(135, "msrpc");
-        m.insert(3306, "mysql");
-        m.insert(8080, "http-proxy");
-        m.insert(1723, "pptp");
-        m.insert(111, "rpcbind");
-        m.insert(995, "pop3s");
-        m.insert(993, "imaps");
-        m.insert(5900, "vnc");
-        m.insert(1025, "NFS-or-IIS");
-        m.insert(587, "submission");
-        m.insert(8888, "sun-answerbook");
-        m.insert(199, "smux");
-        m.insert(1720, "h323q931");
-        m.insert(465, "smtps");
-        m.insert(548, "afp");
-        m.insert(113, "ident");
-        m.insert(81, "hosts2-ns");
-        m.insert(6001, "X11:1");
-        m.insert(10000, "snet-sensor-mgmt");
-        m.insert(514, "shell");
-        m.insert(5060, "sip");
-        m.insert(179, "bgp");
-        m.insert(1026, "LSA-or-nterm");
-        m.insert(2000, "cisco-sccp");
-        m.insert(8443, "https-alt");
-        m.insert(8000, "http-alt");
-        m.insert(32768, "filenet-tms");
-        m.insert(554, "rtsp");
-        m.insert(26, "rsftp");
-        m.insert(1433, "ms-sql-s");
-        m.insert(49152, "unknown");
-        m.insert(2001, "dc");
-        m.insert(515, "printer");
-        m.insert(8008, "http");
-        m.insert(49154, "unknown");
-        m.insert(1027, "IIS");
-        m.insert(5666, "nrpe");
-        m.insert(646, "ldp");
-        m.insert(5000, "upnp");
-        m.insert(5631, "pcanywheredata");
-        m.insert(631, "ipp");
-        m.insert(49153, "unknown");
-        m.insert(8081, "blackice-icecap");
-        m.insert(2049, "nfs");
-        m.insert(88, "kerberos-sec");
-        m.insert(79, "finger");
-        m.insert(5800, "vnc-http");
-        m.insert(106, "pop3pw");
-        m.insert(2121, "ccproxy-ftp");
-        m.insert(1110, "nfsd-status");
-        m.insert(49155, "unknown");
-        m.insert(6000, "X11");
-        m.insert(513, "login");
-        m.insert(990, "ftps");
-        m.insert(5357, "wsdapi");
-        m.insert(427, "svrloc");
-        m.insert(49156, "unknown");
-        m.insert(543, "klogin");
-        m.insert(544, "kshell");
-        m.insert(5101, "admdog");
-        m.insert(144, "news");
-        m.insert(7, "echo");
-        m.insert(389, "ldap");
-        m.insert(8009, "ajp13");
-        m.insert(3128, "squid-http");
-        m.insert(444, "snpp");
-        m.insert(9999, "abyss");
-        m.insert(5009, "airport-admin");
-        m.insert(7070, "realsertver");
-        m.insert(5190, "aol");
-        m.insert(3000, "ppp");
-        m.insert(5432, "postgresql");
-        m.insert(1900, "upnp");
-        m.insert(3986, "mapper-ws_ethd");
-        m.insert(13, "daytime");
-        m.insert(1029, "ms-lsa");
-        m.insert(9, "discard");
-        m.insert(5051, "ida-agent");
-        m.insert(6646, "unknown");
-        m.insert(49157, "unknown");
-        m.insert(1028, "unknown");
-        m.insert(873, "rsync");
-        m.insert(1755, "wms");
-        m.insert(2717, "pn-requester");
-        m.insert(4899, "radmin");
-        m.insert(9100, "jetdirect");
-        m.insert(119, "nntp");
-        m.insert(37, "time");
-        m.insert(1000, "cadlock");
-        m.insert(3001, "nessus");
-        m.insert(5001, "commplex-link");
-        m.insert(82, "xfer");
-        m.insert(10010, "rxapi");
-        m.insert(1030, "iad1");
-        m.insert(9090, "zeus-admin");
-        m.insert(2107, "msmq-mgmt");
-        m.insert(1024, "kdm");
-        m.insert(2103, "zephyr-clt");
-        m.insert(6004, "X11:4");
-        m.insert(1801, "msmq");
-        m.insert(5050, "mmcc");
-        m.insert(19, "chargen");
-        m.insert(8031, "unknown");
-        m.insert(1041, "danf-ak2");
-        m.insert(255, "unknown");
-        m.insert(1049, "td-postman");
-        m.insert(1048, "neod2");
-        m.insert(2967, "symantec-av");
-        m.insert(1053, "remote-as");
-        m.insert(3703, "adobesertver-3");
-        m.insert(1056, "vfo");
-        m.insert(1065, "syscomlan");
-        m.insert(1064, "jstel");
-        m.insert(1054, "brvread");
-        m.insert(17, "qotd");
-        m.insert(808, "ccproxy-http");
-        m.insert(3689, "rendezvous");
-        m.insert(1031, "iad2");
-        m.insert(1044, "dcutility");
-        m.insert(1071, "bsquare-voip");
-        m.insert(5901, "vnc-1");
-        m.insert(100, "newacct");
-        m.insert(9102, "jetdirect");
-        m.insert(8010, "xmpp");
-        m.insert(2869, "icslap");
-        m.insert(1039, "sbl");
-        m.insert(5120, "barracuda-bbs");
-        m.insert(4001, "newoak");
-        m.insert(9000, "cslistener");
-        m.insert(2105, "eklogin");
-        m.insert(636, "ldapssl");
-        m.insert(1038, "mtqp");
-        m.insert(2601, "zebra");
-        m.insert(1, "tcpmux");
-        m.insert(7000, "afs3-filesertver");
-        m.insert(1066, "fpo-fns");
-        m.insert(1069, "cognex-insight");
-        m.insert(625, "apple-xsrvr-admin");
-        m.insert(311, "asip-webadmin");
-        m.insert(280, "http-mgmt");
-        m.insert(254, "unknown");
-        m.insert(4000, "remoteanything");
-        m.insert(1761, "landesk-rc");
-        m.insert(5003, "filemaker");
-        m.insert(2002, "globe");
-        m.insert(2005, "deslogin");
-        m.insert(1998, "x25-svc-port");
-        m.insert(1032, "iad3");
-        m.insert(1050, "java-or-OTGfileshare");
-        m.insert(6112, "dtspc");
-        m.insert(3690, "svn");
-        m.insert(1521, "oracle");
-        m.insert(2161, "apc-agent");
-        m.insert(6002, "X11:2");
-        m.insert(1080, "socks");
-        m.insert(2401, "cvspsertver");
-        m.insert(4045, "lockd");
-        m.insert(902, "iss-realsecure");
-        m.insert(7937, "nsrexecd");
-        m.insert(787, "qsc");
-        m.insert(1058, "nim");
-        m.insert(2383, "ms-olap4");
-        m.insert(32771, "sometimes-rpc5");
-        m.insert(1033, "netinfo");
-        m.insert(1040, "netsaint");
-        m.insert(1059, "nimreg");
-        m.insert(50000, "ibm-db2");
-        m.insert(5555, "freeciv");
-        m.insert(10001, "scp-config");
-        m.insert(1494, "citrix-ica");
-        m.insert(593, "http-rpc-epmap");
-        m.insert(2301, "compaqdiag");
-        m.insert(3, "compressnet");
-        m.insert(3268, "globalcatLDAP");
-        m.insert(7938, "lgtomapper");
-        m.insert(1234, "hotline");
-        m.insert(1022, "exp2");
-        m.insert(1074, "warmspotMgmt");
-        m.insert(8002, "teradataordbms");
-        m.insert(1036, "nsstp");
-        m.insert(1035, "multidropper");
-        m.insert(9001, "tor-orport");
-        m.insert(1037, "ams");
-        m.insert(464, "kpasswd5");
-        m.insert(497, "retrospect");
-        m.insert(1935, "rtmp");
-        m.insert(6666, "irc");
-        m.insert(2003, "finger");
-        m.insert(6543, "mythtv");
-        m.insert(1352, "lotusnotes");
-        m.insert(24, "priv-mail");
-        m.insert(3269, "globalcatLDAPssl");
-        m.insert(1111, "lmsocialsertver");
-        m.insert(407, "timbuktu");
-        m.insert(500, "isakmp");
-        m.insert(20, "ftp-data");
-        m.insert(2006, "invokator");
-        m.insert(3260, "iscsi");
-        m.insert(15000, "hydap");
-        m.insert(1218, "aeroflight-ads");
-        m.insert(1034, "zincite-a");
-        m.insert(4444, "krb524");
-        m.insert(264, "bgmp");
-        m.insert(2004, "mailbox");
-        m.insert(33, "dsp");
-        m.insert(1042, "afrog");
-        m.insert(42510, "caerpc");
-        m.insert(999, "garcon");
-        m.insert(3052, "powerchute");
-        m.insert(1023, "netvenuechat");
-        m.insert(1068, "instl_bootc");
-        m.insert(222, "rsh-spx");
-        m.insert(7100, "font-sertvice");
-        m.insert(888, "accessbuilder");
-        m.insert(563, "snews");
-        m.insert(1717, "fj-hdnet");
-        m.insert(2008, "conf");
-        m.insert(992, "telnets");
-        m.insert(32770, "sometimes-rpc3");
-        m.insert(32772, "sometimes-rpc7");
-        m.insert(7001, "afs3-callback");
-        m.insert(8082, "blackice-alerts");
-        m.insert(2007, "dectalk");
-        m.insert(5550, "sdadmind");
-        m.insert(2009, "news");
-        m.insert(5801, "vnc-http-1");
-        m.insert(1043, "boinc");
-        m.insert(512, "exec");
-        m.insert(2701, "sms-rcinfo");
-        m.insert(7019, "doceri-ctl");
-        m.insert(50001, "unknown");
-        m.insert(1700, "mps-raft");
-        m.insert(4662, "edonkey");
-        m.insert(2065, "dlsrpn");
-        m.insert(2010, "search");
-        m.insert(42, "namesertver");
-        m.insert(9535, "man");
-        m.insert(2602, "ripd");
-        m.insert(3333, "dec-notes");
-        m.insert(161, "snmp");
-        m.insert(5100, "admd");
-        m.insert(5002, "rfe");
-        m.insert(2604, "ospfd");
-        m.insert(4002, "mlchat-proxy");
-        m.insert(6059, "X11:59");
-        m.insert(1047, "neod1");
-        m.insert(8192, "sophos");
-        m.insert(8193, "sophos");
-        m.insert(2702, "sms-xfer");
-        m.insert(6789, "ibm-db2-admin");
-        m.insert(9595, "pds");
-        m.insert(1051, "optima-vnet");
-        m.insert(9594, "msgsys");
-        m.insert(9593, "cba8");
-        m.insert(16993, "amt-soap-https");
-        m.insert(16992, "amt-soap-http");
-        m.insert(5226, "hp-status");
-        m.insert(5225, "hp-sertver");
-        m.insert(32769, "filenet-rpc");
-        m.insert(3283, "netassistant");
-        m.insert(1052, "ddt");
-        m.insert(8194, "sophos");
-        m.insert(1055, "ansyslmd");
-        m.insert(1062, "veracity");
-        m.insert(9415, "unknown");
-        m.insert(8701, "unknown");
-        m.insert(8652, "unknown");
-        m.insert(8651, "unknown");
-        m.insert(8089, "unknown");
-        m.insert(65389, "unknown");
-        m.insert(65000, "unknown");
-        m.insert(64680, "unknown");
-        m.insert(64623, "unknown");
-        m.insert(55600, "unknown");
-        m.insert(55555, "unknown");
-        m.insert(52869, "unknown");
-        m.insert(35500, "unknown");
-        m.insert(33354, "unknown");
-        m.insert(23502, "unknown");
-        m.insert(20828, "unknown");
-        m.insert(1311, "rxmon");
-        m.insert(1060, "polestar");
-        m.insert(4443, "pharos");
-        m.insert(1067, "instl_boots");
-        m.insert(13782, "netbackup");
-        m.insert(5902, "vnc-2");
-        m.insert(366, "odmr");
-        m.insert(9050, "tor-socks");
-        m.insert(1002, "windows-icfw");
-        m.insert(85, "mit-ml-dev");
-        m.insert(5500, "hotline");
-        m.insert(5431, "park-agent");
-        m.insert(1864, "paradym-31");
-        m.insert(1863, "msnp");
-        m.insert(8085, "unknown");
-        m.insert(51103, "unknown");
-        m.insert(49999, "unknown");
-        m.insert(45100, "unknown");
-        m.insert(10243, "unknown");
-        m.insert(49, "tacacs");
-        m.insert(6667, "irc");
-        m.insert(90, "dnsix");
-        m.insert(27000, "flexlm0");
-        m.insert(1503, "imtc-mcs");
-        m.insert(6881, "bittorrent-tracker");
-        m.insert(1500, "vlsi-lm");
-        m.insert(8021, "ftp-proxy");
-        m.insert(340, "unknown");
-        m.insert(5566, "westec-connect");
-        m.insert(8088, "radan-http");
-        m.insert(2222, "EtherNetIP-1");
-        m.insert(9071, "unknown");
-        m.insert(8899, "ospf-lite");
-        m.insert(6005, "X11:5");
-        m.insert(9876, "sd");
-        m.insert(1501, "sas-3");
-        m.insert(5102, "admeng");
-        m.insert(32774, "sometimes-rpc11");
-        m.insert(32773, "sometimes-rpc9");
-        m.insert(9101, "jetdirect");
-        m.insert(5679, "activesync");
-        m.insert(163, "cmip-man");
-        m.insert(648, "rrp");
-        m.insert(146, "iso-tp0");
-        m.insert(1666, "netview-aix-6");
-        m.insert(901, "samba-swat");
-        m.insert(83, "mit-ml-dev");
-        m.insert(9207, "wap-vcal-s");
-        m.insert(8001, "vcom-tunnel");
-        m.insert(8083, "us-srv");
-        m.insert(8084, "websnp");
-        m.insert(5004, "avt-profile-1");
-        m.insert(3476, "nppmp");
-        m.insert(5214, "unknown");
-        m.insert(14238, "unknown");
-        m.insert(12345, "netbus");
-        m.insert(912, "apex-mesh");
-        m.insert(30, "unknown");
-        m.insert(2605, "bgpd");
-        m.insert(2030, "device2");
-        m.insert(6, "unknown");
-        m.insert(541, "uucp-rlogin");
-        m.insert(8007, "ajp12");
-        m.insert(3005, "deslogin");
-        m.insert(4, "unknown");
-        m.insert(1248, "hermes");
-        m.insert(2500, "rtssertv");
-        m.insert(880, "unknown");
-        m.insert(306, "unknown");
-        m.insert(4242, "vrml-multi-use");
-        m.insert(1097, "sunclustermgr");
-        m.insert(9009, "pichat");
-        m.insert(2525, "ms-v-worlds");
-        m.insert(1086, "cplscrambler-lg");
-        m.insert(1088, "cplscrambler-al");
-        m.insert(8291, "unknown");
-        m.insert(52822, "unknown");
-        m.insert(6101, "backupexec");
-        m.insert(900, "omginitialrefs");
-        m.insert(7200, "fodms");
-        m.insert(2809, "corbaloc");
-        m.insert(800, "mdbs_daemon");
-        m.insert(32775, "sometimes-rpc13");
-        m.insert(12000, "cce4x");
-        m.insert(1083, "ansoft-lm-1");
-        m.insert(211, "914c-g");
-        m.insert(987, "unknown");
-        m.insert(705, "agentx");
-        m.insert(20005, "btx");
-        m.insert(711, "cisco-tdp");
-        m.insert(13783, "netbackup");
-        m.insert(6969, "acmsoda");
-        m.insert(3071, "csd-mgmt-port");
-        m.insert(5269, "xmpp-sertver");
-        m.insert(5222, "xmpp-client");
-        m.insert(1085, "webobjects");
-        m.insert(1046, "wfremotertm");
-        m.insert(5987, "wbem-rmi");
-        m.insert(5989, "wbem-https");
-        m.insert(5988, "wbem-http");
-        m.insert(2190, "tivoconnect");
-        m.insert(3301, "tarantool");
-        m.insert(11967, "sysinfo-sp");
-        m.insert(8600, "asterix");
-        m.insert(3766, "sitewatch-s");
-        m.insert(7627, "soap-http");
-        m.insert(8087, "simplifymedia");
-        m.insert(30000, "ndmps");
-        m.insert(9010, "sdr");
-        m.insert(7741, "scriptview");
-        m.insert(14000, "scotty-ft");
-        m.insert(3367, "satvid-datalnk");
-        m.insert(1099, "rmiregistry");
-        m.insert(1098, "rmiactivation");
-        m.insert(3031, "eppc");
-        m.insert(2718, "pn-requester2");
-        m.insert(6580, "parsec-master");
-        m.insert(15002, "onep-tls");
-        m.insert(4129, "nuauth");
-        m.insert(6901, "jetstream");
-        m.insert(3827, "netmpi");
-        m.insert(3580, "nati-svrloc");
-        m.insert(2144, "lv-ffx");
-        m.insert(9900, "iua");
-        m.insert(8181, "intermapper");
-        m.insert(3801, "ibm-mgr");
-        m.insert(1718, "h323gatedisc");
-        m.insert(2811, "gsiftp");
-        m.insert(9080, "glrpc");
-        m.insert(2135, "gris");
-        m.insert(1045, "fpitp");
-        m.insert(2399, "fmpro-fdal");
-        m.insert(3017, "event_listener");
-        m.insert(10002, "documentum");
-        m.insert(1148, "elfiq-repl");
-        m.insert(9002, "dynamid");
-        m.insert(8873, "dxspider");
-        m.insert(2875, "dxmessagebase2");
-        m.insert(9011, "d-star");
-        m.insert(5718, "dpm");
-        m.insert(8086, "d-s-n");
-        m.insert(3998, "dnx");
-        m.insert(2607, "connection");
-        m.insert(11110, "sgi-soap");
-        m.insert(4126, "ddrepl");
-        m.insert(9618, "condor");
-        m.insert(2381, "compaq-https");
-        m.insert(1096, "cnrprotocol");
-        m.insert(3300, "ceph");
-        m.insert(3351, "btrieve");
-        m.insert(1073, "bridgecontrol");
-        m.insert(8333, "bitcoin");
-        m.insert(3784, "bfd-control");
-        m.insert(5633, "beorl");
-        m.insert(15660, "bex-xr");
-        m.insert(6123, "backup-express");
-        m.insert(3211, "avsecuremgmt");
-        m.insert(1078, "avocent-proxy");
-        m.insert(5910, "cm");
-        m.insert(5911, "cpdlc");
-        m.insert(3659, "apple-sasl");
-        m.insert(3551, "apcupsd");
-        m.insert(2260, "apc-2260");
-        m.insert(2160, "apc-2160");
-        m.insert(2100, "amiganetfs");
-        m.insert(16001, "fmsascon");
-        m.insert(3325, "active-net");
-        m.insert(3323, "active-net");
-        m.insert(1104, "xrl");
-        m.insert(9968, "unknown");
-        m.insert(9503, "unknown");
-        m.insert(9502, "unknown");
-        m.insert(9485, "unknown");
-        m.insert(9290, "unknown");
-        m.insert(9220, "unknown");
-        m.insert(8994, "unknown");
-        m.insert(8649, "unknown");
-        m.insert(8222, "unknown");
-        m.insert(7911, "unknown");
-        m.insert(7625, "unknown");
-        m.insert(7106, "unknown");
-        m.insert(65129, "unknown");
-        m.insert(63331, "unknown");
-        m.insert(6156, "unknown");
-        m.insert(6129, "unknown");
-        m.insert(60020, "unknown");
-        m.insert(5962, "unknown");
-        m.insert(5961, "unknown");
-        m.insert(5960, "unknown");
-        m.insert(5959, "unknown");
-        m.insert(5925, "unknown");
-        m.insert(5877, "unknown");
-        m.insert(5825, "unknown");
-        m.insert(5810, "unknown");
-        m.insert(58080, "unknown");
-        m.insert(57294, "unknown");
-        m.insert(50800, "unknown");
-        m.insert(50006, "unknown");
-        m.insert(50003, "unknown");
-        m.insert(49160, "unknown");
-        m.insert(49159, "unknown");
-        m.insert(49158, "unknown");
-        m.insert(48080, "unknown");
-        m.insert(40193, "unknown");
-        m.insert(34573, "unknown");
-        m.insert(34572, "unknown");
-        m.insert(34571, "unknown");
-        m.insert(3404, "unknown");
-        m.insert(33899, "unknown");
-        m.insert(32782, "unknown");
-        m.insert(32781, "unknown");
-        m.insert(31038, "unknown");
-        m.insert(30718, "unknown");
-        m.insert(28201, "unknown");
-        m.insert(27715, "unknown");
-        m.insert(25734, "unknown");
-        m.insert(24800, "unknown");
-        m.insert(22939, "unknown");
-        m.insert(21571, "unknown");
-        m.insert(20221, "unknown");
-        m.insert(20031, "unknown");
-        m.insert(19842, "unknown");
-        m.insert(19801, "unknown");
-        m.insert(19101, "unknown");
-        m.insert(17988, "unknown");
-        m.insert(1783, "unknown");
-        m.insert(16018, "unknown");
-        m.insert(16016, "unknown");
-        m.insert(15003, "unknown");
-        m.insert(14442, "unknown");
-        m.insert(13456, "unknown");
-        m.insert(10629, "unknown");
-        m.insert(10628, "unknown");
-        m.insert(10626, "unknown");
-        m.insert(10621, "unknown");
-        m.insert(10617, "unknown");
-        m.insert(10616, "unknown");
-        m.insert(10566, "unknown");
-        m.insert(10025, "unknown");
-        m.insert(10024, "unknown");
-        m.insert(10012, "unknown");
-        m.insert(1169, "tripwire");
-        m.insert(5030, "surfpass");
-        m.insert(5414, "statusd");
-        m.insert(1057, "startron");
-        m.insert(6788, "smc-http");
-        m.insert(1947, "sentinelsrm");
-        m.insert(1094, "rootd");
-        m.insert(1075, "rdrmshc");
-        m.insert(1108, "ratio-adp");
-        m.insert(4003, "pxc-splr-ft");
-        m.insert(1081, "pvuniwien");
-        m.insert(1093, "proofd");
-        m.insert(4449, "privatewire");
-        m.insert(1687, "nsjtp-ctrl");
-        m.insert(1840, "netopia-vo2");
-        m.insert(1100, "mctp");
-        m.insert(1063, "kyoceranetdev");
-        m.insert(1061, "kiosk");
-        m.insert(1107, "isoipsigport-2");
-        m.insert(1106, "isoipsigport-1");
-        m.insert(9500, "ismsertver");
-        m.insert(20222, "ipulse-ics");
-        m.insert(7778, "interwise");
-        m.insert(1077, "imgames");
-        m.insert(1310, "husky");
-        m.insert(2119, "gsigatekeeper");
-        m.insert(2492, "groove");
-        m.insert(1070, "gmrupdatesertv");
-        m.insert(20000, "dnp");
-        m.insert(8400, "cvd");
-        m.insert(1272, "cspmlockmgr");
-        m.insert(6389, "clariion-evr01");
-        m.insert(7777, "cbt");
-        m.insert(1072, "cardax");
-        m.insert(1079, "asprovatalk");
-        m.insert(1082, "amt-esd-prot");
-        m.insert(8402, "abarsd");
-        m.insert(89, "su-mit-tg");
-        m.insert(691, "resvc");
-        m.insert(1001, "webpush");
-        m.insert(32776, "sometimes-rpc15");
-        m.insert(1999, "tcp-id-port");
-        m.insert(212, "anet");
-        m.insert(2020, "xinupagesertver");
-        m.insert(6003, "X11:3");
-        m.insert(7002, "afs3-prsertver");
-        m.insert(2998, "iss-realsec");
-        m.insert(50002, "iiimsf");
-        m.insert(3372, "msdtc");
-        m.insert(898, "sun-manageconsole");
-        m.insert(5510, "secureidprop");
-        m.insert(32, "unknown");
-        m.insert(2033, "glogger");
-        m.insert(99, "metagram");
-        m.insert(749, "kerberos-adm");
-        m.insert(425, "icad-el");
-        m.insert(5903, "vnc-3");
-        m.insert(43, "whois");
-        m.insert(5405, "pcduo");
-        m.insert(6106, "isdninfo");
-        m.insert(13722, "netbackup");
-        m.insert(6502, "netop-rc");
-        m.insert(7007, "afs3-bos");
-        m.insert(458, "appleqtc");
-        m.insert(9666, "zoomcp");
-        m.insert(8100, "xprint-sertver");
-        m.insert(3737, "xpanel");
-        m.insert(5298, "presence");
-        m.insert(1152, "winpoplanmess");
-        m.insert(8090, "opsmessaging");
-        m.insert(2191, "tvbus");
-        m.insert(3011, "trusted-web");
-        m.insert(1580, "tn-tl-r1");
-        m.insert(9877, "x510");
-        m.insert(5200, "targus-getdata");
-        m.insert(3851, "spectraport");
-        m.insert(3371, "satvid-datalnk");
-        m.insert(3370, "satvid-datalnk");
-        m.insert(3369, "satvid-datalnk");
-        m.insert(7402, "rtps-dd-mt");
-        m.insert(5054, "rlm-admin");
-        m.insert(3918, "pktcablemmcops");
-        m.insert(3077, "orbix-loc-ssl");
-        m.insert(7443, "oracleas-https");
-        m.insert(3493, "nut");
-        m.insert(3828, "neteh");
-        m.insert(1186, "mysql-cluster");
-        m.insert(2179, "vmrdp");
-        m.insert(1183, "llsurfup-http");
-        m.insert(19315, "keyshadow");
-        m.insert(19283, "keysrvr");
-        m.insert(3995, "iss-mgmt-ssl");
-        m.insert(5963, "indy");
-        m.insert(1124, "hpvmmcontrol");
-        m.insert(8500, "fmtp");
-        m.insert(1089, "ff-annunc");
-        m.insert(10004, "emcrmirccd");
-        m.insert(2251, "dif-port");
-        m.insert(1087, "cplscrambler-in");
-        m.insert(5280, "xmpp-bosh");
-        m.insert(3871, "avocent-adsap");
-        m.insert(3030, "arepa-cas");
-        m.insert(62078, "iphone-sync");
-        m.insert(5904, "ag-swim");
-        m.insert(9091, "xmltec-xmlmail");
-        m.insert(4111, "xgrid");
-        m.insert(1334, "writesrv");
-        m.insert(3261, "winshadow");
-        m.insert(2522, "windb");
-        m.insert(5859, "wherehoo");
-        m.insert(1247, "visionpyramid");
-        m.insert(9944, "unknown");
-        m.insert(9943, "unknown");
-        m.insert(9110, "unknown");
-        m.insert(8654, "unknown");
-        m.insert(8254, "unknown");
-        m.insert(8180, "unknown");
-        m.insert(8011, "unknown");
-        m.insert(7512, "unknown");
-        m.insert(7435, "unknown");
-        m.insert(7103, "unknown");
-        m.insert(61900, "unknown");
-        m.insert(61532, "unknown");
-        m.insert(5922, "unknown");
-        m.insert(5915, "unknown");
-        m.insert(5822, "unknown");
-        m.insert(56738, "unknown");
-        m.insert(55055, "unknown");
-        m.insert(51493, "unknown");
-        m.insert(50636, "unknown");
-        m.insert(50389, "unknown");
-        m.insert(49175, "unknown");
-        m.insert(49165, "unknown");
-        m.insert(49163, "unknown");
-        m.insert(3546, "unknown");
-        m.insert(32784, "unknown");
-        m.insert(27355, "unknown");
-        m.insert(27353, "unknown");
-        m.insert(27352, "unknown");
-        m.insert(24444, "unknown");
-        m.insert(19780, "unknown");
-        m.insert(18988, "unknown");
-        m.insert(16012, "unknown");
-        m.insert(15742, "unknown");
-        m.insert(10778, "unknown");
-        m.insert(4006, "pxc-spvr");
-        m.insert(2126, "pktcable-cops");
-        m.insert(4446, "n1-fwp");
-        m.insert(3880, "igrs");
-        m.insert(1782, "hp-hcip");
-        m.insert(1296, "dproxy");
-        m.insert(9998, "distinct32");
-        m.insert(9040, "tor-trans");
-        m.insert(32779, "sometimes-rpc21");
-        m.insert(1021, "exp1");
-        m.insert(32777, "sometimes-rpc17");
-        m.insert(2021, "sertvexec");
-        m.insert(32778, "sometimes-rpc19");
-        m.insert(616, "sco-sysmgr");
-        m.insert(666, "doom");
-        m.insert(700, "epp");
-        m.insert(5802, "vnc-http-2");
-        m.insert(4321, "rwhois");
-        m.insert(545, "ekshell");
-        m.insert(1524, "ingreslock");
-        m.insert(1112, "msql");
-        m.insert(49400, "compaqdiag");
-        m.insert(84, "ctf");
-        m.insert(38292, "landesk-cba");
-        m.insert(2040, "lam");
-        m.insert(32780, "sometimes-rpc23");
-        m.insert(3006, "deslogind");
-        m.insert(2111, "kx");
-        m.insert(1084, "ansoft-lm-2");
-        m.insert(1600, "issd");
-        m.insert(2048, "dls-monitor");
-        m.insert(2638, "sybase");
-        m.insert(9111, "DragonIDSConsole");
-        m.insert(6699, "napster");
-        m.insert(16080, "osxwebadmin");
-        m.insert(6547, "powerchuteplus");
-        m.insert(6007, "X11:7");
-        m.insert(1533, "virtual-places");
-        m.insert(5560, "isqlplus");
-        m.insert(2106, "ekshell");
-        m.insert(1443, "ies-lm");
-        m.insert(667, "disclose");
-        m.insert(720, "unknown");
-        m.insert(2034, "scoremgr");
-        m.insert(555, "dsf");
-        m.insert(801, "device");
-        m.insert(6025, "x11");
-        m.insert(3221, "xnm-clear-text");
-        m.insert(3826, "wormux");
-        m.insert(9200, "wap-wsp");
-        m.insert(2608, "wag-sertvice");
-        m.insert(4279, "vrml-multi-use");
-        m.insert(7025, "vmsvc-2");
-        m.insert(11111, "vce");
-        m.insert(3527, "besertver-msg-q");
-        m.insert(1151, "unizensus");
-        m.insert(8200, "trivnet1");
-        m.insert(8300, "tmi");
-        m.insert(6689, "tsa");
-        m.insert(9878, "kca-sertvice");
-        m.insert(10009, "swdtp-sv");
-        m.insert(8800, "sunwebadmin");
-        m.insert(5730, "unieng");
-        m.insert(2394, "ms-olap2");
-        m.insert(2393, "ms-olap1");
-        m.insert(2725, "msolap-ptp2");
-        m.insert(5061, "sip-tls");
-        m.insert(6566, "sane-port");
-        m.insert(9081, "cisco-aqos");
-        m.insert(5678, "rrac");
-        m.insert(5906, "rpas-c2");
-        m.insert(3800, "pwgpsi");
-        m.insert(4550, "gds-adppiw-db");
-        m.insert(5080, "onscreen");
-        m.insert(1201, "nucleus-sand");
-        m.insert(3168, "poweronnud");
-        m.insert(3814, "neto-dcs");
-        m.insert(1862, "mysql-cm-agent");
-        m.insert(1114, "mini-sql");
-        m.insert(6510, "mcer-port");
-        m.insert(3905, "mupdate");
-        m.insert(8383, "m2msertvices");
-        m.insert(3914, "listcrt-port-2");
-        m.insert(3971, "lanrevsertver");
-        m.insert(3809, "apocd");
-        m.insert(5033, "jtnetd-sertver");
-        m.insert(7676, "imqbrokerd");
-        m.insert(3517, "802-11-iapp");
-        m.insert(4900, "hfcs");
-        m.insert(3869, "ovsam-mgmt");
-        m.insert(9418, "git");
-        m.insert(2909, "funk-dialout");
-        m.insert(3878, "fotogcad");
-        m.insert(8042, "fs-agent");
-        m.insert(1091, "ff-sm");
-        m.insert(1090, "ff-fms");
-        m.insert(3920, "exasoftport1");
-        m.insert(6567, "esp");
-        m.insert(1138, "encrypted_admin");
-        m.insert(3945, "emcads");
-        m.insert(1175, "dossier");
-        m.insert(10003, "documentum_s");
-        m.insert(3390, "dsc");
-        m.insert(5907, "dsd");
-        m.insert(3889, "dandv-tester");
-        m.insert(1131, "caspssl");
-        m.insert(8292, "blp3");
-        m.insert(5087, "biotic");
-        m.insert(1119, "bnetgame");
-        m.insert(1117, "ardus-mtrns");
-        m.insert(4848, "appsertv-http");
-        m.insert(7800, "asr");
-        m.insert(16000, "fmsas");
-        m.insert(3324, "active-net");
-        m.insert(3322, "active-net");
-        m.insert(5221, "3exmp");
-        m.insert(4445, "upnotifyp");
-        m.insert(9917, "unknown");
-        m.insert(9575, "unknown");
-        m.insert(9099, "unknown");
-        m.insert(9003, "unknown");
-        m.insert(8290, "unknown");
-        m.insert(8099, "unknown");
-        m.insert(8093, "unknown");
-        m.insert(8045, "unknown");
-        m.insert(7921, "unknown");
-        m.insert(7920, "unknown");
-        m.insert(7496, "unknown");
-        m.insert(6839, "unknown");
-        m.insert(6792, "unknown");
-        m.insert(6779, "unknown");
-        m.insert(6692, "unknown");
-        m.insert(6565, "unknown");
-        m.insert(60443, "unknown");
-        m.insert(5952, "unknown");
-        m.insert(5950, "unknown");
-        m.insert(5862, "unknown");
-        m.insert(5850, "unknown");
-        m.insert(5815, "unknown");
-        m.insert(5811, "unknown");
-        m.insert(57797, "unknown");
-        m.insert(56737, "unknown");
-        m.insert(5544, "unknown");
-        m.insert(55056, "unknown");
-        m.insert(5440, "unknown");
-        m.insert(54328, "unknown");
-        m.insert(54045, "unknown");
-        m.insert(52848, "unknown");
-        m.insert(52673, "unknown");
-        m.insert(50500, "unknown");
-        m.insert(50300, "unknown");
-        m.insert(49176, "unknown");
-        m.insert(49167, "unknown");
-        m.insert(49161, "unknown");
-        m.insert(44501, "unknown");
-        m.insert(44176, "unknown");
-        m.insert(41511, "unknown");
-        m.insert(40911, "unknown");
-        m.insert(32785, "unknown");
-        m.insert(32783, "unknown");
-        m.insert(30951, "unknown");
-        m.insert(27356, "unknown");
-        m.insert(26214, "unknown");
-        m.insert(25735, "unknown");
-        m.insert(19350, "unknown");
-        m.insert(18101, "unknown");
-        m.insert(18040, "unknown");
-        m.insert(17877, "unknown");
-        m.insert(16113, "unknown");
-        m.insert(15004, "unknown");
-        m.insert(14441, "unknown");
-        m.insert(12265, "unknown");
-        m.insert(12174, "unknown");
-        m.insert(10215, "unknown");
-        m.insert(10180, "unknown");
-        m.insert(4567, "tram");
-        m.insert(6100, "synchronet-db");
-        m.insert(4004, "pxc-roid");
-        m.insert(4005, "pxc-pin");
-        m.insert(8022, "oa-system");
-        m.insert(9898, "monkeycom");
-        m.insert(7999, "irdmi2");
-        m.insert(1271, "excw");
-        m.insert(1199, "dmidi");
-        m.insert(3003, "cgms");
-        m.insert(1122, "availant-mgr");
-        m.insert(2323, "3d-nfsd");
-        m.insert(4224, "xtell");
-        m.insert(2022, "down");
-        m.insert(617, "sco-dtmgr");
-        m.insert(777, "multiling-http");
-        m.insert(417, "onmux");
-        m.insert(714, "iris-xpcs");
-        m.insert(6346, "gnutella");
-        m.insert(981, "unknown");
-        m.insert(722, "unknown");
-        m.insert(1009, "unknown");
-        m.insert(4998, "maybe-veritas");
-        m.insert(70, "gopher");
-        m.insert(1076, "sns_credit");
-        m.insert(5999, "ncd-conf");
-        m.insert(10082, "amandaidx");
-        m.insert(765, "webster");
-        m.insert(301, "unknown");
-        m.insert(524, "ncp");
-        m.insert(668, "mecomm");
-        m.insert(2041, "interbase");
-        m.insert(6009, "X11:9");
-        m.insert(1417, "timbuktu-srv1");
-        m.insert(1434, "ms-sql-m");
-        m.insert(259, "esro-gen");
-        m.insert(44443, "coldfusion-auth");
-        m.insert(1984, "bigbrother");
-        m.insert(2068, "avocentkvm");
-        m.insert(7004, "afs3-kasertver");
-        m.insert(1007, "unknown");
-        m.insert(4343, "unicall");
-        m.insert(416, "silverplatter");
-        m.insert(2038, "objectmanager");
-        m.insert(6006, "X11:6");
-        m.insert(109, "pop2");
-        m.insert(4125, "rww");
-        m.insert(1461, "ibm_wrless_lan");
-        m.insert(9103, "jetdirect");
-        m.insert(911, "xact-backup");
-        m.insert(726, "unknown");
-        m.insert(1010, "surf");
-        m.insert(2046, "sdfunc");
-        m.insert(2035, "imsldoc");
-        m.insert(7201, "dlip");
-        m.insert(687, "asipregistry");
-        m.insert(2013, "raid-am");
-        m.insert(481, "dvs");
-        m.insert(125, "locus-map");
-        m.insert(6669, "irc");
-        m.insert(6668, "irc");
-        m.insert(903, "iss-console-mgr");
-        m.insert(1455, "esl-lm");
-        m.insert(683, "corba-iiop");
-        m.insert(1011, "unknown");
-        m.insert(2043, "isis-bcast");
-        m.insert(2047, "dls");
-        m.insert(256, "fw1-secureremote");
-        m.insert(9929, "nping-echo");
-        m.insert(5998, "ncd-diag");
-        m.insert(406, "imsp");
-        m.insert(31337, "Elite");
-        m.insert(44442, "coldfusion-auth");
-        m.insert(783, "spamassassin");
-        m.insert(843, "unknown");
-        m.insert(2042, "isis");
-        m.insert(2045, "cdfunc");
-        m.insert(4040, "yo-main");
-        m.insert(6060, "x11");
-        m.insert(6051, "x11");
-        m.insert(1145, "x9-icue");
-        m.insert(3916, "wysdmc");
-        m.insert(9443, "tungsten-https");
-        m.insert(9444, "wso2esb-console");
-        m.insert(1875, "westell-stats");
-        m.insert(7272, "watchme-7272");
-        m.insert(4252, "vrml-multi-use");
-        m.insert(4200, "vrml-multi-use");
-        m.insert(7024, "vmsvc");
-        m.insert(1556, "veritas_pbx");
-        m.insert(13724, "vnetd");
-        m.insert(1141, "mxomss");
-        m.insert(1233, "univ-appsertver");
-        m.insert(8765, "ultraseek-http");
-        m.insert(1137, "trim");
-        m.insert(3963, "thrp");
-        m.insert(5938, "teamviewer");
-        m.insert(9191, "sun-as-jpda");
-        m.insert(3808, "sun-as-iiops-ca");
-        m.insert(8686, "sun-as-jmxrmi");
-        m.insert(3981, "starfish");
-        m.insert(2710, "sso-sertvice");
-        m.insert(3852, "sse-app-config");
-        m.insert(3849, "spw-dnspreload");
-        m.insert(3944, "sops");
-        m.insert(3853, "sscan");
-        m.insert(9988, "nsesrvr");
-        m.insert(1163, "sddp");
-        m.insert(4164, "silverpeakcomm");
-        m.insert(3820, "scp");
-        m.insert(6481, "sertvicetags");
-        m.insert(3731, "smap");
-        m.insert(5081, "sdl-ets");
-        m.insert(40000, "safetynetp");
-        m.insert(8097, "sac");
-        m.insert(4555, "rsip");
-        m.insert(3863, "asap-tcp");
-        m.insert(1287, "routematch");
-        m.insert(4430, "rsqlsertver");
-        m.insert(7744, "raqmon-pdu");
-        m.insert(1812, "radius");
-        m.insert(7913, "qo-secure");
-        m.insert(1166, "qsm-remote");
-        m.insert(1164, "qsm-proxy");
-        m.insert(1165, "qsm-gui");
-        m.insert(8019, "qbdb");
-        m.insert(10160, "qb-db-sertver");
-        m.insert(4658, "playsta2-app");
-        m.insert(7878, "owms");
-        m.insert(3304, "opsession-srvr");
-        m.insert(3307, "opsession-prxy");
-        m.insert(1259, "opennl-voice");
-        m.insert(1092, "obrpd");
-        m.insert(7278, "oma-dcdocbs");
-        m.insert(3872, "oem-agent");
-        m.insert(10008, "octopus");
-        m.insert(7725, "nitrogen");
-        m.insert(3410, "networklenss");
-        m.insert(1971, "netop-school");
-        m.insert(3697, "nw-license");
-        m.insert(3859, "nav-port");
-        m.insert(3514, "must-p2p");
-        m.insert(4949, "munin");
-        m.insert(4147, "vrxpsertvman");
-        m.insert(7900, "mevent");
-        m.insert(5353, "mdns");
-        m.insert(3931, "msr-plugin-port");
-        m.insert(8675, "msi-cps-rm");
-        m.insert(1277, "miva-mqs");
-        m.insert(3957, "mqe-broker");
-        m.insert(1213, "mpc-lifenet");
-        m.insert(2382, "ms-olap3");
-        m.insert(6600, "mshvlm");
-        m.insert(3700, "lrs-paging");
-        m.insert(3007, "lotusmtap");
-        m.insert(4080, "lorica-in");
-        m.insert(1113, "ltp-deepspace");
-        m.insert(3969, "landmarks");
-        m.insert(1132, "kvm-via-ip");
-        m.insert(1309, "jtag-sertver");
-        m.insert(3848, "item");
-        m.insert(7281, "itactionsertver2");
-        m.insert(3907, "imoguia-port");
-        m.insert(3972, "iconp");
-        m.insert(3968, "ianywhere-dbns");
-        m.insert(1126, "hpvmmdata");
-        m.insert(5223, "hpvirtgrp");
-        m.insert(1217, "hpss-ndapi");
-        m.insert(3870, "ovsam-d-agent");
-        m.insert(3941, "homeportal-web");
-        m.insert(8293, "hiperscan-id");
-        m.insert(1719, "h323gatestat");
-        m.insert(1300, "h323hostcallsc");
-        m.insert(2099, "h2250-annex-g");
-        m.insert(6068, "gsmp");
-        m.insert(3013, "gilatskysurfer");
-        m.insert(3050, "gds_db");
-        m.insert(1174, "fnet-remote-ui");
-        m.insert(3684, "faxstfx-port");
-        m.insert(2170, "eyetv");
-        m.insert(3792, "sitewatch");
-        m.insert(1216, "etebac5");
-        m.insert(5151, "esri_sde");
-        m.insert(7123, "snif");
-        m.insert(7080, "empowerid");
-        m.insert(22222, "easyengine");
-        m.insert(4143, "oidsr");
-        m.insert(5868, "diameters");
-        m.insert(8889, "ddi-tcp-2");
-        m.insert(12006, "dbisamsertver2");
-        m.insert(1121, "rmpp");
-        m.insert(3119, "d2000kernel");
-        m.insert(8015, "cfg-cloud");
-        m.insert(10023, "cefd-vmp");
-        m.insert(3824, "acp-policy");
-        m.insert(1154, "resacommunity");
-        m.insert(20002, "commtact-http");
-        m.insert(3888, "ciphire-sertv");
-        m.insert(4009, "chimera-hwm");
-        m.insert(5063, "csrpc");
-        m.insert(3376, "cdbroker");
-        m.insert(1185, "catchpole");
-        m.insert(1198, "cajo-discovery");
-        m.insert(1192, "caids-sensor");
-        m.insert(1972, "intersys-cache");
-        m.insert(1130, "casp");
-        m.insert(1149, "bvtsonar");
-        m.insert(4096, "bre");
-        m.insert(6500, "boks");
-        m.insert(8294, "blp4");
-        m.insert(3990, "bv-is");
-        m.insert(3993, "bv-agent");
-        m.insert(8016, "ads-s");
-        m.insert(5242, "attune");
-        m.insert(3846, "an-pcp");
-        m.insert(3929, "smauth-port");
-        m.insert(1187, "alias");
-        m.insert(5074, "alesquery");
-        m.insert(5909, "agma");
-        m.insert(8766, "amcs");
-        m.insert(5905, "asmgcs");
-        m.insert(1102, "adobesertver-1");
-        m.insert(2800, "acc-raid");
-        m.insert(9941, "unknown");
-        m.insert(9914, "unknown");
-        m.insert(9815, "unknown");
-        m.insert(9673, "unknown");
-        m.insert(9643, "unknown");
-        m.insert(9621, "unknown");
-        m.insert(9501, "unknown");
-        m.insert(9409, "unknown");
-        m.insert(9198, "unknown");
-        m.insert(9197, "unknown");
-        m.insert(9098, "unknown");
-        m.insert(8996, "unknown");
-        m.insert(8987, "unknown");
-        m.insert(8877, "unknown");
-        m.insert(8676, "unknown");
-        m.insert(8648, "unknown");
-        m.insert(8540, "unknown");
-        m.insert(8481, "unknown");
-        m.insert(8385, "unknown");
-        m.insert(8189, "unknown");
-        m.insert(8098, "unknown");
-        m.insert(8095, "unknown");
-        m.insert(8050, "unknown");
-        m.insert(7929, "unknown");
-        m.insert(7770, "unknown");
-        m.insert(7749, "unknown");
-        m.insert(7438, "unknown");
-        m.insert(7241, "unknown");
-        m.insert(7051, "unknown");
-        m.insert(7050, "unknown");
-        m.insert(6896, "unknown");
-        m.insert(6732, "unknown");
-        m.insert(6711, "unknown");
-        m.insert(65310, "unknown");
-        m.insert(6520, "unknown");
-        m.insert(6504, "unknown");
-        m.insert(6247, "unknown");
-        m.insert(6203, "unknown");
-        m.insert(61613, "unknown");
-        m.insert(60642, "unknown");
-        m.insert(60146, "unknown");
-        m.insert(60123, "unknown");
-        m.insert(5981, "unknown");
-        m.insert(5940, "unknown");
-        m.insert(59202, "unknown");
-        m.insert(59201, "unknown");
-        m.insert(59200, "unknown");
-        m.insert(5918, "unknown");
-        m.insert(5914, "unknown");
-        m.insert(59110, "unknown");
-        m.insert(5899, "unknown");
-        m.insert(58838, "unknown");
-        m.insert(5869, "unknown");
-        m.insert(58632, "unknown");
-        m.insert(58630, "unknown");
-        m.insert(5823, "unknown");
-        m.insert(5818, "unknown");
-        m.insert(5812, "unknown");
-        m.insert(5807, "unknown");
-        m.insert(58002, "unknown");
-        m.insert(58001, "unknown");
-        m.insert(57665, "unknown");
-        m.insert(55576, "unknown");
-        m.insert(55020, "unknown");
-        m.insert(53535, "unknown");
-        m.insert(5339, "unknown");
-        m.insert(53314, "unknown");
-        m.insert(53313, "unknown");
-        m.insert(53211, "unknown");
-        m.insert(52853, "unknown");
-        m.insert(52851, "unknown");
-        m.insert(52850, "unknown");
-        m.insert(52849, "unknown");
-        m.insert(52847, "unknown");
-        m.insert(5279, "unknown");
-        m.insert(52735, "unknown");
-        m.insert(52710, "unknown");
-        m.insert(52660, "unknown");
-        m.insert(5212, "unknown");
-        m.insert(51413, "unknown");
-        m.insert(51191, "unknown");
-        m.insert(5040, "unknown");
-        m.insert(50050, "unknown");
-        m.insert(49401, "unknown");
-        m.insert(49236, "unknown");
-        m.insert(49195, "unknown");
-        m.insert(49186, "unknown");
-        m.insert(49171, "unknown");
-        m.insert(49168, "unknown");
-        m.insert(49164, "unknown");
-        m.insert(4875, "unknown");
-        m.insert(47544, "unknown");
-        m.insert(46996, "unknown");
-        m.insert(46200, "unknown");
-        m.insert(44709, "unknown");
-        m.insert(41523, "unknown");
-        m.insert(41064, "unknown");
-        m.insert(40811, "unknown");
-        m.insert(3994, "unknown");
-        m.insert(39659, "unknown");
-        m.insert(39376, "unknown");
-        m.insert(39136, "unknown");
-        m.insert(38188, "unknown");
-        m.insert(38185, "unknown");
-        m.insert(37839, "unknown");
-        m.insert(35513, "unknown");
-        m.insert(33554, "unknown");
-        m.insert(33453, "unknown");
-        m.insert(32835, "unknown");
-        m.insert(32822, "unknown");
-        m.insert(32816, "unknown");
-        m.insert(32803, "unknown");
-        m.insert(32792, "unknown");
-        m.insert(32791, "unknown");
-        m.insert(30704, "unknown");
-        m.insert(30005, "unknown");
-        m.insert(29831, "unknown");
-        m.insert(29672, "unknown");
-        m.insert(28211, "unknown");
-        m.insert(27357, "unknown");
-        m.insert(26470, "unknown");
-        m.insert(23796, "unknown");
-        m.insert(23052, "unknown");
-        m.insert(2196, "unknown");
-        m.insert(21792, "unknown");
-        m.insert(19900, "unknown");
-        m.insert(18264, "unknown");
-        m.insert(18018, "unknown");
-        m.insert(17595, "unknown");
-        m.insert(16851, "unknown");
-        m.insert(16800, "unknown");
-        m.insert(16705, "unknown");
-        m.insert(15402, "unknown");
-        m.insert(15001, "unknown");
-        m.insert(12452, "unknown");
-        m.insert(12380, "unknown");
-        m.insert(12262, "unknown");
-        m.insert(12215, "unknown");
-        m.insert(12059, "unknown");
-        m.insert(12021, "unknown");
-        m.insert(10873, "unknown");
-        m.insert(10058, "unknown");
-        m.insert(10034, "unknown");
-        m.insert(10022, "unknown");
-        m.insert(10011, "unknown");
-        m.insert(2910, "tdaccess");
-        m.insert(1594, "sixtrak");
-        m.insert(1658, "sixnetudr");
-        m.insert(1583, "simbaexpress");
-        m.insert(3162, "sflm");
-        m.insert(2920, "roboeda");
-        m.insert(26000, "quake");
-        m.insert(2366, "qip-login");
-        m.insert(4600, "piranha1");
-        m.insert(1688, "nsjtp-data");
-        m.insert(1322, "novation");
-        m.insert(2557, "nicetec-mgmt");
-        m.insert(1095, "nicelink");
-        m.insert(1839, "netopia-vo1");
-        m.insert(2288, "netml");
-        m.insert(1123, "murray");
-        m.insert(5968, "mppolicy-v5");
-        m.insert(9600, "micromuse-ncpw");
-        m.insert(1244, "isbconference1");
-        m.insert(1641, "invision");
-        m.insert(2200, "ici");
-        m.insert(1105, "ftranhc");
-        m.insert(6550, "fg-sysupdate");
-        m.insert(5501, "fcp-addr-srvr2");
-        m.insert(1328, "ewall");
-        m.insert(2968, "enpp");
-        m.insert(1805, "enl-name");
-        m.insert(1914, "elm-momentum");
-        m.insert(1974, "drp");
-        m.insert(31727, "diagd");
-        m.insert(3400, "csms2");
-        m.insert(1301, "ci3-software-1");
-        m.insert(1147, "capioverlan");
-        m.insert(1721, "caicci");
-        m.insert(1236, "bvcontrol");
-        m.insert(2501, "rtsclient");
-        m.insert(2012, "ttyinfo");
-        m.insert(6222, "radmind");
-        m.insert(1220, "quicktime");
-        m.insert(1109, "kpop");
-        m.insert(1347, "bbn-mmc");
-        m.insert(502, "mbap");
-        m.insert(701, "lmp");
-        m.insert(2232, "ivs-video");
-        m.insert(2241, "ivsd");
-        m.insert(4559, "hylafax");
-        m.insert(710, "entrust-ash");
-        m.insert(10005, "stel");
-        m.insert(5680, "canna");
-        m.insert(623, "oob-ws-http");
-        m.insert(913, "apex-edge");
-        m.insert(1103, "xaudio");
-        m.insert(780, "wpgs");
-        m.insert(930, "unknown");
-        m.insert(803, "unknown");
-        m.insert(725, "unknown");
-        m.insert(639, "msdp");
-        m.insert(540, "uucp");
-        m.insert(102, "iso-tsap");
-        m.insert(5010, "telelpathstart");
-        m.insert(1222, "nerv");
-        m.insert(953, "rndc");
-        m.insert(8118, "privoxy");
-        m.insert(9992, "issc");
-        m.insert(1270, "sssertver");
-        m.insert(27, "nsw-fe");
-        m.insert(123, "ntp");
-        m.insert(86, "mfcobol");
-        m.insert(447, "ddm-dfm");
-        m.insert(1158, "lsnr");
-        m.insert(442, "cvc_hostd");
-        m.insert(18000, "biimenu");
-        m.insert(419, "ariel1");
-        m.insert(931, "unknown");
-        m.insert(874, "unknown");
-        m.insert(856, "unknown");
-        m.insert(250, "unknown");
-        m.insert(475, "tcpnethaspsrv");
-        m.insert(2044, "rimsl");
-        m.insert(441, "decvms-sysmgt");
-        m.insert(210, "z39.50");
-        m.insert(6008, "X11:8");
-        m.insert(7003, "afs3-vlsertver");
-        m.insert(5803, "vnc-http-3");
-        m.insert(1008, "ufsd");
-        m.insert(556, "remotefs");
-        m.insert(6103, "RETS-or-BackupExec");
-        m.insert(829, "pkix-3-ca-ra");
-        m.insert(3299, "saprouter");
-        m.insert(55, "isi-gl");
-        m.insert(713, "iris-xpc");
-        m.insert(1550, "3m-image-lm");
-        m.insert(709, "entrustmanager");
-        m.insert(2628, "dict");
-        m.insert(223, "cdc");
-        m.insert(3025, "slnp");
-        m.insert(87, "priv-term-l");
-        m.insert(57, "priv-term");
-        m.insert(10083, "amidxtape");
-        m.insert(5520, "sdlog");
-        m.insert(980, "unknown");
-        m.insert(251, "unknown");
-        m.insert(1013, "unknown");
-        m.insert(9152, "ms-sql2000");
-        m.insert(1212, "lupa");
-        m.insert(2433, "codasrv-se");
-        m.insert(1516, "vpad");
-        m.insert(333, "texar");
-        m.insert(2011, "raid-cc");
-        m.insert(748, "ris-cm");
-        m.insert(1350, "editbench");
-        m.insert(1526, "pdap-np");
-        m.insert(7010, "ups-onlinet");
-        m.insert(1241, "nessus");
-        m.insert(127, "locus-con");
-        m.insert(157, "knet-cmp");
-        m.insert(220, "imap3");
-        m.insert(1351, "equationbuilder");
-        m.insert(2067, "dlswpn");
-        m.insert(684, "corba-iiop-ssl");
-        m.insert(77, "priv-rje");
-        m.insert(4333, "msql");
-        m.insert(674, "acap");
-        m.insert(943, "unknown");
-        m.insert(904, "unknown");
-        m.insert(840, "unknown");
-        m.insert(825, "unknown");
-        m.insert(792, "unknown");
-        m.insert(732, "unknown");
-        m.insert(1020, "unknown");
-        m.insert(1006, "unknown");
-        m.insert(657, "rmc");
-        m.insert(557, "openvms-sysipc");
-        m.insert(610, "npmp-local");
-        m.insert(1547, "laplink");
-        m.insert(523, "ibm-db2");
-        m.insert(996, "xtreelic");
-        m.insert(2025, "ellpack");
-        m.insert(602, "xmlrpc-beep");
-        m.insert(3456, "vat");
-        m.insert(862, "twamp-control");
-        m.insert(600, "ipcsertver");
-        m.insert(2903, "extensisportfolio");
-        m.insert(257, "fw1-mc-fwmodule");
-        m.insert(1522, "rna-lm");
-        m.insert(1353, "relief");
-        m.insert(6662, "radmind");
-        m.insert(998, "busboy");
-        m.insert(660, "mac-srvr-admin");
-        m.insert(729, "netviewdm1");
-        m.insert(730, "netviewdm2");
-        m.insert(731, "netviewdm3");
-        m.insert(782, "hp-managed-node");
-        m.insert(1357, "pegboard");
-        m.insert(3632, "distccd");
-        m.insert(3399, "sapeps");
-        m.insert(6050, "arcsertve");
-        m.insert(2201, "ats");
-        m.insert(971, "unknown");
-        m.insert(969, "unknown");
-        m.insert(905, "unknown");
-        m.insert(846, "unknown");
-        m.insert(839, "unknown");
-        m.insert(823, "unknown");
-        m.insert(822, "unknown");
-        m.insert(795, "unknown");
-        m.insert(790, "unknown");
-        m.insert(778, "unknown");
-        m.insert(757, "unknown");
-        m.insert(659, "unknown");
-        m.insert(225, "unknown");
-        m.insert(1015, "unknown");
-        m.insert(1014, "unknown");
-        m.insert(1012, "unknown");
-        m.insert(655, "tinc");
-        m.insert(786, "concert");
-        m.insert(6017, "xmail-ctrl");
-        m.insert(6670, "irc");
-        m.insert(690, "vatp");
-        m.insert(388, "unidata-ldm");
-        m.insert(44334, "tinyfw");
-        m.insert(754, "krb_prop");
-        m.insert(5011, "telelpathattack");
-        m.insert(98, "linuxconf");
-        m.insert(411, "rmt");
-        m.insert(1525, "orasrv");
-        m.insert(3999, "remoteanything");
-        m.insert(740, "netcp");
-        m.insert(12346, "netbus");
-        m.insert(802, "mbap-s");
-        m.insert(1337, "waste");
-        m.insert(1127, "supfiledbg");
-        m.insert(2112, "kip");
-        m.insert(1414, "ibm-mqserties");
-        m.insert(2600, "zebrasrv");
-        m.insert(621, "escp-ip");
-        m.insert(606, "urm");
-        m.insert(59, "priv-file");
-        m.insert(928, "unknown");
-        m.insert(924, "unknown");
-        m.insert(922, "unknown");
-        m.insert(921, "unknown");
-        m.insert(918, "unknown");
-        m.insert(878, "unknown");
-        m.insert(864, "unknown");
-        m.insert(859, "unknown");
-        m.insert(806, "unknown");
-        m.insert(805, "unknown");
-        m.insert(728, "unknown");
-        m.insert(252, "unknown");
-        m.insert(1005, "unknown");
-        m.insert(1004, "unknown");
-        m.insert(641, "repcmd");
-        m.insert(758, "nlogin");
-        m.insert(669, "meregister");
-        m.insert(38037, "landesk-cba");
-        m.insert(715, "iris-lwz");
-        m.insert(1413, "innosys-acl");
-        m.insert(2104, "zephyr-hm");
-        m.insert(1229, "zented");
-        m.insert(3817, "tapeware");
-        m.insert(6063, "x11");
-        m.insert(6062, "x11");
-        m.insert(6055, "x11");
-        m.insert(6052, "x11");
-        m.insert(6030, "x11");
-        m.insert(6021, "x11");
-        m.insert(6015, "x11");
-        m.insert(6010, "x11");
-        m.insert(3220, "xnm-ssl");
-        m.insert(6115, "xic");
-        m.insert(3940, "xecp-node");
-        m.insert(2340, "wrs_registry");
-        m.insert(8006, "wpl-analytics");
-        m.insert(4141, "oirtgsvc");
-        m.insert(3810, "wlanauth");
-        m.insert(1565, "winddlb");
-        m.insert(3511, "webmail-2");
-        m.insert(5986, "wsmans");
-        m.insert(5985, "wsman");
-        m.insert(33000, "wg-endpt-comms");
-        m.insert(2723, "watchdog-nt");
-        m.insert(9202, "wap-wsp-s");
-        m.insert(4036, "wap-push-https");
-        m.insert(4035, "wap-push-http");
-        m.insert(2312, "wanscaler");
-        m.insert(3652, "vxcrnbuport");
-        m.insert(3280, "vs-sertver");
-        m.insert(4243, "vrml-multi-use");
-        m.insert(4298, "vrml-multi-use");
-        m.insert(4297, "vrml-multi-use");
-        m.insert(4294, "vrml-multi-use");
-        m.insert(4262, "vrml-multi-use");
-        m.insert(4234, "vrml-multi-use");
-        m.insert(4220, "vrml-multi-use");
-        m.insert(4206, "vrml-multi-use");
-        m.insert(22555, "vocaltec-wconf");
-        m.insert(9300, "vrace");
-        m.insert(7121, "virprot-lm");
-        m.insert(1927, "videte-cipc");
-        m.insert(4433, "vop");
-        m.insert(5070, "vtsas");
-        m.insert(2148, "veritas-ucl");
-        m.insert(1168, "vchat");
-        m.insert(9979, "visweather");
-        m.insert(7998, "usicontentpush");
-        m.insert(4414, "updog");
-        m.insert(1823, "unisys-lm");
-        m.insert(3653, "tsp");
-        m.insert(1223, "tgp");
-        m.insert(8201, "trivnet2");
-        m.insert(4876, "tritium-can");
-        m.insert(3240, "triomotion");
-        m.insert(2644, "travsoft-ipx-t");
-        m.insert(4020, "trap");
-        m.insert(2436, "topx");
-        m.insert(3906, "topovista-data");
-        m.insert(4375, "tolteces");
-        m.insert(4024, "tnp1-port");
-        m.insert(5581, "tmosms1");
-        m.insert(5580, "tmosms0");
-        m.insert(9694, "client-wakeup");
-        m.insert(6251, "tl1-raw-ssl");
-        m.insert(7345, "swx");
-        m.insert(7325, "swx");
-        m.insert(7320, "swx");
-        m.insert(7300, "swx");
-        m.insert(3121, "pcmk-remote");
-        m.insert(5473, "apsolab-tags");
-        m.insert(5475, "apsolab-data");
-        m.insert(3600, "trap-daemon");
-        m.insert(3943, "tig");
-        m.insert(4912, "lutap");
-        m.insert(2142, "tdmoip");
-        m.insert(1976, "tcoregagent");
-        m.insert(1975, "tcoflashagent");
-        m.insert(5202, "targus-getdata2");
-        m.insert(5201, "targus-getdata1");
-        m.insert(4016, "talarian-mcast2");
-        m.insert(5111, "taep-as-svc");
-        m.insert(9911, "sype-transport");
-        m.insert(10006, "netapp-sync");
-        m.insert(3923, "symb-sb-port");
-        m.insert(3930, "syam-websertver");
-        m.insert(1221, "sweetware-apps");
-        m.insert(2973, "svnetworks");
-        m.insert(3909, "surfcontrolcpa");
-        m.insert(5814, "spt-automation");
-        m.insert(14001, "sua");
-        m.insert(3080, "stm_pproc");
-        m.insert(4158, "stat-cc");
-        m.insert(3526, "starquiz-port");
-        m.insert(1911, "mtp");
-        m.insert(5066, "stanag-5066");
-        m.insert(2711, "sso-control");
-        m.insert(2187, "ssmc");
-        m.insert(3788, "isrp-port");
-        m.insert(3796, "spw-dialer");
-        m.insert(3922, "sor-update");
-        m.insert(2292, "mib-streaming");
-        m.insert(16161, "sun-sea-port");
-        m.insert(3102, "sl-mon");
-        m.insert(4881, "socp-t");
-        m.insert(3979, "smwan");
-        m.insert(3670, "smile");
-        m.insert(4174, "smcluster");
-        m.insert(3483, "slim-devices");
-        m.insert(2631, "sitaradir");
-        m.insert(1750, "sslp");
-        m.insert(3897, "sdo-ssh");
-        m.insert(7500, "silhouette");
-        m.insert(5553, "sgi-eventmond");
-        m.insert(5554, "sgi-esphttp");
-        m.insert(9875, "sapv1");
-        m.insert(4570, "deploymentmap");
-        m.insert(3860, "sasp");
-        m.insert(3712, "sentinel-ent");
-        m.insert(8052, "senomix01");
-        m.insert(2083, "radsec");
-        m.insert(8883, "secure-mqtt");
-        m.insert(2271, "mmcals");
-        m.insert(4606, "sixid");
-        m.insert(1208, "seagull-ais");
-        m.insert(3319, "sdt-lmd");
-        m.insert(3935, "sdp-portmapper");
-        m.insert(3430, "ssdispatch");
-        m.insert(1215, "scanstat-1");
-        m.insert(3962, "sbi-agent");
-        m.insert(3368, "satvid-datalnk");
-        m.insert(3964, "sasggprs");
-        m.insert(1128, "saphostctrl");
-        m.insert(5557, "farenet");
-        m.insert(4010, "samsung-unidex");
-        m.insert(9400, "sec-t4net-srv");
-        m.insert(1605, "slp");
-        m.insert(3291, "sah-lm");
-        m.insert(7400, "rtps-discovery");
-        m.insert(5005, "avt-profile-2");
-        m.insert(1699, "rsvp-encap-2");
-        m.insert(1195, "rsf-1");
-        m.insert(5053, "rlm");
-        m.insert(3813, "rap-ip");
-        m.insert(1712, "registrar");
-        m.insert(3002, "exlm-agent");
-        m.insert(3765, "rtraceroute");
-        m.insert(3806, "wsmlb");
-        m.insert(43000, "recvr-rc");
-        m.insert(2371, "worldwire");
-        m.insert(3532, "raven-rmp");
-        m.insert(3799, "radius-dynauth");
-        m.insert(3790, "quickbooksrds");
-        m.insert(3599, "quasar-sertver");
-        m.insert(3850, "qtms-bootstrap");
-        m.insert(4355, "qsnet-workst");
-        m.insert(4358, "qsnet-nucl");
-        m.insert(4357, "qsnet-cond");
-        m.insert(4356, "qsnet-assist");
-        m.insert(5433, "pyrrho");
-        m.insert(3928, "netboot-pxe");
-        m.insert(4713, "pulseaudio");
-        m.insert(4374, "psi-ptt");
-        m.insert(3961, "proaxess");
-        m.insert(9022, "paragent");
-        m.insert(3911, "prnstatus");
-        m.insert(3396, "printer_agent");
-        m.insert(7628, "zen-pawn");
-        m.insert(3200, "tick-port");
-        m.insert(1753, "predatar-comms");
-        m.insert(3967, "ppsms");
-        m.insert(2505, "ppcontrol");
-        m.insert(5133, "nbt-pc");
-        m.insert(3658, "ps-ams");
-        m.insert(8471, "pim-port");
-        m.insert(1314, "pdps");
-        m.insert(2558, "pclemultimedia");
-        m.insert(6161, "patrol-ism");
-        m.insert(4025, "partimage");
-        m.insert(3089, "ptk-alink");
-        m.insert(9021, "panagolin-ident");
-        m.insert(30001, "pago-sertvices1");
-        m.insert(8472, "otv");
-        m.insert(5014, "onpsocket");
-        m.insert(9990, "osm-appsrvr");
-        m.insert(1159, "oracle-oms");
-        m.insert(1157, "iascontrol");
-        m.insert(1308, "odsi");
-        m.insert(5723, "omhs");
-        m.insert(3443, "ov-nnm-websrv");
-        m.insert(4161, "omscontact");
-        m.insert(1135, "omnivision");
-        m.insert(9211, "oma-mlp-s");
-        m.insert(9210, "oma-mlp");
-        m.insert(4090, "omasgport");
-        m.insert(7789, "office-tools");
-        m.insert(6619, "odette-ftps");
-        m.insert(9628, "odbcpathway");
-        m.insert(12121, "nupaper-ss");
-        m.insert(4454, "nssagentmgr");
-        m.insert(3680, "npds-tracker");
-        m.insert(3167, "nowcontact");
-        m.insert(3902, "nimaux");
-        m.insert(3901, "nimsh");
-        m.insert(3890, "ndsconnect");
-        m.insert(3842, "nhci");
-        m.insert(16900, "newbay-snc-mc");
-        m.insert(4700, "netxms-agent");
-        m.insert(4687, "nst");
-        m.insert(8980, "nod-provider");
-        m.insert(1196, "netmagic");
-        m.insert(4407, "nacagent");
-        m.insert(3520, "galileolog");
-        m.insert(3812, "neto-wol-sertver");
-        m.insert(5012, "nsp");
-        m.insert(10115, "netiq-endpt");
-        m.insert(1615, "netbill-auth");
-        m.insert(2902, "netaspi");
-        m.insert(4118, "netscript");
-        m.insert(2706, "ncdmirroring");
-        m.insert(2095, "nbx-sert");
-        m.insert(2096, "nbx-dir");
-        m.insert(3363, "nati-vi-sertver");
-        m.insert(5137, "ctsd");
-        m.insert(3795, "myblast");
-        m.insert(8005, "mxi");
-        m.insert(10007, "mvs-capacity");
-        m.insert(3515, "must-backplane");
-        m.insert(8003, "mcreport");
-        m.insert(3847, "msfw-control");
-        m.insert(3503, "lsp-ping");
-        m.insert(5252, "movaz-ssc");
-        m.insert(27017, "mongod");
-        m.insert(2197, "mnp-exchange");
-        m.insert(4120, "minirem");
-        m.insert(1180, "mc-client");
-        m.insert(5722, "msdfsr");
-        m.insert(1134, "aplx");
-        m.insert(1883, "mqtt");
-        m.insert(1249, "mesavistaco");
-        m.insert(3311, "mcns-tel-ret");
-        m.insert(27350, "mdcs-scheduler");
-        m.insert(3837, "mkm-discovery");
-        m.insert(2804, "dvr-esm");
-        m.insert(4558, "mtcevrunqman");
-        m.insert(4190, "sieve");
-        m.insert(2463, "lsi-raid-mgmt");
-        m.insert(1204, "ssslog-mgr");
-        m.insert(4056, "lms");
-        m.insert(1184, "llsurfup-https");
-        m.insert(19333, "litecoin");
-        m.insert(9333, "litecoin");
-        m.insert(3913, "listcrt-port");
-        m.insert(3672, "lispworks-orb");
-        m.insert(4342, "lisp-cons");
-        m.insert(4877, "lmcs");
-        m.insert(3586, "emprise-lsc");
-        m.insert(8282, "libelle");
-        m.insert(1861, "lecroy-vicp");
-        m.insert(1752, "lofr-lm");
-        m.insert(9592, "ldgateway");
-        m.insert(1701, "l2f");
-        m.insert(6085, "konspire2b");
-        m.insert(2081, "kme-trap-port");
-        m.insert(4058, "kingfisher");
-        m.insert(2115, "kdm");
-        m.insert(8900, "jmb-cds1");
-        m.insert(4328, "jaxer-manager");
-        m.insert(2958, "jmact6");
-        m.insert(2957, "jmact5");
-        m.insert(7071, "iwg1");
-        m.insert(3899, "itv-control");
-        m.insert(2531, "ito-e-gui");
-        m.insert(2691, "itinternet");
-        m.insert(5052, "ita-manager");
-        m.insert(1638, "ismc");
-        m.insert(3419, "softaudit");
-        m.insert(2551, "isg-uda-sertver");
-        m.insert(5908, "ipsma");
-        m.insert(4029, "ip-qsig");
-        m.insert(3603, "int-rcv-cntrl");
-        m.insert(1336, "ischat");
-        m.insert(2082, "infowave");
-        m.insert(1143, "imyx");
-        m.insert(3602, "infiniswitchcl");
-        m.insert(1176, "indigo-sertver");
-        m.insert(4100, "igo-incognito");
-        m.insert(3486, "ifsf-hb-port");
-        m.insert(6077, "iconstructsrv");
-        m.insert(4800, "iims");
-        m.insert(2062, "icg-swp");
-        m.insert(1918, "can-nds");
-        m.insert(12001, "entextnetwk");
-        m.insert(12002, "entexthigh");
-        m.insert(9084, "aurora");
-        m.insert(7072, "iba-cfg");
-        m.insert(1156, "iascontrol-oms");
-        m.insert(2313, "iapp");
-        m.insert(3952, "i3-sessionmgr");
-        m.insert(4999, "hfcs-manager");
-        m.insert(5023, "htuilsrv");
-        m.insert(2069, "event-port");
-        m.insert(28017, "mongod");
-        m.insert(27019, "mongod");
-        m.insert(27018, "mongod");
-        m.insert(3439, "hri-port");
-        m.insert(6324, "hrd-ncs");
-        m.insert(1188, "hp-webadmin");
-        m.insert(1125, "hpvmmagent");
-        m.insert(3908, "hppronetman");
-        m.insert(7501, "ovbus");
-        m.insert(8232, "hncp-dtls-port");
-        m.insert(1722, "hks-lm");
-        m.insert(2988, "hippad");
-        m.insert(10500, "hip-nat-t");
-        m.insert(1136, "hhb-gateway");
-        m.insert(1162, "health-trap");
-        m.insert(10020, "abb-hw");
-        m.insert(22128, "gsidcap");
-        m.insert(1211, "groove-dpp");
-        m.insert(3530, "gf");
-        m.insert(12009, "ghvpn");
-        m.insert(9005, "golem");
-        m.insert(3057, "goahead-fldup");
-        m.insert(3956, "gvcp");
-        m.insert(4325, "geognosisman");
-        m.insert(1191, "gpfs");
-        m.insert(3519, "nvmsgd");
-        m.insert(5235, "galaxy-network");
-        m.insert(1144, "fuscript");
-        m.insert(4745, "fmp");
-        m.insert(1901, "fjicl-tep-a");
-        m.insert(1807, "fhsp");
-        m.insert(2425, "fjitsuappmgr");
-        m.insert(3210, "flamenco-proxy");
-        m.insert(32767, "filenet-powsrm");
-        m.insert(5015, "fmwp");
-        m.insert(5013, "fmpro-v6");
-        m.insert(3622, "ff-lr-port");
-        m.insert(4039, "fazzt-admin");
-        m.insert(10101, "ezmeeting-2");
-        m.insert(5233, "enfs");
-        m.insert(5152, "sde-discovery");
-        m.insert(3983, "eisp");
-        m.insert(3982, "eis");
-        m.insert(9616, "erunbook_agent");
-        m.insert(4369, "epmd");
-        m.insert(3728, "e-woa");
-        m.insert(3621, "ep-nsp");
-        m.insert(2291, "eapsp");
-        m.insert(5114, "ev-sertvices");
-        m.insert(7101, "elcn");
-        m.insert(1315, "els");
-        m.insert(2087, "eli");
-        m.insert(5234, "eenet");
-        m.insert(1635, "edb-sertver1");
-        m.insert(3263, "ecolor-imager");
-        m.insert(4121, "e-builder");
-        m.insert(4602, "mtssertver");
-        m.insert(2224, "efi-mg");
-        m.insert(3949, "drip");
-        m.insert(9131, "dddp");
-        m.insert(3310, "dyna-access");
-        m.insert(3937, "dvbsertvdsc");
-        m.insert(2253, "dtv-chan-req");
-        m.insert(3882, "msdts1");
-        m.insert(3831, "dvapps");
-        m.insert(2376, "docker");
-        m.insert(2375, "docker");
-        m.insert(3876, "dl_agent");
-        m.insert(3362, "dj-ilm");
-        m.insert(3663, "dtp");
-        m.insert(3334, "directv-web");
-        m.insert(47624, "directplaysrvr");
-        m.insert(1825, "direcpc-video");
-        m.insert(3868, "diameter");
-        m.insert(4302, "d-data-control");
-        m.insert(5721, "dtpt");
-        m.insert(1279, "dellwebadmin-2");
-        m.insert(2606, "netmon");
-        m.insert(1173, "d-cinema-rrp");
-        m.insert(22125, "dcap");
-        m.insert(17500, "db-lsp");
-        m.insert(12005, "dbisamsertver1");
-        m.insert(6113, "daylitesertver");
-        m.insert(1973, "dlsrap");
-        m.insert(3793, "dcsoftware");
-        m.insert(3637, "scsertvp");
-        m.insert(8954, "cumulus-admin");
-        m.insert(3742, "cst-port");
-        m.insert(9667, "xmms2");
-        m.insert(41795, "crestron-ctp");
-        m.insert(41794, "crestron-cip");
-        m.insert(4300, "corelccam");
-        m.insert(8445, "copy");
-        m.insert(12865, "netperf");
-        m.insert(3365, "contentsertver");
-        m.insert(4665, "contclientms");
-        m.insert(3190, "csvr-proxy");
-        m.insert(3577, "config-port");
-        m.insert(3823, "acp-conduit");
-        m.insert(2261, "comotionmaster");
-        m.insert(2262, "comotionback");
-        m.insert(2812, "atmtcp");
-        m.insert(1190, "commlinx-avl");
-        m.insert(22350, "CodeMeter");
-        m.insert(3374, "cluster-disc");
-        m.insert(4135, "cl-db-attach");
-        m.insert(2598, "citriximaclient");
-        m.insert(2567, "clp");
-        m.insert(1167, "cisco-ipsla");
-        m.insert(8470, "cisco-avp");
-        m.insert(10443, "cirrossp");
-        m.insert(8116, "cp-cluster");
-        m.insert(3830, "cernsysmgmtagt");
-        m.insert(8880, "cddbp-alt");
-        m.insert(2734, "ccs-software");
-        m.insert(3505, "ccmcomm");
-        m.insert(3388, "cbsertver");
-        m.insert(3669, "casanswmgmt");
-        m.insert(1871, "canocentral0");
-        m.insert(8025, "ca-audit-da");
-        m.insert(1958, "dxadmind");
-        m.insert(3681, "bts-x73");
-        m.insert(3014, "broker_sertvice");
-        m.insert(8999, "bctp");
-        m.insert(4415, "brcd-vr-req");
-        m.insert(3414, "wip-port");
-        m.insert(4101, "brlp-0");
-        m.insert(6503, "boks_clntd");
-        m.insert(9700, "board-roar");
-        m.insert(3683, "bmc-ea");
-        m.insert(1150, "blaze");
-        m.insert(18333, "bitcoin");
-        m.insert(4376, "bip");
-        m.insert(3991, "bv-smcsrv");
-        m.insert(3989, "bv-queryengine");
-        m.insert(3992, "bv-ds");
-        m.insert(2302, "binderysupport");
-        m.insert(3415, "bcinamesertvice");
-        m.insert(1179, "b2n");
-        m.insert(3946, "backupedge");
-        m.insert(2203, "b2-runtime");
-        m.insert(4192, "azeti");
-        m.insert(4418, "axysbridge");
-        m.insert(2712, "aocp");
-        m.insert(25565, "minecraft");
-        m.insert(4065, "avanti_cdp");
-        m.insert(5820, "autopassdaemon");
-        m.insert(3915, "agcat");
-        m.insert(2080, "autodesk-nlm");
-        m.insert(3103, "autocuesmi");
-        m.insert(2265, "apx500api-2");
-        m.insert(8202, "aesop");
-        m.insert(2304, "attachmate-uts");
-        m.insert(8060, "aero");
-        m.insert(4119, "assuria-slm");
-        m.insert(4401, "ds-srvr");
-        m.insert(1560, "asci-val");
-        m.insert(3904, "omnilink-port");
-        m.insert(4534, "armagetronad");
-        m.insert(1835, "ardusmul");
-        m.insert(1116, "ardus-cntl");
-        m.insert(8023, "arca-api");
-        m.insert(8474, "noteshare");
-        m.insert(3879, "appss-lm");
-        m.insert(4087, "applussertvice");
-        m.insert(4112, "apple-vpns-rp");
-        m.insert(6350, "adap");
-        m.insert(9950, "apc-9950");
-        m.insert(3506, "apc-3506");
-        m.insert(3948, "apdap");
-        m.insert(3825, "ffsertver");
-        m.insert(2325, "ansysli");
-        m.insert(1800, "ansys-lm");
-        m.insert(1153, "c1222-acse");
-        m.insert(6379, "redis");
-        m.insert(3839, "amx-rms");
-        m.insert(5672, "amqp");
-        m.insert(4689, "altovacentral");
-        m.insert(47806, "ap");
-        m.insert(5912, "fis");
-        m.insert(3975, "airshot");
-        m.insert(3980, "acms");
-        m.insert(4113, "aipn-reg");
-        m.insert(2847, "aimpp-port-req");
-        m.insert(2070, "ah-esp-encap");
-        m.insert(3425, "agps-port");
-        m.insert(6628, "afesc-mc");
-        m.insert(3997, "agentsease-db");
-        m.insert(3513, "arcpd");
-        m.insert(3656, "abatjss");
-        m.insert(2335, "ace-proxy");
-        m.insert(1182, "accelenet");
-        m.insert(1954, "abr-api");
-        m.insert(3996, "abcsoftware");
-        m.insert(4599, "a17-an-an");
-        m.insert(2391, "3com-net-mgmt");
-        m.insert(3479, "twrpc");
-        m.insert(5021, "zenginkyo-2");
-        m.insert(5020, "zenginkyo-1");
-        m.insert(1558, "xingmpeg");
-        m.insert(1924, "xiip");
-        m.insert(4545, "worldscores");
-        m.insert(2991, "wkstn-mon");
-        m.insert(6065, "winpharaoh");
-        m.insert(1290, "winjasertver");
-        m.insert(1559, "web2host");
-        m.insert(1317, "vrts-ipcsertver");
-        m.insert(5423, "virtualusert");
-        m.insert(1707, "vdmplay");
-        m.insert(5055, "unot");
-        m.insert(9975, "unknown");
-        m.insert(9971, "unknown");
-        m.insert(9919, "unknown");
-        m.insert(9915, "unknown");
-        m.insert(9912, "unknown");
-        m.insert(9910, "unknown");
-        m.insert(9908, "unknown");
-        m.insert(9901, "unknown");
-        m.insert(9844, "unknown");
-        m.insert(9830, "unknown");
-        m.insert(9826, "unknown");
-        m.insert(9825, "unknown");
-        m.insert(9823, "unknown");
-        m.insert(9814, "unknown");
-        m.insert(9812, "unknown");
-        m.insert(9777, "unknown");
-        m.insert(9745, "unknown");
-        m.insert(9683, "unknown");
-        m.insert(9680, "unknown");
-        m.insert(9679, "unknown");
-        m.insert(9674, "unknown");
-        m.insert(9665, "unknown");
-        m.insert(9661, "unknown");
-        m.insert(9654, "unknown");
-        m.insert(9648, "unknown");
-        m.insert(9620, "unknown");
-        m.insert(9619, "unknown");
-        m.insert(9613, "unknown");
-        m.insert(9583, "unknown");
-        m.insert(9527, "unknown");
-        m.insert(9513, "unknown");
-        m.insert(9493, "unknown");
-        m.insert(9478, "unknown");
-        m.insert(9464, "unknown");
-        m.insert(9454, "unknown");
-        m.insert(9364, "unknown");
-        m.insert(9351, "unknown");
-        m.insert(9183, "unknown");
-        m.insert(9170, "unknown");
-        m.insert(9133, "unknown");
-        m.insert(9130, "unknown");
-        m.insert(9128, "unknown");
-        m.insert(9125, "unknown");
-        m.insert(9065, "unknown");
-        m.insert(9061, "unknown");
-        m.insert(9044, "unknown");
-        m.insert(9037, "unknown");
-        m.insert(9013, "unknown");
-        m.insert(9004, "unknown");
-        m.insert(8925, "unknown");
-        m.insert(8898, "unknown");
-        m.insert(8887, "unknown");
-        m.insert(8882, "unknown");
-        m.insert(8879, "unknown");
-        m.insert(8878, "unknown");
-        m.insert(8865, "unknown");
-        m.insert(8843, "unknown");
-        m.insert(8801, "unknown");
-        m.insert(8798, "unknown");
-        m.insert(8790, "unknown");
-        m.insert(8772, "unknown");
-        m.insert(8756, "unknown");
-        m.insert(8752, "unknown");
-        m.insert(8736, "unknown");
-        m.insert(8680, "unknown");
-        m.insert(8673, "unknown");
-        m.insert(8658, "unknown");
-        m.insert(8655, "unknown");
-        m.insert(8644, "unknown");
-        m.insert(8640, "unknown");
-        m.insert(8621, "unknown");
-        m.insert(8601, "unknown");
-        m.insert(8562, "unknown");
-        m.insert(8539, "unknown");
-        m.insert(8531, "unknown");
-        m.insert(8530, "unknown");
-        m.insert(8515, "unknown");
-        m.insert(8484, "unknown");
-        m.insert(8479, "unknown");
-        m.insert(8477, "unknown");
-        m.insert(8455, "unknown");
-        m.insert(8454, "unknown");
-        m.insert(8453, "unknown");
-        m.insert(8452, "unknown");
-        m.insert(8451, "unknown");
-        m.insert(8409, "unknown");
-        m.insert(8339, "unknown");
-        m.insert(8308, "unknown");
-        m.insert(8295, "unknown");
-        m.insert(8273, "unknown");
-        m.insert(8268, "unknown");
-        m.insert(8255, "unknown");
-        m.insert(8248, "unknown");
-        m.insert(8245, "unknown");
-        m.insert(8144, "unknown");
-        m.insert(8133, "unknown");
-        m.insert(8110, "unknown");
-        m.insert(8092, "unknown");
-        m.insert(8064, "unknown");
-        m.insert(8037, "unknown");
-        m.insert(8029, "unknown");
-        m.insert(8018, "unknown");
-        m.insert(8014, "unknown");
-        m.insert(7975, "unknown");
-        m.insert(7895, "unknown");
-        m.insert(7854, "unknown");
-        m.insert(7853, "unknown");
-        m.insert(7852, "unknown");
-        m.insert(7830, "unknown");
-        m.insert(7813, "unknown");
-        m.insert(7788, "unknown");
-        m.insert(7780, "unknown");
-        m.insert(7772, "unknown");
-        m.insert(7771, "unknown");
-        m.insert(7688, "unknown");
-        m.insert(7685, "unknown");
-        m.insert(7654, "unknown");
-        m.insert(7637, "unknown");
-        m.insert(7600, "unknown");
-        m.insert(7555, "unknown");
-        m.insert(7553, "unknown");
-        m.insert(7456, "unknown");
-        m.insert(7451, "unknown");
-        m.insert(7231, "unknown");
-        m.insert(7218, "unknown");
-        m.insert(7184, "unknown");
-        m.insert(7119, "unknown");
-        m.insert(7104, "unknown");
-        m.insert(7102, "unknown");
-        m.insert(7092, "unknown");
-        m.insert(7068, "unknown");
-        m.insert(7067, "unknown");
-        m.insert(7043, "unknown");
-        m.insert(7033, "unknown");
-        m.insert(6973, "unknown");
-        m.insert(6972, "unknown");
-        m.insert(6956, "unknown");
-        m.insert(6942, "unknown");
-        m.insert(6922, "unknown");
-        m.insert(6920, "unknown");
-        m.insert(6897, "unknown");
-        m.insert(6877, "unknown");
-        m.insert(6780, "unknown");
-        m.insert(6734, "unknown");
-        m.insert(6725, "unknown");
-        m.insert(6710, "unknown");
-        m.insert(6709, "unknown");
-        m.insert(6650, "unknown");
-        m.insert(6647, "unknown");
-        m.insert(6644, "unknown");
-        m.insert(6606, "unknown");
-        m.insert(65514, "unknown");
-        m.insert(65488, "unknown");
-        m.insert(6535, "unknown");
-        m.insert(65311, "unknown");
-        m.insert(65048, "unknown");
-        m.insert(64890, "unknown");
-        m.insert(64727, "unknown");
-        m.insert(64726, "unknown");
-        m.insert(64551, "unknown");
-        m.insert(64507, "unknown");
-        m.insert(64438, "unknown");
-        m.insert(64320, "unknown");
-        m.insert(6412, "unknown");
-        m.insert(64127, "unknown");
-        m.insert(64080, "unknown");
-        m.insert(63803, "unknown");
-        m.insert(63675, "unknown");
-        m.insert(6349, "unknown");
-        m.insert(63423, "unknown");
-        m.insert(6323, "unknown");
-        m.insert(63156, "unknown");
-        m.insert(6310, "unknown");
-        m.insert(63105, "unknown");
-        m.insert(6309, "unknown");
-        m.insert(62866, "unknown");
-        m.insert(6274, "unknown");
-        m.insert(6273, "unknown");
-        m.insert(62674, "unknown");
-        m.insert(6259, "unknown");
-        m.insert(62570, "unknown");
-        m.insert(62519, "unknown");
-        m.insert(6250, "unknown");
-        m.insert(62312, "unknown");
-        m.insert(62188, "unknown");
-        m.insert(62080, "unknown");
-        m.insert(62042, "unknown");
-        m.insert(62006, "unknown");
-        m.insert(61942, "unknown");
-        m.insert(61851, "unknown");
-        m.insert(61827, "unknown");
-        m.insert(61734, "unknown");
-        m.insert(61722, "unknown");
-        m.insert(61669, "unknown");
-        m.insert(61617, "unknown");
-        m.insert(61616, "unknown");
-        m.insert(61516, "unknown");
-        m.insert(61473, "unknown");
-        m.insert(61402, "unknown");
-        m.insert(6126, "unknown");
-        m.insert(6120, "unknown");
-        m.insert(61170, "unknown");
-        m.insert(61169, "unknown");
-        m.insert(61159, "unknown");
-        m.insert(60989, "unknown");
-        m.insert(6091, "unknown");
-        m.insert(6090, "unknown");
-        m.insert(60794, "unknown");
-        m.insert(60789, "unknown");
-        m.insert(60783, "unknown");
-        m.insert(60782, "unknown");
-        m.insert(60753, "unknown");
-        m.insert(60743, "unknown");
-        m.insert(60728, "unknown");
-        m.insert(60713, "unknown");
-        m.insert(6067, "unknown");
-        m.insert(60628, "unknown");
-        m.insert(60621, "unknown");
-        m.insert(60612, "unknown");
-        m.insert(60579, "unknown");
-        m.insert(60544, "unknown");
-        m.insert(60504, "unknown");
-        m.insert(60492, "unknown");
-        m.insert(60485, "unknown");
-        m.insert(60403, "unknown");
-        m.insert(60401, "unknown");
-        m.insert(60377, "unknown");
-        m.insert(60279, "unknown");
-        m.insert(60243, "unknown");
-        m.insert(60227, "unknown");
-        m.insert(60177, "unknown");
-        m.insert(60111, "unknown");
-        m.insert(60086, "unknown");
-        m.insert(60055, "unknown");
-        m.insert(60003, "unknown");
-        m.insert(60002, "unknown");
-        m.insert(60000, "unknown");
-        m.insert(59987, "unknown");
-        m.insert(59841, "unknown");
-        m.insert(59829, "unknown");
-        m.insert(59810, "unknown");
-        m.insert(59778, "unknown");
-        m.insert(5975, "unknown");
-        m.insert(5974, "unknown");
-        m.insert(5971, "unknown");
-        m.insert(59684, "unknown");
-        m.insert(5966, "unknown");
-        m.insert(5958, "unknown");
-        m.insert(59565, "unknown");
-        m.insert(5954, "unknown");
-        m.insert(5953, "unknown");
-        m.insert(59525, "unknown");
-        m.insert(59510, "unknown");
-        m.insert(59509, "unknown");
-        m.insert(59504, "unknown");
-        m.insert(5949, "unknown");
-        m.insert(59499, "unknown");
-        m.insert(5948, "unknown");
-        m.insert(5945, "unknown");
-        m.insert(5939, "unknown");
-        m.insert(5936, "unknown");
-        m.insert(5934, "unknown");
-        m.insert(59340, "unknown");
-        m.insert(5931, "unknown");
-        m.insert(5927, "unknown");
-        m.insert(5926, "unknown");
-        m.insert(5924, "unknown");
-        m.insert(5923, "unknown");
-        m.insert(59239, "unknown");
-        m.insert(5921, "unknown");
-        m.insert(5920, "unknown");
-        m.insert(59191, "unknown");
-        m.insert(5917, "unknown");
-        m.insert(59160, "unknown");
-        m.insert(59149, "unknown");
-        m.insert(59122, "unknown");
-        m.insert(59107, "unknown");
-        m.insert(59087, "unknown");
-        m.insert(58991, "unknown");
-        m.insert(58970, "unknown");
-        m.insert(58908, "unknown");
-        m.insert(5888, "unknown");
-        m.insert(5887, "unknown");
-        m.insert(5881, "unknown");
-        m.insert(5878, "unknown");
-        m.insert(5875, "unknown");
-        m.insert(5874, "unknown");
-        m.insert(58721, "unknown");
-        m.insert(5871, "unknown");
-        m.insert(58699, "unknown");
-        m.insert(58634, "unknown");
-        m.insert(58622, "unknown");
-        m.insert(58610, "unknown");
-        m.insert(5860, "unknown");
-        m.insert(5858, "unknown");
-        m.insert(58570, "unknown");
-        m.insert(58562, "unknown");
-        m.insert(5854, "unknown");
-        m.insert(5853, "unknown");
-        m.insert(5852, "unknown");
-        m.insert(5849, "unknown");
-        m.insert(58498, "unknown");
-        m.insert(5848, "unknown");
-        m.insert(58468, "unknown");
-        m.insert(5845, "unknown");
-        m.insert(58456, "unknown");
-        m.insert(58446, "unknown");
-        m.insert(58430, "unknown");
-        m.insert(5840, "unknown");
-        m.insert(5839, "unknown");
-        m.insert(5838, "unknown");
-        m.insert(58374, "unknown");
-        m.insert(5836, "unknown");
-        m.insert(5834, "unknown");
-        m.insert(5831, "unknown");
-        m.insert(58310, "unknown");
-        m.insert(58305, "unknown");
-        m.insert(5827, "unknown");
-        m.insert(5826, "unknown");
-        m.insert(58252, "unknown");
-        m.insert(5824, "unknown");
-        m.insert(5821, "unknown");
-        m.insert(5817, "unknown");
-        m.insert(58164, "unknown");
-        m.insert(58109, "unknown");
-        m.insert(58107, "unknown");
-        m.insert(5808, "unknown");
-        m.insert(58072, "unknown");
-        m.insert(5806, "unknown");
-        m.insert(5804, "unknown");
-        m.insert(57999, "unknown");
-        m.insert(57988, "unknown");
-        m.insert(57928, "unknown");
-        m.insert(57923, "unknown");
-        m.insert(57896, "unknown");
-        m.insert(57891, "unknown");
-        m.insert(57733, "unknown");
-        m.insert(57730, "unknown");
-        m.insert(57702, "unknown");
-        m.insert(57681, "unknown");
-        m.insert(57678, "unknown");
-        m.insert(57576, "unknown");
-        m.insert(57479, "unknown");
-        m.insert(57398, "unknown");
-        m.insert(57387, "unknown");
-        m.insert(5737, "unknown");
-        m.insert(57352, "unknown");
-        m.insert(57350, "unknown");
-        m.insert(5734, "unknown");
-        m.insert(57347, "unknown");
-        m.insert(57335, "unknown");
-        m.insert(5732, "unknown");
-        m.insert(57325, "unknown");
-        m.insert(57123, "unknown");
-        m.insert(5711, "unknown");
-        m.insert(57103, "unknown");
-        m.insert(57020, "unknown");
-        m.insert(56975, "unknown");
-        m.insert(56973, "unknown");
-        m.insert(56827, "unknown");
-        m.insert(56822, "unknown");
-        m.insert(56810, "unknown");
-        m.insert(56725, "unknown");
-        m.insert(56723, "unknown");
-        m.insert(56681, "unknown");
-        m.insert(5667, "unknown");
-        m.insert(56668, "unknown");
-        m.insert(5665, "unknown");
-        m.insert(56591, "unknown");
-        m.insert(56535, "unknown");
-        m.insert(56507, "unknown");
-        m.insert(56293, "unknown");
-        m.insert(56259, "unknown");
-        m.insert(5622, "unknown");
-        m.insert(5621, "unknown");
-        m.insert(5620, "unknown");
-        m.insert(5612, "unknown");
-        m.insert(5611, "unknown");
-        m.insert(56055, "unknown");
-        m.insert(56016, "unknown");
-        m.insert(55948, "unknown");
-        m.insert(55910, "unknown");
-        m.insert(55907, "unknown");
-        m.insert(55901, "unknown");
-        m.insert(55781, "unknown");
-        m.insert(55773, "unknown");
-        m.insert(55758, "unknown");
-        m.insert(55721, "unknown");
-        m.insert(55684, "unknown");
-        m.insert(55652, "unknown");
-        m.insert(55635, "unknown");
-        m.insert(55579, "unknown");
-        m.insert(55569, "unknown");
-        m.insert(55568, "unknown");
-        m.insert(55556, "unknown");
-        m.insert(5552, "unknown");
-        m.insert(55527, "unknown");
-        m.insert(55479, "unknown");
-        m.insert(55426, "unknown");
-        m.insert(55400, "unknown");
-        m.insert(55382, "unknown");
-        m.insert(55350, "unknown");
-        m.insert(55312, "unknown");
-        m.insert(55227, "unknown");
-        m.insert(55187, "unknown");
-        m.insert(55183, "unknown");
-        m.insert(55000, "unknown");
-        m.insert(54991, "unknown");
-        m.insert(54987, "unknown");
-        m.insert(54907, "unknown");
-        m.insert(54873, "unknown");
-        m.insert(54741, "unknown");
-        m.insert(54722, "unknown");
-        m.insert(54688, "unknown");
-        m.insert(54658, "unknown");
-        m.insert(54605, "unknown");
-        m.insert(5458, "unknown");
-        m.insert(5457, "unknown");
-        m.insert(54551, "unknown");
-        m.insert(54514, "unknown");
-        m.insert(5444, "unknown");
-        m.insert(5442, "unknown");
-        m.insert(5441, "unknown");
-        m.insert(54323, "unknown");
-        m.insert(54321, "unknown");
-        m.insert(54276, "unknown");
-        m.insert(54263, "unknown");
-        m.insert(54235, "unknown");
-        m.insert(54127, "unknown");
-        m.insert(54101, "unknown");
-        m.insert(54075, "unknown");
-        m.insert(53958, "unknown");
-        m.insert(53910, "unknown");
-        m.insert(53852, "unknown");
-        m.insert(53827, "unknown");
-        m.insert(53782, "unknown");
-        m.insert(5377, "unknown");
-        m.insert(53742, "unknown");
-        m.insert(5370, "unknown");
-        m.insert(53690, "unknown");
-        m.insert(53656, "unknown");
-        m.insert(53639, "unknown");
-        m.insert(53633, "unknown");
-        m.insert(53491, "unknown");
-        m.insert(5347, "unknown");
-        m.insert(53469, "unknown");
-        m.insert(53460, "unknown");
-        m.insert(53370, "unknown");
-        m.insert(53361, "unknown");
-        m.insert(53319, "unknown");
-        m.insert(53240, "unknown");
-        m.insert(53212, "unknown");
-        m.insert(53189, "unknown");
-        m.insert(53178, "unknown");
-        m.insert(53085, "unknown");
-        m.insert(52948, "unknown");
-        m.insert(5291, "unknown");
-        m.insert(52893, "unknown");
-        m.insert(52675, "unknown");
-        m.insert(52665, "unknown");
-        m.insert(5261, "unknown");
-        m.insert(5259, "unknown");
-        m.insert(52573, "unknown");
-        m.insert(52506, "unknown");
-        m.insert(52477, "unknown");
-        m.insert(52391, "unknown");
-        m.insert(52262, "unknown");
-        m.insert(52237, "unknown");
-        m.insert(52230, "unknown");
-        m.insert(52226, "unknown");
-        m.insert(52225, "unknown");
-        m.insert(5219, "unknown");
-        m.insert(52173, "unknown");
-        m.insert(52071, "unknown");
-        m.insert(52046, "unknown");
-        m.insert(52025, "unknown");
-        m.insert(52003, "unknown");
-        m.insert(52002, "unknown");
-        m.insert(52001, "unknown");
-        m.insert(52000, "unknown");
-        m.insert(51965, "unknown");
-        m.insert(51961, "unknown");
-        m.insert(51909, "unknown");
-        m.insert(51906, "unknown");
-        m.insert(51809, "unknown");
-        m.insert(51800, "unknown");
-        m.insert(51772, "unknown");
-        m.insert(51771, "unknown");
-        m.insert(51658, "unknown");
-        m.insert(51582, "unknown");
-        m.insert(51515, "unknown");
-        m.insert(51488, "unknown");
-        m.insert(51485, "unknown");
-        m.insert(51484, "unknown");
-        m.insert(5147, "unknown");
-        m.insert(51460, "unknown");
-        m.insert(51423, "unknown");
-        m.insert(51366, "unknown");
-        m.insert(51351, "unknown");
-        m.insert(51343, "unknown");
-        m.insert(51300, "unknown");
-        m.insert(5125, "unknown");
-        m.insert(51240, "unknown");
-        m.insert(51235, "unknown");
-        m.insert(51234, "unknown");
-        m.insert(51233, "unknown");
-        m.insert(5122, "unknown");
-        m.insert(5121, "unknown");
-        m.insert(51139, "unknown");
-        m.insert(51118, "unknown");
-        m.insert(51067, "unknown");
-        m.insert(51037, "unknown");
-        m.insert(51020, "unknown");
-        m.insert(51011, "unknown");
-        m.insert(50997, "unknown");
-        m.insert(5098, "unknown");
-        m.insert(5096, "unknown");
-        m.insert(5095, "unknown");
-        m.insert(50945, "unknown");
-        m.insert(5090, "unknown");
-        m.insert(50903, "unknown");
-        m.insert(5088, "unknown");
-        m.insert(50887, "unknown");
-        m.insert(50854, "unknown");
-        m.insert(50849, "unknown");
-        m.insert(50836, "unknown");
-        m.insert(50835, "unknown");
-        m.insert(50834, "unknown");
-        m.insert(50833, "unknown");
-        m.insert(50831, "unknown");
-        m.insert(50815, "unknown");
-        m.insert(50809, "unknown");
-        m.insert(50787, "unknown");
-        m.insert(50733, "unknown");
-        m.insert(50692, "unknown");
-        m.insert(50585, "unknown");
-        m.insert(50577, "unknown");
-        m.insert(50576, "unknown");
-        m.insert(50545, "unknown");
-        m.insert(50529, "unknown");
-        m.insert(50513, "unknown");
-        m.insert(50356, "unknown");
-        m.insert(50277, "unknown");
-        m.insert(50258, "unknown");
-        m.insert(50246, "unknown");
-        m.insert(50224, "unknown");
-        m.insert(50205, "unknown");
-        m.insert(50202, "unknown");
-        m.insert(50198, "unknown");
-        m.insert(50189, "unknown");
-        m.insert(5017, "unknown");
-        m.insert(5016, "unknown");
-        m.insert(50101, "unknown");
-        m.insert(50040, "unknown");
-        m.insert(50019, "unknown");
-        m.insert(50016, "unknown");
-        m.insert(49927, "unknown");
-        m.insert(49803, "unknown");
-        m.insert(49765, "unknown");
-        m.insert(49762, "unknown");
-        m.insert(49751, "unknown");
-        m.insert(49678, "unknown");
-        m.insert(49603, "unknown");
-        m.insert(49597, "unknown");
-        m.insert(49522, "unknown");
-        m.insert(49521, "unknown");
-        m.insert(49520, "unknown");
-        m.insert(49519, "unknown");
-        m.insert(49500, "unknown");
-        m.insert(49498, "unknown");
-        m.insert(49452, "unknown");
-        m.insert(49398, "unknown");
-        m.insert(49372, "unknown");
-        m.insert(49352, "unknown");
-        m.insert(4931, "unknown");
-        m.insert(49302, "unknown");
-        m.insert(49275, "unknown");
-        m.insert(49241, "unknown");
-        m.insert(49235, "unknown");
-        m.insert(49232, "unknown");
-        m.insert(49228, "unknown");
-        m.insert(49216, "unknown");
-        m.insert(49213, "unknown");
-        m.insert(49211, "unknown");
-        m.insert(49204, "unknown");
-        m.insert(49203, "unknown");
-        m.insert(49202, "unknown");
-        m.insert(49201, "unknown");
-        m.insert(49197, "unknown");
-        m.insert(49196, "unknown");
-        m.insert(49191, "unknown");
-        m.insert(49190, "unknown");
-        m.insert(49189, "unknown");
-        m.insert(49179, "unknown");
-        m.insert(49173, "unknown");
-        m.insert(49172, "unknown");
-        m.insert(49170, "unknown");
-        m.insert(49169, "unknown");
-        m.insert(49166, "unknown");
-        m.insert(49132, "unknown");
-        m.insert(49048, "unknown");
-        m.insert(4903, "unknown");
-        m.insert(49002, "unknown");
-        m.insert(48973, "unknown");
-        m.insert(48967, "unknown");
-        m.insert(48966, "unknown");
-        m.insert(48925, "unknown");
-        m.insert(48813, "unknown");
-        m.insert(48783, "unknown");
-        m.insert(48682, "unknown");
-        m.insert(48648, "unknown");
-        m.insert(48631, "unknown");
-        m.insert(4860, "unknown");
-        m.insert(4859, "unknown");
-        m.insert(48434, "unknown");
-        m.insert(48356, "unknown");
-        m.insert(4819, "unknown");
-        m.insert(48167, "unknown");
-        m.insert(48153, "unknown");
-        m.insert(48127, "unknown");
-        m.insert(48083, "unknown");
-        m.insert(48067, "unknown");
-        m.insert(48009, "unknown");
-        m.insert(47969, "unknown");
-        m.insert(47966, "unknown");
-        m.insert(4793, "unknown");
-        m.insert(47860, "unknown");
-        m.insert(47858, "unknown");
-        m.insert(47850, "unknown");
-        m.insert(4778, "unknown");
-        m.insert(47777, "unknown");
-        m.insert(4771, "unknown");
-        m.insert(4770, "unknown");
-        m.insert(47700, "unknown");
-        m.insert(4767, "unknown");
-        m.insert(47634, "unknown");
-        m.insert(4760, "unknown");
-        m.insert(47595, "unknown");
-        m.insert(47581, "unknown");
-        m.insert(47567, "unknown");
-        m.insert(47448, "unknown");
-        m.insert(47372, "unknown");
-        m.insert(47348, "unknown");
-        m.insert(47267, "unknown");
-        m.insert(47197, "unknown");
-        m.insert(4712, "unknown");
-        m.insert(47119, "unknown");
-        m.insert(47029, "unknown");
-        m.insert(47012, "unknown");
-        m.insert(46992, "unknown");
-        m.insert(46813, "unknown");
-        m.insert(46593, "unknown");
-        m.insert(4649, "unknown");
-        m.insert(4644, "unknown");
-        m.insert(46436, "unknown");
-        m.insert(46418, "unknown");
-        m.insert(46372, "unknown");
-        m.insert(46310, "unknown");
-        m.insert(46182, "unknown");
-        m.insert(46171, "unknown");
-        m.insert(46115, "unknown");
-        m.insert(4609, "unknown");
-        m.insert(46069, "unknown");
-        m.insert(46034, "unknown");
-        m.insert(45960, "unknown");
-        m.insert(45864, "unknown");
-        m.insert(45777, "unknown");
-        m.insert(45697, "unknown");
-        m.insert(45624, "unknown");
-        m.insert(45602, "unknown");
-        m.insert(45463, "unknown");
-        m.insert(45438, "unknown");
-        m.insert(45413, "unknown");
-        m.insert(4530, "unknown");
-        m.insert(45226, "unknown");
-        m.insert(45220, "unknown");
-        m.insert(4517, "unknown");
-        m.insert(4516, "unknown");
-        m.insert(45164, "unknown");
-        m.insert(45136, "unknown");
-        m.insert(45050, "unknown");
-        m.insert(45038, "unknown");
-        m.insert(44981, "unknown");
-        m.insert(44965, "unknown");
-        m.insert(4476, "unknown");
-        m.insert(4471, "unknown");
-        m.insert(44711, "unknown");
-        m.insert(44704, "unknown");
-        m.insert(4464, "unknown");
-        m.insert(44628, "unknown");
-        m.insert(44616, "unknown");
-        m.insert(44541, "unknown");
-        m.insert(44505, "unknown");
-        m.insert(44479, "unknown");
-        m.insert(44431, "unknown");
-        m.insert(44410, "unknown");
-        m.insert(44380, "unknown");
-        m.insert(44200, "unknown");
-        m.insert(44119, "unknown");
-        m.insert(44101, "unknown");
-        m.insert(44004, "unknown");
-        m.insert(4388, "unknown");
-        m.insert(43868, "unknown");
-        m.insert(4384, "unknown");
-        m.insert(43823, "unknown");
-        m.insert(43734, "unknown");
-        m.insert(43690, "unknown");
-        m.insert(43654, "unknown");
-        m.insert(43425, "unknown");
-        m.insert(43242, "unknown");
-        m.insert(43231, "unknown");
-        m.insert(43212, "unknown");
-        m.insert(43143, "unknown");
-        m.insert(43139, "unknown");
-        m.insert(43103, "unknown");
-        m.insert(43027, "unknown");
-        m.insert(43018, "unknown");
-        m.insert(43002, "unknown");
-        m.insert(42990, "unknown");
-        m.insert(42906, "unknown");
-        m.insert(42735, "unknown");
-        m.insert(42685, "unknown");
-        m.insert(42679, "unknown");
-        m.insert(42675, "unknown");
-        m.insert(42632, "unknown");
-        m.insert(42590, "unknown");
-        m.insert(42575, "unknown");
-        m.insert(42560, "unknown");
-        m.insert(42559, "unknown");
-        m.insert(42452, "unknown");
-        m.insert(42449, "unknown");
-        m.insert(42322, "unknown");
-        m.insert(42276, "unknown");
-        m.insert(42251, "unknown");
-        m.insert(42158, "unknown");
-        m.insert(42127, "unknown");
-        m.insert(42035, "unknown");
-        m.insert(42001, "unknown");
-        m.insert(41808, "unknown");
-        m.insert(41773, "unknown");
-        m.insert(41632, "unknown");
-        m.insert(41551, "unknown");
-        m.insert(41442, "unknown");
-        m.insert(41398, "unknown");
-        m.insert(41348, "unknown");
-        m.insert(41345, "unknown");
-        m.insert(41342, "unknown");
-        m.insert(41318, "unknown");
-        m.insert(41281, "unknown");
-        m.insert(41250, "unknown");
-        m.insert(41142, "unknown");
-        m.insert(41123, "unknown");
-        m.insert(40951, "unknown");
-        m.insert(40834, "unknown");
-        m.insert(40812, "unknown");
-        m.insert(40754, "unknown");
-        m.insert(40732, "unknown");
-        m.insert(40712, "unknown");
-        m.insert(40628, "unknown");
-        m.insert(40614, "unknown");
-        m.insert(40513, "unknown");
-        m.insert(40489, "unknown");
-        m.insert(40457, "unknown");
-        m.insert(40400, "unknown");
-        m.insert(40393, "unknown");
-        m.insert(40306, "unknown");
-        m.insert(40011, "unknown");
-        m.insert(40005, "unknown");
-        m.insert(40003, "unknown");
-        m.insert(40002, "unknown");
-        m.insert(40001, "unknown");
-        m.insert(39917, "unknown");
-        m.insert(39895, "unknown");
-        m.insert(39883, "unknown");
-        m.insert(39869, "unknown");
-        m.insert(39795, "unknown");
-        m.insert(39774, "unknown");
-        m.insert(39763, "unknown");
-        m.insert(39732, "unknown");
-        m.insert(39630, "unknown");
-        m.insert(39489, "unknown");
-        m.insert(39482, "unknown");
-        m.insert(39433, "unknown");
-        m.insert(39380, "unknown");
-        m.insert(39293, "unknown");
-        m.insert(39265, "unknown");
-        m.insert(39117, "unknown");
-        m.insert(39067, "unknown");
-        m.insert(38936, "unknown");
-        m.insert(38805, "unknown");
-        m.insert(38780, "unknown");
-        m.insert(38764, "unknown");
-        m.insert(38761, "unknown");
-        m.insert(38570, "unknown");
-        m.insert(38561, "unknown");
-        m.insert(38546, "unknown");
-        m.insert(38481, "unknown");
-        m.insert(38446, "unknown");
-        m.insert(38358, "unknown");
-        m.insert(38331, "unknown");
-        m.insert(38313, "unknown");
-        m.insert(38270, "unknown");
-        m.insert(38224, "unknown");
-        m.insert(38205, "unknown");
-        m.insert(38194, "unknown");
-        m.insert(38029, "unknown");
-        m.insert(37855, "unknown");
-        m.insert(37789, "unknown");
-        m.insert(37777, "unknown");
-        m.insert(37674, "unknown");
-        m.insert(37647, "unknown");
-        m.insert(37614, "unknown");
-        m.insert(37607, "unknown");
-        m.insert(37522, "unknown");
-        m.insert(37393, "unknown");
-        m.insert(37218, "unknown");
-        m.insert(37185, "unknown");
-        m.insert(37174, "unknown");
-        m.insert(37151, "unknown");
-        m.insert(37121, "unknown");
-        m.insert(36983, "unknown");
-        m.insert(36962, "unknown");
-        m.insert(36950, "unknown");
-        m.insert(36914, "unknown");
-        m.insert(36824, "unknown");
-        m.insert(36823, "unknown");
-        m.insert(36748, "unknown");
-        m.insert(36710, "unknown");
-        m.insert(36694, "unknown");
-        m.insert(36677, "unknown");
-        m.insert(36659, "unknown");
-        m.insert(36552, "unknown");
-        m.insert(36530, "unknown");
-        m.insert(36508, "unknown");
-        m.insert(36436, "unknown");
-        m.insert(36368, "unknown");
-        m.insert(36275, "unknown");
-        m.insert(36256, "unknown");
-        m.insert(36105, "unknown");
-        m.insert(36104, "unknown");
-        m.insert(36046, "unknown");
-        m.insert(35986, "unknown");
-        m.insert(35929, "unknown");
-        m.insert(35906, "unknown");
-        m.insert(35901, "unknown");
-        m.insert(35900, "unknown");
-        m.insert(35879, "unknown");
-        m.insert(35731, "unknown");
-        m.insert(35593, "unknown");
-        m.insert(35553, "unknown");
-        m.insert(35506, "unknown");
-        m.insert(35401, "unknown");
-        m.insert(35393, "unknown");
-        m.insert(35392, "unknown");
-        m.insert(35349, "unknown");
-        m.insert(35272, "unknown");
-        m.insert(35217, "unknown");
-        m.insert(35131, "unknown");
-        m.insert(35116, "unknown");
-        m.insert(35050, "unknown");
-        m.insert(35033, "unknown");
-        m.insert(34875, "unknown");
-        m.insert(34833, "unknown");
-        m.insert(34783, "unknown");
-        m.insert(34765, "unknown");
-        m.insert(34728, "unknown");
-        m.insert(34683, "unknown");
-        m.insert(34510, "unknown");
-        m.insert(34507, "unknown");
-        m.insert(34401, "unknown");
-        m.insert(34381, "unknown");
-        m.insert(34341, "unknown");
-        m.insert(34317, "unknown");
-        m.insert(34189, "unknown");
-        m.insert(34096, "unknown");
-        m.insert(34036, "unknown");
-        m.insert(34021, "unknown");
-        m.insert(33895, "unknown");
-        m.insert(33889, "unknown");
-        m.insert(33882, "unknown");
-        m.insert(33879, "unknown");
-        m.insert(33841, "unknown");
-        m.insert(33605, "unknown");
-        m.insert(33604, "unknown");
-        m.insert(33550, "unknown");
-        m.insert(33523, "unknown");
-        m.insert(33522, "unknown");
-        m.insert(33444, "unknown");
-        m.insert(33395, "unknown");
-        m.insert(33367, "unknown");
-        m.insert(33337, "unknown");
-        m.insert(33335, "unknown");
-        m.insert(33327, "unknown");
-        m.insert(33277, "unknown");
-        m.insert(33203, "unknown");
-        m.insert(33200, "unknown");
-        m.insert(33192, "unknown");
-        m.insert(33175, "unknown");
-        m.insert(33124, "unknown");
-        m.insert(33087, "unknown");
-        m.insert(33070, "unknown");
-        m.insert(33017, "unknown");
-        m.insert(33011, "unknown");
-        m.insert(32976, "unknown");
-        m.insert(32961, "unknown");
-        m.insert(32960, "unknown");
-        m.insert(32944, "unknown");
-        m.insert(32932, "unknown");
-        m.insert(32911, "unknown");
-        m.insert(32910, "unknown");
-        m.insert(32908, "unknown");
-        m.insert(32905, "unknown");
-        m.insert(32904, "unknown");
-        m.insert(32898, "unknown");
-        m.insert(32897, "unknown");
-        m.insert(32888, "unknown");
-        m.insert(32871, "unknown");
-        m.insert(32869, "unknown");
-        m.insert(32868, "unknown");
-        m.insert(32858, "unknown");
-        m.insert(32842, "unknown");
-        m.insert(32837, "unknown");
-        m.insert(32820, "unknown");
-        m.insert(32815, "unknown");
-        m.insert(32814, "unknown");
-        m.insert(32807, "unknown");
-        m.insert(32799, "unknown");
-        m.insert(32798, "unknown");
-        m.insert(32797, "unknown");
-        m.insert(32790, "unknown");
-        m.insert(32789, "unknown");
-        m.insert(32788, "unknown");
-        m.insert(32765, "unknown");
-        m.insert(32764, "unknown");
-        m.insert(32261, "unknown");
-        m.insert(32260, "unknown");
-        m.insert(32219, "unknown");
-        m.insert(32200, "unknown");
-        m.insert(32102, "unknown");
-        m.insert(32088, "unknown");
-        m.insert(32031, "unknown");
-        m.insert(32022, "unknown");
-        m.insert(32006, "unknown");
-        m.insert(31728, "unknown");
-        m.insert(31657, "unknown");
-        m.insert(31522, "unknown");
-        m.insert(31438, "unknown");
-        m.insert(31386, "unknown");
-        m.insert(31339, "unknown");
-        m.insert(31072, "unknown");
-        m.insert(31058, "unknown");
-        m.insert(31033, "unknown");
-        m.insert(30896, "unknown");
-        m.insert(30705, "unknown");
-        m.insert(30659, "unknown");
-        m.insert(30644, "unknown");
-        m.insert(30599, "unknown");
-        m.insert(30519, "unknown");
-        m.insert(30299, "unknown");
-        m.insert(30195, "unknown");
-        m.insert(30087, "unknown");
-        m.insert(29810, "unknown");
-        m.insert(29507, "unknown");
-        m.insert(29243, "unknown");
-        m.insert(29152, "unknown");
-        m.insert(29045, "unknown");
-        m.insert(28967, "unknown");
-        m.insert(28924, "unknown");
-        m.insert(28851, "unknown");
-        m.insert(28850, "unknown");
-        m.insert(28717, "unknown");
-        m.insert(28567, "unknown");
-        m.insert(28374, "unknown");
-        m.insert(28142, "unknown");
-        m.insert(28114, "unknown");
-        m.insert(27770, "unknown");
-        m.insert(27537, "unknown");
-        m.insert(27521, "unknown");
-        m.insert(27372, "unknown");
-        m.insert(27351, "unknown");
-        m.insert(27316, "unknown");
-        m.insert(27204, "unknown");
-        m.insert(27087, "unknown");
-        m.insert(27075, "unknown");
-        m.insert(27074, "unknown");
-        m.insert(27055, "unknown");
-        m.insert(27016, "unknown");
-        m.insert(27015, "unknown");
-        m.insert(26972, "unknown");
-        m.insert(26669, "unknown");
-        m.insert(26417, "unknown");
-        m.insert(26340, "unknown");
-        m.insert(26007, "unknown");
-        m.insert(26001, "unknown");
-        m.insert(25847, "unknown");
-        m.insert(25717, "unknown");
-        m.insert(25703, "unknown");
-        m.insert(25486, "unknown");
-        m.insert(25473, "unknown");
-        m.insert(25445, "unknown");
-        m.insert(25327, "unknown");
-        m.insert(25288, "unknown");
-        m.insert(25262, "unknown");
-        m.insert(25260, "unknown");
-        m.insert(25174, "unknown");
-        m.insert(24999, "unknown");
-        m.insert(24616, "unknown");
-        m.insert(24552, "unknown");
-        m.insert(24416, "unknown");
-        m.insert(24392, "unknown");
-        m.insert(24218, "unknown");
-        m.insert(23953, "unknown");
-        m.insert(23887, "unknown");
-        m.insert(23723, "unknown");
-        m.insert(23451, "unknown");
-        m.insert(23430, "unknown");
-        m.insert(23382, "unknown");
-        m.insert(23342, "unknown");
-        m.insert(23296, "unknown");
-        m.insert(23270, "unknown");
-        m.insert(23228, "unknown");
-        m.insert(23219, "unknown");
-        m.insert(23040, "unknown");
-        m.insert(23017, "unknown");
-        m.insert(22969, "unknown");
-        m.insert(22959, "unknown");
-        m.insert(22882, "unknown");
-        m.insert(22769, "unknown");
-        m.insert(22727, "unknown");
-        m.insert(22719, "unknown");
-        m.insert(22711, "unknown");
-        m.insert(22563, "unknown");
-        m.insert(22341, "unknown");
-        m.insert(22290, "unknown");
-        m.insert(22223, "unknown");
-        m.insert(22200, "unknown");
-        m.insert(22177, "unknown");
-        m.insert(22100, "unknown");
-        m.insert(22063, "unknown");
-        m.insert(22022, "unknown");
-        m.insert(21915, "unknown");
-        m.insert(21891, "unknown");
-        m.insert(21728, "unknown");
-        m.insert(21634, "unknown");
-        m.insert(21631, "unknown");
-        m.insert(21473, "unknown");
-        m.insert(21078, "unknown");
-        m.insert(21011, "unknown");
-        m.insert(20990, "unknown");
-        m.insert(20940, "unknown");
-        m.insert(20934, "unknown");
-        m.insert(20883, "unknown");
-        m.insert(20734, "unknown");
-        m.insert(20473, "unknown");
-        m.insert(20280, "unknown");
-        m.insert(20228, "unknown");
-        m.insert(20227, "unknown");
-        m.insert(20226, "unknown");
-        m.insert(20225, "unknown");
-        m.insert(20224, "unknown");
-        m.insert(20223, "unknown");
-        m.insert(20180, "unknown");
-        m.insert(20179, "unknown");
-        m.insert(20147, "unknown");
-        m.insert(20127, "unknown");
-        m.insert(20125, "unknown");
-        m.insert(20118, "unknown");
-        m.insert(20111, "unknown");
-        m.insert(20106, "unknown");
-        m.insert(20102, "unknown");
-        m.insert(20089, "unknown");
-        m.insert(20085, "unknown");
-        m.insert(20080, "unknown");
-        m.insert(20076, "unknown");
-        m.insert(20052, "unknown");
-        m.insert(20039, "unknown");
-        m.insert(20032, "unknown");
-        m.insert(20021, "unknown");
-        m.insert(20017, "unknown");
-        m.insert(20011, "unknown");
-        m.insert(19996, "unknown");
-        m.insert(19995, "unknown");
-        m.insert(19852, "unknown");
-        m.insert(19715, "unknown");
-        m.insert(19634, "unknown");
-        m.insert(19612, "unknown");
-        m.insert(19501, "unknown");
-        m.insert(19464, "unknown");
-        m.insert(19403, "unknown");
-        m.insert(19353, "unknown");
-        m.insert(19201, "unknown");
-        m.insert(19200, "unknown");
-        m.insert(19130, "unknown");
-        m.insert(19010, "unknown");
-        m.insert(18962, "unknown");
-        m.insert(18910, "unknown");
-        m.insert(18887, "unknown");
-        m.insert(18874, "unknown");
-        m.insert(18669, "unknown");
-        m.insert(18569, "unknown");
-        m.insert(18517, "unknown");
-        m.insert(18505, "unknown");
-        m.insert(18439, "unknown");
-        m.insert(18380, "unknown");
-        m.insert(18337, "unknown");
-        m.insert(18336, "unknown");
-        m.insert(18231, "unknown");
-        m.insert(18148, "unknown");
-        m.insert(18080, "unknown");
-        m.insert(18015, "unknown");
-        m.insert(18012, "unknown");
-        m.insert(17997, "unknown");
-        m.insert(17985, "unknown");
-        m.insert(17969, "unknown");
-        m.insert(17867, "unknown");
-        m.insert(17860, "unknown");
-        m.insert(17802, "unknown");
-        m.insert(17801, "unknown");
-        m.insert(17715, "unknown");
-        m.insert(17702, "unknown");
-        m.insert(17701, "unknown");
-        m.insert(17700, "unknown");
-        m.insert(17413, "unknown");
-        m.insert(17409, "unknown");
-        m.insert(17255, "unknown");
-        m.insert(17251, "unknown");
-        m.insert(17129, "unknown");
-        m.insert(17089, "unknown");
-        m.insert(17070, "unknown");
-        m.insert(17017, "unknown");
-        m.insert(17016, "unknown");
-        m.insert(16901, "unknown");
-        m.insert(16845, "unknown");
-        m.insert(16797, "unknown");
-        m.insert(16725, "unknown");
-        m.insert(16724, "unknown");
-        m.insert(16723, "unknown");
-        m.insert(16464, "unknown");
-        m.insert(16372, "unknown");
-        m.insert(16349, "unknown");
-        m.insert(16297, "unknown");
-        m.insert(16286, "unknown");
-        m.insert(16283, "unknown");
-        m.insert(16273, "unknown");
-        m.insert(16270, "unknown");
-        m.insert(16048, "unknown");
-        m.insert(15915, "unknown");
-        m.insert(15758, "unknown");
-        m.insert(15730, "unknown");
-        m.insert(15722, "unknown");
-        m.insert(15677, "unknown");
-        m.insert(15670, "unknown");
-        m.insert(15646, "unknown");
-        m.insert(15645, "unknown");
-        m.insert(15631, "unknown");
-        m.insert(15550, "unknown");
-        m.insert(15448, "unknown");
-        m.insert(15344, "unknown");
-        m.insert(15317, "unknown");
-        m.insert(15275, "unknown");
-        m.insert(15191, "unknown");
-        m.insert(15190, "unknown");
-        m.insert(15145, "unknown");
-        m.insert(15050, "unknown");
-        m.insert(15005, "unknown");
-        m.insert(14916, "unknown");
-        m.insert(14891, "unknown");
-        m.insert(14827, "unknown");
-        m.insert(14733, "unknown");
-        m.insert(14693, "unknown");
-        m.insert(14545, "unknown");
-        m.insert(14534, "unknown");
-        m.insert(14444, "unknown");
-        m.insert(14443, "unknown");
-        m.insert(14418, "unknown");
-        m.insert(14254, "unknown");
-        m.insert(14237, "unknown");
-        m.insert(14218, "unknown");
-        m.insert(14147, "unknown");
-        m.insert(13899, "unknown");
-        m.insert(13846, "unknown");
-        m.insert(13784, "unknown");
-        m.insert(13766, "unknown");
-        m.insert(13730, "unknown");
-        m.insert(13723, "unknown");
-        m.insert(13695, "unknown");
-        m.insert(13580, "unknown");
-        m.insert(13502, "unknown");
-        m.insert(13359, "unknown");
-        m.insert(13340, "unknown");
-        m.insert(13318, "unknown");
-        m.insert(13306, "unknown");
-        m.insert(13265, "unknown");
-        m.insert(13264, "unknown");
-        m.insert(13261, "unknown");
-        m.insert(13250, "unknown");
-        m.insert(13229, "unknown");
-        m.insert(13194, "unknown");
-        m.insert(13193, "unknown");
-        m.insert(13192, "unknown");
-        m.insert(13188, "unknown");
-        m.insert(13167, "unknown");
-        m.insert(13149, "unknown");
-        m.insert(13142, "unknown");
-        m.insert(13140, "unknown");
-        m.insert(13132, "unknown");
-        m.insert(13130, "unknown");
-        m.insert(13093, "unknown");
-        m.insert(13017, "unknown");
-        m.insert(12962, "unknown");
-        m.insert(12955, "unknown");
-        m.insert(12892, "unknown");
-        m.insert(12891, "unknown");
-        m.insert(12766, "unknown");
-        m.insert(12702, "unknown");
-        m.insert(12699, "unknown");
-        m.insert(12414, "unknown");
-        m.insert(12340, "unknown");
-        m.insert(12296, "unknown");
-        m.insert(12275, "unknown");
-        m.insert(12271, "unknown");
-        m.insert(12251, "unknown");
-        m.insert(12243, "unknown");
-        m.insert(12240, "unknown");
-        m.insert(12225, "unknown");
-        m.insert(12192, "unknown");
-        m.insert(12171, "unknown");
-        m.insert(12156, "unknown");
-        m.insert(12146, "unknown");
-        m.insert(12137, "unknown");
-        m.insert(12132, "unknown");
-        m.insert(12097, "unknown");
-        m.insert(12096, "unknown");
-        m.insert(12090, "unknown");
-        m.insert(12080, "unknown");
-        m.insert(12077, "unknown");
-        m.insert(12034, "unknown");
-        m.insert(12031, "unknown");
-        m.insert(12019, "unknown");
-        m.insert(11940, "unknown");
-        m.insert(11863, "unknown");
-        m.insert(11862, "unknown");
-        m.insert(11813, "unknown");
-        m.insert(11735, "unknown");
-        m.insert(11697, "unknown");
-        m.insert(11552, "unknown");
-        m.insert(11401, "unknown");
-        m.insert(11296, "unknown");
-        m.insert(11288, "unknown");
-        m.insert(11250, "unknown");
-        m.insert(11224, "unknown");
-        m.insert(11200, "unknown");
-        m.insert(11180, "unknown");
-        m.insert(11100, "unknown");
-        m.insert(11089, "unknown");
-        m.insert(11033, "unknown");
-        m.insert(11032, "unknown");
-        m.insert(11031, "unknown");
-        m.insert(11026, "unknown");
-        m.insert(11019, "unknown");
-        m.insert(11007, "unknown");
-        m.insert(11003, "unknown");
-        m.insert(10900, "unknown");
-        m.insert(10878, "unknown");
-        m.insert(10852, "unknown");
-        m.insert(10842, "unknown");
-        m.insert(10754, "unknown");
-        m.insert(10699, "unknown");
-        m.insert(10602, "unknown");
-        m.insert(10601, "unknown");
-        m.insert(10567, "unknown");
-        m.insert(10565, "unknown");
-        m.insert(10556, "unknown");
-        m.insert(10555, "unknown");
-        m.insert(10554, "unknown");
-        m.insert(10553, "unknown");
-        m.insert(10552, "unknown");
-        m.insert(10551, "unknown");
-        m.insert(10550, "unknown");
-        m.insert(10535, "unknown");
-        m.insert(10529, "unknown");
-        m.insert(10509, "unknown");
-        m.insert(10494, "unknown");
-        m.insert(10414, "unknown");
-        m.insert(10387, "unknown");
-        m.insert(10357, "unknown");
-        m.insert(10347, "unknown");
-        m.insert(10338, "unknown");
-        m.insert(10280, "unknown");
-        m.insert(10255, "unknown");
-        m.insert(10246, "unknown");
-        m.insert(10245, "unknown");
-        m.insert(10238, "unknown");
-        m.insert(10093, "unknown");
-        m.insert(10064, "unknown");
-        m.insert(10045, "unknown");
-        m.insert(10042, "unknown");
-        m.insert(10035, "unknown");
-        m.insert(10019, "unknown");
-        m.insert(10018, "unknown");
-        m.insert(1327, "ultrex");
-        m.insert(2330, "tscchat");
-        m.insert(2580, "tributary");
-        m.insert(2700, "tqdata");
-        m.insert(1584, "tn-tl-fd2");
-        m.insert(9020, "tambora");
-        m.insert(3281, "sysopt");
-        m.insert(2439, "sybasedbsynch");
-        m.insert(1250, "swldy-sias");
-        m.insert(1607, "stt");
-        m.insert(1736, "street-stream");
-        m.insert(1330, "streetperfect");
-        m.insert(2270, "starschool");
-        m.insert(2728, "sqdr");
-        m.insert(2888, "spcsdlobby");
-        m.insert(3803, "soniqsync");
-        m.insert(5250, "soagateway");
-        m.insert(1645, "sightline");
-        m.insert(1303, "sftsrv");
-        m.insert(3636, "sertvistaitsm");
-        m.insert(1251, "sertvergraph");
-        m.insert(1243, "sertialgateway");
-        m.insert(1291, "seagulllms");
-        m.insert(1297, "sdproxy");
-        m.insert(1200, "scol");
-        m.insert(1811, "scientia-sdb");
-        m.insert(4442, "saris");
-        m.insert(1118, "sacred");
-        m.insert(8401, "sabarsd");
-        m.insert(2101, "rtcm-sc104");
-        m.insert(2889, "rsom");
-        m.insert(1694, "rrimwm");
-        m.insert(1730, "roketz");
-        m.insert(1912, "rhp-iibp");
-        m.insert(1745, "remote-winsock");
-        m.insert(2250, "remote-collab");
-        m.insert(1306, "re-conn-proto");
-        m.insert(2997, "rebol");
-        m.insert(2449, "ratl");
-        m.insert(1262, "qnts-orb");
-        m.insert(4007, "pxc-splr");
-        m.insert(1101, "pt2-discover");
-        m.insert(1268, "propel-msgsys");
-        m.insert(1735, "privatechat");
-        m.insert(1858, "privateark");
-        m.insert(1264, "prat");
-        m.insert(1711, "pptconference");
-        m.insert(3118, "pkagent");
-        m.insert(4601, "piranha2");
-        m.insert(1321, "pip");
-        m.insert(1598, "picknfs");
-        m.insert(1305, "pe-mike");
-        m.insert(1632, "pammratc");
-        m.insert(9995, "palace-4");
-        m.insert(1307, "pacmand");
-        m.insert(1981, "p2pq");
-        m.insert(2532, "ovtopmd");
-        m.insert(1808, "oracle-vp2");
-        m.insert(2435, "optilogic");
-        m.insert(1194, "openvpn");
-        m.insert(1622, "ontime");
-        m.insert(1239, "nmsd");
-        m.insert(1799, "netrisk");
-        m.insert(2882, "ndtp");
-        m.insert(1683, "ncpm-hip");
-        m.insert(3063, "ncadg-ip-udp");
-        m.insert(3062, "ncacn-ip-tcp");
-        m.insert(1340, "naap");
-        m.insert(4447, "n1-rmgmt");
-        m.insert(1806, "musiconline");
-        m.insert(6888, "muse");
-        m.insert(2438, "msp");
-        m.insert(1261, "mpshrsv");
-        m.insert(5969, "mppolicy-mgr");
-        m.insert(9343, "mpidcmgr");
-        m.insert(2583, "mon");
-        m.insert(2031, "mobrien-chat");
-        m.insert(3798, "minilock");
-        m.insert(2269, "mikey");
-        m.insert(20001, "microsan");
-        m.insert(2622, "metricadbc");
-        m.insert(11001, "metasys");
-        m.insert(1207, "metasage");
-        m.insert(2850, "metaconsole");
-        m.insert(21201, "memcachedb");
-        m.insert(2908, "mao");
-        m.insert(3936, "mailprox");
-        m.insert(3023, "magicnotes");
-        m.insert(2280, "lnvpoller");
-        m.insert(2623, "lmdp");
-        m.insert(7099, "lazy-ptop");
-        m.insert(2372, "lanmessenger");
-        m.insert(1318, "krb5gatekeeper");
-        m.insert(1339, "kjtsitesertver");
-        m.insert(1276, "ivmanager");
-        m.insert(11000, "irisa");
-        m.insert(48619, "iqobject");
-        m.insert(3497, "ipether232port");
-        m.insert(1209, "ipcd3");
-        m.insert(1331, "intersan");
-        m.insert(1240, "instantia");
-        m.insert(3856, "informer");
-        m.insert(2987, "identify");
-        m.insert(2326, "idcp");
-        m.insert(25001, "icl-twobase2");
-        m.insert(25000, "icl-twobase1");
-        m.insert(1792, "ibm-dt-2");
-        m.insert(3919, "hyperip");
-        m.insert(1299, "hp-sci");
-        m.insert(2984, "hpidsadmin");
-        m.insert(1715, "houdini-lm");
-        m.insert(1703, "hb-engine");
-        m.insert(1677, "groupwise");
-        m.insert(2086, "gnunet");
-        m.insert(1708, "gat-lmd");
-        m.insert(1228, "florence");
-        m.insert(3787, "fintrx");
-        m.insert(5502, "fcp-srvr-inst1");
-        m.insert(1620, "faxportwinport");
-        m.insert(1316, "exbit-escp");
-        m.insert(1569, "ets");
-        m.insert(1210, "eoss");
-        m.insert(1691, "empire-empuma");
-        m.insert(1282, "emperion");
-        m.insert(2124, "elatelink");
-        m.insert(1791, "ea1");
-        m.insert(2150, "dynamic3d");
-        m.insert(9909, "domaintime");
-        m.insert(4022, "dnox");
-        m.insert(1324, "delta-mcp");
-        m.insert(2584, "cyasertv");
-        m.insert(2300, "cvmmon");
-        m.insert(9287, "cumulus");
-        m.insert(2806, "cspuni");
-        m.insert(1566, "corelvideo");
-        m.insert(1713, "conferencetalk");
-        m.insert(1592, "commonspace");
-        m.insert(3749, "cimtrak");
-        m.insert(1302, "ci3-software-2");
-        m.insert(1709, "centra");
-        m.insert(3485, "celatalk");
-        m.insert(2418, "cas");
-        m.insert(2472, "c3");
-        m.insert(24554, "binkp");
-        m.insert(3146, "bears-02");
-        m.insert(2134, "avenue");
-        m.insert(2898, "appliance-cfg");
-        m.insert(9161, "apani2");
-        m.insert(9160, "apani1");
-        m.insert(2930, "amx-weblinx");
-        m.insert(1319, "amx-icsp");
-        m.insert(3811, "amp");
-        m.insert(2456, "altav-remmgt");
-        m.insert(2901, "allstorcns");
-        m.insert(6579, "affiliate");
-        m.insert(2550, "ads");
-        m.insert(8403, "admind");
-        m.insert(31416, "boinc");
-        m.insert(22273, "wnn6");
-        m.insert(7005, "afs3-volsert");
-        m.insert(66, "sqlnet");
-        m.insert(32786, "sometimes-rpc25");
-        m.insert(32787, "sometimes-rpc27");
-        m.insert(706, "silc");
-        m.insert(635, "rlzdbase");
-        m.insert(6105, "isdninfo");
-        m.insert(400, "work-sol");
-        m.insert(47, "ni-ftp");
-        m.insert(830, "netconf-ssh");
-        m.insert(4008, "netcheque");
-        m.insert(5977, "ncd-pref-tcp");
-        m.insert(1989, "tr-rsrb-p3");
-        m.insert(1444, "marcam-lm");
-        m.insert(3985, "mapper-mapethd");
-        m.insert(678, "ggf-ncp");
-        m.insert(27001, "flexlm1");
-        m.insert(591, "http-alt");
-        m.insert(642, "esro-emsdp");
-        m.insert(446, "ddm-rdb");
-        m.insert(1441, "cadis-1");
-        m.insert(54320, "bo2k");
-        m.insert(11, "systat");
-        m.insert(769, "vid");
-        m.insert(983, "unknown");
-        m.insert(979, "unknown");
-        m.insert(973, "unknown");
-        m.insert(967, "unknown");
-        m.insert(965, "unknown");
-        m.insert(961, "unknown");
-        m.insert(942, "unknown");
-        m.insert(935, "unknown");
-        m.insert(926, "unknown");
-        m.insert(925, "unknown");
-        m.insert(914, "unknown");
-        m.insert(863, "unknown");
-        m.insert(858, "unknown");
-        m.insert(844, "unknown");
-        m.insert(834, "unknown");
-        m.insert(817, "unknown");
-        m.insert(815, "unknown");
-        m.insert(811, "unknown");
-        m.insert(809, "unknown");
-        m.insert(789, "unknown");
-        m.insert(779, "unknown");
-        m.insert(743, "unknown");
-        m.insert(1019, "unknown");
-        m.insert(1507, "symplex");
-        m.insert(1492, "stone-design-1");
-        m.insert(509, "snare");
-        m.insert(762, "quotad");
-        m.insert(5632, "pcanywherestat");
-        m.insert(578, "ipdd");
-        m.insert(1495, "cvc");
-        m.insert(5308, "cfengine");
-        m.insert(52, "xns-time");
-        m.insert(219, "uarps");
-        m.insert(525, "timed");
-        m.insert(1420, "timbuktu-srv4");
-        m.insert(665, "sun-dr");
-        m.insert(620, "sco-websrvrmgr");
-        m.insert(3064, "dnet-tstproxy");
-        m.insert(3045, "slnp");
-        m.insert(653, "repscmd");
-        m.insert(158, "pcmail-srv");
-        m.insert(716, "pana");
-        m.insert(861, "owamp-control");
-        m.insert(9991, "issa");
-        m.insert(3049, "cfs");
-        m.insert(1366, "netware-csp");
-        m.insert(1364, "ndm-sertver");
-        m.insert(833, "netconfsoapbeep");
-        m.insert(91, "mit-dov");
-        m.insert(1680, "CarbonCopy");
-        m.insert(3398, "sapcomm");
-        m.insert(750, "kerberos");
-        m.insert(615, "sco-inetmgr");
-        m.insert(603, "mnotes");
-        m.insert(6110, "softcm");
-        m.insert(101, "hostname");
-        m.insert(989, "ftps-data");
-        m.insert(27010, "flexlm10");
-        m.insert(510, "fcp");
-        m.insert(810, "fcp-udp");
-        m.insert(1139, "cce3x");
-        m.insert(4199, "eims-admin");
-        m.insert(76, "deos");
-        m.insert(847, "dhcp-failover2");
-        m.insert(649, "cadview-3d");
-        m.insert(707, "borland-dsj");
-        m.insert(68, "dhcpc");
-        m.insert(449, "as-sertvermap");
-        m.insert(664, "secure-aux-bus");
-        m.insert(75, "priv-dial");
-        m.insert(104, "acr-nema");
-        m.insert(629, "3com-amp3");
-        m.insert(1652, "xnmp");
-        m.insert(682, "xfr");
-        m.insert(577, "vnas");
-        m.insert(985, "unknown");
-        m.insert(984, "unknown");
-        m.insert(974, "unknown");
-        m.insert(958, "unknown");
-        m.insert(952, "unknown");
-        m.insert(949, "unknown");
-        m.insert(946, "unknown");
-        m.insert(923, "unknown");
-        m.insert(916, "unknown");
-        m.insert(899, "unknown");
-        m.insert(897, "unknown");
-        m.insert(894, "unknown");
-        m.insert(889, "unknown");
-        m.insert(835, "unknown");
-        m.insert(824, "unknown");
-        m.insert(814, "unknown");
-        m.insert(807, "unknown");
-        m.insert(804, "unknown");
-        m.insert(798, "unknown");
-        m.insert(733, "unknown");
-        m.insert(727, "unknown");
-        m.insert(237, "unknown");
-        m.insert(12, "unknown");
-        m.insert(10, "unknown");
-        m.insert(501, "stmf");
-        m.insert(122, "smakynet");
-        m.insert(440, "sgcp");
-        m.insert(771, "rtip");
-        m.insert(1663, "netview-aix-3");
-        m.insert(828, "itm-mcell-s");
-        m.insert(860, "iscsi");
-        m.insert(695, "ieee-mms-ssl");
-        m.insert(634, "ginad");
-        m.insert(538, "gdomap");
-        m.insert(1359, "ftsrv");
-        m.insert(1358, "connlcli");
-        m.insert(1517, "vpac");
-        m.insert(1370, "us-gv");
-        m.insert(3900, "udt_os");
-        m.insert(492, "ticf-1");
-        m.insert(268, "td-replica");
-        m.insert(27374, "subseven");
-        m.insert(605, "soap-beep");
-        m.insert(8076, "slnp");
-        m.insert(1651, "shiva_confsrvr");
-        m.insert(1178, "skksertv");
-        m.insert(6401, "crystalenterprise");
-        m.insert(761, "kpasswd");
-        m.insert(5145, "rmonitor_secure");
-        m.insert(50, "re-mail-ck");
-        m.insert(2018, "terminaldb");
-        m.insert(1349, "sbook");
-        m.insert(2014, "troff");
-        m.insert(7597, "qaz");
-        m.insert(2120, "kauth");
-        m.insert(1445, "proxima-lm");
-        m.insert(1402, "prm-sm-np");
-        m.insert(1465, "pipes");
-        m.insert(9104, "jetdirect");
-        m.insert(627, "passgo-tivoli");
-        m.insert(4660, "mosmig");
-        m.insert(7273, "openmanage");
-        m.insert(950, "oftep-rpc");
-        m.insert(1384, "os-licman");
-        m.insert(1388, "objective-dbc");
-        m.insert(760, "krbupdate");
-        m.insert(92, "npp");
-        m.insert(831, "netconf-beep");
-        m.insert(5978, "ncd-diag-tcp");
-        m.insert(4557, "fax");
-        m.insert(45, "mpm");
-        m.insert(112, "mcidas");
-        m.insert(456, "macon");
-        m.insert(1214, "fasttrack");
-        m.insert(3086, "sj3");
-        m.insert(702, "iris-beep");
-        m.insert(6665, "irc");
-        m.insert(1404, "igi-lm");
-        m.insert(651, "ieee-mms");
-        m.insert(5300, "hacl-hb");
-        m.insert(6347, "gnutella2");
-        m.insert(5400, "pcduo-old");
-        m.insert(1389, "iclpv-dm");
-        m.insert(647, "dhcp-failover");
-        m.insert(448, "ddm-ssl");
-        m.insert(1356, "cuillamartin");
-        m.insert(5232, "sgi-dgl");
-        m.insert(1484, "confluent");
-        m.insert(450, "tsertver");
-        m.insert(1991, "stun-p2");
-        m.insert(1988, "tr-rsrb-p2");
-        m.insert(1523, "cichild-lm");
-        m.insert(1400, "cadkey-tablet");
-        m.insert(1399, "cadkey-licman");
-        m.insert(221, "fln-spx");
-        m.insert(1385, "atex_elmd");
-        m.insert(5191, "aol-1");
-        m.insert(1346, "alta-ana-lm");
-        m.insert(2024, "xinuexpansion4");
-        m.insert(2430, "venus");
-        m.insert(988, "unknown");
-        m.insert(962, "unknown");
-        m.insert(948, "unknown");
-        m.insert(945, "unknown");
-        m.insert(941, "unknown");
-        m.insert(938, "unknown");
-        m.insert(936, "unknown");
-        m.insert(929, "unknown");
-        m.insert(927, "unknown");
-        m.insert(919, "unknown");
-        m.insert(906, "unknown");
-        m.insert(883, "unknown");
-        m.insert(881, "unknown");
-        m.insert(875, "unknown");
-        m.insert(872, "unknown");
-        m.insert(870, "unknown");
-        m.insert(866, "unknown");
-        m.insert(855, "unknown");
-        m.insert(851, "unknown");
-        m.insert(850, "unknown");
-        m.insert(841, "unknown");
-        m.insert(836, "unknown");
-        m.insert(826, "unknown");
-        m.insert(820, "unknown");
-        m.insert(819, "unknown");
-        m.insert(816, "unknown");
-        m.insert(813, "unknown");
-        m.insert(791, "unknown");
-        m.insert(745, "unknown");
-        m.insert(736, "unknown");
-        m.insert(735, "unknown");
-        m.insert(724, "unknown");
-        m.insert(719, "unknown");
-        m.insert(343, "unknown");
-        m.insert(334, "unknown");
-        m.insert(300, "unknown");
-        m.insert(28, "unknown");
-        m.insert(249, "unknown");
-        m.insert(230, "unknown");
-        m.insert(16, "unknown");
-        m.insert(1018, "unknown");
-        m.insert(1016, "unknown");
-        m.insert(658, "tenfold");
-        m.insert(1474, "telefinder");
-        m.insert(696, "rushd");
-        m.insert(630, "rda");
-        m.insert(663, "purenoise");
-        m.insert(2307, "pehelp");
-        m.insert(1552, "pciarray");
-        m.insert(609, "npmp-trap");
-        m.insert(741, "netgw");
-        m.insert(353, "ndsauth");
-        m.insert(638, "mcns-sec");
-        m.insert(1551, "hecmtl-db");
-        m.insert(661, "hap");
-        m.insert(491, "go-login");
-        m.insert(640, "entrust-sps");
-        m.insert(507, "crs");
-        m.insert(673, "cimplex");
-        m.insert(632, "bmpp");
-        m.insert(1354, "rightbrain");
-        m.insert(9105, "jetdirect");
-        m.insert(6143, "watershed-lm");
-        m.insert(676, "vpps-via");
-        m.insert(214, "vmpwscs");
-        m.insert(14141, "bo2k");
-        m.insert(182, "audit");
-        m.insert(69, "tftp");
-        m.insert(27665, "Trinoo_Master");
-        m.insert(1475, "taligent-lm");
-        m.insert(97, "swift-rvf");
-        m.insert(633, "sertvstat");
-        m.insert(560, "rmonitor");
-        m.insert(799, "controlit");
-        m.insert(7009, "afs3-rmtsys");
-        m.insert(2015, "cypress");
-        m.insert(628, "qmqp");
-        m.insert(751, "kadmin");
-        m.insert(4480, "proxy-plus");
-        m.insert(1403, "prm-nm-np");
-        m.insert(8123, "polipo");
-        m.insert(1527, "tlisrv");
-        m.insert(723, "omfs");
-        m.insert(1466, "oceansoft-lm");
-        m.insert(1486, "nms_topo_sertv");
-        m.insert(1650, "nkd");
-        m.insert(991, "nas");
-        m.insert(832, "netconfsoaphttp");
-        m.insert(137, "netbios-ns");
-        m.insert(1348, "bbn-mmx");
-        m.insert(685, "mdc-portmapper");
-        m.insert(1762, "landesk-rc");
-        m.insert(6701, "carracho");
-        m.insert(994, "ircs");
-        m.insert(4500, "sae-urn");
-        m.insert(194, "irc");
-        m.insert(180, "ris");
-        m.insert(1539, "intellistor-lm");
-        m.insert(1379, "dbreporter");
-        m.insert(51, "la-maint");
-        m.insert(886, "iclcnet-locate");
-        m.insert(2064, "dnet-keyproxy");
-        m.insert(1405, "ibm-res");
-        m.insert(1435, "ibm-cics");
-        m.insert(11371, "pksd");
-        m.insert(1401, "goldleaf-licman");
-        m.insert(1369, "gv-us");
-        m.insert(402, "genie");
-        m.insert(103, "gppitnp");
-        m.insert(1372, "fc-sert");
-        m.insert(704, "elcsd");
-        m.insert(854, "dlep");
-        m.insert(8892, "seosload");
-        m.insert(47557, "dbbrowse");
-        m.insert(624, "cryptoadmin");
-        m.insert(1387, "cadsi-lm");
-        m.insert(3397, "saposs");
-        m.insert(1996, "tr-rsrb-port");
-        m.insert(1995, "perf-port");
-        m.insert(1997, "gdp-port");
-        m.insert(18182, "opsec-ufp");
-        m.insert(18184, "opsec-lea");
-        m.insert(3264, "ccmail");
-        m.insert(3292, "meetingmaker");
-        m.insert(13720, "netbackup");
-        m.insert(9107, "jetdirect");
-        m.insert(9106, "jetdirect");
-        m.insert(201, "at-rtmp");
-        m.insert(1381, "apple-licman");
-        m.insert(35, "priv-print");
-        m.insert(6588, "analogx");
-        m.insert(5530, "sdsertv");
-        m.insert(3141, "vmodem");
-        m.insert(670, "vacdsm-sws");
-        m.insert(970, "unknown");
-        m.insert(968, "unknown");
-        m.insert(964, "unknown");
-        m.insert(963, "unknown");
-        m.insert(960, "unknown");
-        m.insert(959, "unknown");
-        m.insert(951, "unknown");
-        m.insert(947, "unknown");
-        m.insert(944, "unknown");
-        m.insert(939, "unknown");
-        m.insert(933, "unknown");
-        m.insert(909, "unknown");
-        m.insert(895, "unknown");
-        m.insert(891, "unknown");
-        m.insert(879, "unknown");
-        m.insert(869, "unknown");
-        m.insert(868, "unknown");
-        m.insert(867, "unknown");
-        m.insert(837, "unknown");
-        m.insert(821, "unknown");
-        m.insert(812, "unknown");
-        m.insert(797, "unknown");
-        m.insert(796, "unknown");
-        m.insert(794, "unknown");
-        m.insert(788, "unknown");
-        m.insert(756, "unknown");
-        m.insert(734, "unknown");
-        m.insert(721, "unknown");
-        m.insert(718, "unknown");
-        m.insert(708, "unknown");
-        m.insert(703, "unknown");
-        m.insert(60, "unknown");
-        m.insert(40, "unknown");
-        m.insert(253, "unknown");
-        m.insert(231, "unknown");
-        m.insert(14, "unknown");
-        m.insert(1017, "unknown");
-        m.insert(1003, "unknown");
-        m.insert(656, "spmp");
-        m.insert(975, "securenetpro-sensor");
-        m.insert(2026, "scrabble");
-        m.insert(1497, "rfx-lm");
-        m.insert(553, "pirp");
-        m.insert(511, "passgo");
-        m.insert(611, "npmp-gui");
-        m.insert(689, "nmap");
-        m.insert(1668, "netview-aix-8");
-        m.insert(1664, "netview-aix-4");
-        m.insert(15, "netstat");
-        m.insert(561, "monitor");
-        m.insert(997, "maitrd");
-        m.insert(505, "mailbox-lm");
-        m.insert(1496, "liberty-lm");
-        m.insert(637, "lansertver");
-        m.insert(213, "ipx");
-        m.insert(1412, "innosys");
-        m.insert(1515, "ifor-protocol");
-        m.insert(692, "hyperwave-isp");
-        m.insert(694, "ha-cluster");
-        m.insert(681, "entrust-aams");
-        m.insert(680, "entrust-aaas");
-        m.insert(644, "dwr");
-        m.insert(675, "dctp");
-        m.insert(1467, "csdmbase");
-        m.insert(454, "contentsertver");
-        m.insert(622, "collaborator");
-        m.insert(1476, "clvm-cfg");
-        m.insert(1373, "chromagrafx");
-        m.insert(770, "cadlock");
-        m.insert(262, "arcisdms");
-        m.insert(654, "aodv");
-        m.insert(1535, "ampr-info");
-        m.insert(58, "xns-mail");
-        m.insert(177, "xdmcp");
-        m.insert(26208, "wnn6_DS");
-        m.insert(677, "vpp");
-        m.insert(1519, "vpvc");
-        m.insert(1398, "video-activmail");
-        m.insert(3457, "vat-control");
-        m.insert(401, "ups");
-        m.insert(412, "synoptics-trap");
-        m.insert(493, "ticf-2");
-        m.insert(13713, "netbackup");
-        m.insert(94, "objcall");
-        m.insert(1498, "watcom-sql");
-        m.insert(871, "supfilesrv");
-        m.insert(1390, "iclpv-sc");
-        m.insert(6145, "statsci2-lm");
-        m.insert(133, "statsrv");
-        m.insert(362, "srssend");
-        m.insert(118, "sqlsertv");
-        m.insert(193, "srmp");
-        m.insert(115, "sftp");
-        m.insert(1549, "shivahose");
-        m.insert(7008, "afs3-update");
-        m.insert(608, "sift-uft");
-        m.insert(1426, "sas-1");
-        m.insert(1436, "sas-2");
-        m.insert(38, "rap");
-        m.insert(74, "netrjs-4");
-        m.insert(73, "netrjs-3");
-        m.insert(71, "netrjs-1");
-        m.insert(601, "syslog-conn");
-        m.insert(136, "profile");
-        m.insert(4144, "wincim");
-        m.insert(129, "pwdgen");
-        m.insert(16444, "overnet");
-        m.insert(1446, "ora-lm");
-        m.insert(4132, "nuts_dem");
-        m.insert(308, "novastorbakcup");
-        m.insert(1528, "mciautoreg");
-        m.insert(1365, "adapt-sna");
-        m.insert(1393, "iclpv-nls");
-        m.insert(1394, "iclpv-nlc");
-        m.insert(1493, "netmap_lm");
-        m.insert(138, "netbios-dgm");
-        m.insert(5997, "ncd-pref");
-        m.insert(397, "mptn");
-        m.insert(29, "msg-icp");
-        m.insert(31, "msg-auth");
-        m.insert(44, "mpm-flags");
-        m.insert(2627, "webster");
-        m.insert(6147, "montage-lm");
-        m.insert(1510, "mvx-lm");
-        m.insert(568, "ms-shuttle");
-        m.insert(350, "matip-type-a");
-        m.insert(2053, "knetd");
-        m.insert(6146, "lonewolf-lm");
-        m.insert(6544, "mythtv");
-        m.insert(1763, "landesk-rc");
-        m.insert(3531, "peerenabler");
-        m.insert(399, "iso-tsap-c2");
-        m.insert(1537, "sdsc-lm");
-        m.insert(1992, "stun-p3");
-        m.insert(1355, "intuitive-edge");
-        m.insert(1454, "interhdl_elmd");
-        m.insert(261, "nsiiops");
-        m.insert(887, "iclcnet_svinfo");
-        m.insert(200, "src");
-        m.insert(1376, "ibm-pps");
-        m.insert(1424, "hybrid");
-        m.insert(6111, "spc");
-        m.insert(1410, "hiq");
-        m.insert(1409, "here-lm");
-        m.insert(686, "hcp-wismar");
-        m.insert(5301, "hacl-gs");
-        m.insert(5302, "hacl-cfg");
-        m.insert(1513, "fujitsu-dtc");
-        m.insert(747, "fujitsu-dev");
-        m.insert(9051, "tor-control");
-        m.insert(1499, "fhc");
-        m.insert(7006, "afs3-errors");
-        m.insert(1439, "eicon-x25");
-        m.insert(1438, "eicon-sertver");
-        m.insert(8770, "apple-iphoto");
-        m.insert(853, "domain-s");
-        m.insert(196, "dn6-smm-red");
-        m.insert(93, "dcp");
-        m.insert(410, "decladebug");
-        m.insert(462, "datasurfsrvsec");
-        m.insert(619, "compaq-evm");
-        m.insert(1529, "support");
-        m.insert(1990, "stun-p1");
-        m.insert(1994, "stun-port");
-        m.insert(1986, "licensedaemon");
-        m.insert(1386, "checksum");
-        m.insert(18183, "opsec-sam");
-        m.insert(18181, "opsec-cvp");
-        m.insert(6700, "carracho");
-        m.insert(1442, "cadis-2");
-        m.insert(95, "supdup");
-        m.insert(6400, "crystalreports");
-        m.insert(1432, "blueberry-lm");
-        m.insert(1548, "axon-lm");
-        m.insert(486, "sstats");
-        m.insert(1422, "autodesk-lm");
-        m.insert(114, "audionews");
-        m.insert(1397, "audio-activmail");
-        m.insert(6142, "aspentec-lm");
-        m.insert(1827, "pcm");
-        m.insert(626, "apple-imap-admin");
-        m.insert(422, "ariel3");
-        m.insert(688, "realm-rusd");
-        m.insert(206, "at-zis");
-        m.insert(202, "at-nbp");
-        m.insert(204, "at-echo");
-        m.insert(1483, "afs");
-        m.insert(7634, "hddtemp");
-        m.insert(774, "rpasswd");
-        m.insert(699, "accessnetwork");
-        m.insert(2023, "xinuexpansion3");
-        m.insert(776, "wpages");
-        m.insert(672, "vpps-qua");
-        m.insert(1545, "vistium-share");
-        m.insert(2431, "venus-se");
-        m.insert(697, "uuidgen");
-        m.insert(982, "unknown");
-        m.insert(978, "unknown");
-        m.insert(972, "unknown");
-        m.insert(966, "unknown");
-        m.insert(957, "unknown");
-        m.insert(956, "unknown");
-        m.insert(934, "unknown");
-        m.insert(920, "unknown");
-        m.insert(915, "unknown");
-        m.insert(908, "unknown");
-        m.insert(907, "unknown");
-        m.insert(892, "unknown");
-        m.insert(890, "unknown");
-        m.insert(885, "unknown");
-        m.insert(884, "unknown");
-        m.insert(882, "unknown");
-        m.insert(877, "unknown");
-        m.insert(876, "unknown");
-        m.insert(865, "unknown");
-        m.insert(857, "unknown");
-        m.insert(852, "unknown");
-        m.insert(849, "unknown");
-        m.insert(842, "unknown");
-        m.insert(838, "unknown");
-        m.insert(827, "unknown");
-        m.insert(818, "unknown");
-        m.insert(793, "unknown");
-        m.insert(785, "unknown");
-        m.insert(784, "unknown");
-        m.insert(755, "unknown");
-        m.insert(746, "unknown");
-        m.insert(738, "unknown");
-        m.insert(737, "unknown");
-        m.insert(717, "unknown");
-        m.insert(34, "unknown");
-        m.insert(336, "unknown");
-        m.insert(325, "unknown");
-        m.insert(303, "unknown");
-        m.insert(276, "unknown");
-        m.insert(273, "unknown");
-        m.insert(236, "unknown");
-        m.insert(235, "unknown");
-        m.insert(233, "unknown");
-        m.insert(181, "unify");
-        m.insert(604, "tunnel");
-        m.insert(1362, "timeflies");
-        m.insert(712, "tbrpf");
-        m.insert(1437, "tabula");
-        m.insert(2027, "shadowsertver");
-        m.insert(1368, "screencast");
-        m.insert(1531, "rap-listen");
-        m.insert(645, "pssc");
-        m.insert(65301, "pcanywhere");
-        m.insert(260, "openport");
-        m.insert(536, "opalis-rdv");
-        m.insert(764, "omsertv");
-        m.insert(698, "olsr");
-        m.insert(607, "nqs");
-        m.insert(1667, "netview-aix-7");
-        m.insert(1662, "netview-aix-2");
-        m.insert(1661, "netview-aix-1");
-        m.insert(404, "nced");
-        m.insert(224, "masqdialer");
-        m.insert(418, "hyper-g");
-        m.insert(176, "genrad-mux");
-        m.insert(848, "gdoi");
-        m.insert(315, "dpsi");
-        m.insert(466, "digital-vrc");
-        m.insert(403, "decap");
-        m.insert(1456, "dca");
-        m.insert(1479, "dberegister");
-        m.insert(355, "datex-asn");
-        m.insert(763, "cyclesertv");
-        m.insert(1472, "csdm");
-        m.insert(453, "creativesertver");
-        m.insert(759, "con");
-        m.insert(437, "comscm");
-        m.insert(2432, "codasrv");
-        m.insert(120, "cfdptkt");
-        m.insert(415, "bnet");
-        m.insert(1544, "aspeclmd");
-        m.insert(1511, "3l-l1");
-        m.insert(1538, "3ds-lm");
-        m.insert(346, "zsertv");
-        m.insert(173, "xyplex-mux");
-        m.insert(54, "xns-ch");
-        m.insert(56, "xns-auth");
-        m.insert(265, "maybe-fw1");
-        m.insert(1462, "world-lm");
-        m.insert(13701, "netbackup");
-        m.insert(1518, "vpvd");
-        m.insert(1457, "valisys-lm");
-        m.insert(117, "uucp-path");
-        m.insert(1470, "uaiact");
-        m.insert(13715, "netbackup");
-        m.insert(13714, "netbackup");
-        m.insert(267, "td-sertvice");
-        m.insert(1419, "timbuktu-srv3");
-        m.insert(1418, "timbuktu-srv2");
-        m.insert(1407, "dbsa-lm");
-        m.insert(380, "is99s");
-        m.insert(518, "ntalk");
-        m.insert(65, "tacacs-ds");
-        m.insert(391, "synotics-relay");
-        m.insert(392, "synotics-broker");
-        m.insert(413, "smsp");
-        m.insert(1391, "iclpv-sas");
-        m.insert(614, "sshell");
-        m.insert(1408, "sophia-lm");
-        m.insert(162, "snmptrap");
-        m.insert(108, "snagas");
-        m.insert(4987, "maybe-veritas");
-        m.insert(1502, "shivadiscovery");
-        m.insert(598, "sco-websrvrmg3");
-        m.insert(582, "scc-security");
-        m.insert(487, "saft");
-        m.insert(530, "courier");
-        m.insert(1509, "robcad-lm");
-        m.insert(72, "netrjs-2");
-        m.insert(4672, "rfa");
-        m.insert(189, "qft");
-        m.insert(209, "tam");
-        m.insert(270, "gist");
-        m.insert(7464, "pythonds");
-        m.insert(408, "prm-sm");
-        m.insert(191, "prospero");
-        m.insert(1459, "proshare1");
-        m.insert(5714, "prosharevideo");
-        m.insert(5717, "prosharenotify");
-        m.insert(5713, "proshareaudio");
-        m.insert(564, "9pfs");
-        m.insert(767, "phonebook");
-        m.insert(583, "philips-vc");
-        m.insert(1395, "iclpv-wsm");
-        m.insert(192, "osu-nms");
-        m.insert(1448, "oc-lm");
-        m.insert(428, "ocs_cmu");
-        m.insert(4133, "nuts_bootp");
-        m.insert(1416, "novell-lu6.2");
-        m.insert(773, "submit");
-        m.insert(1458, "nrcabq-lm");
-        m.insert(526, "tempo");
-        m.insert(1363, "ndm-requester");
-        m.insert(742, "netrcs");
-        m.insert(1464, "msl_lmd");
-        m.insert(1427, "mloadd");
-        m.insert(1482, "miteksys-lm");
-        m.insert(569, "ms-rome");
-        m.insert(571, "umeter");
-        m.insert(6141, "meta-corp");
-        m.insert(351, "matip-type-b");
-        m.insert(3984, "mapper-nodemgr");
-        m.insert(5490, "connect-proxy");
-        m.insert(2, "compressnet");
-        m.insert(13718, "netbackup");
-        m.insert(373, "legent-1");
-        m.insert(17300, "kuang2");
-        m.insert(910, "kink");
-        m.insert(148, "cronus");
-        m.insert(7326, "icb");
-        m.insert(271, "pt-tls");
-        m.insert(423, "opc-job-start");
-        m.insert(1451, "infoman");
-        m.insert(480, "loadsrv");
-        m.insert(1430, "tpdu");
-        m.insert(1429, "nms");
-        m.insert(781, "hp-collector");
-        m.insert(383, "hp-alarm-mgr");
-        m.insert(2564, "hp-3000-telnet");
-        m.insert(613, "hmmp-op");
-        m.insert(612, "hmmp-ind");
-        m.insert(652, "hello-port");
-        m.insert(5303, "hacl-probe");
-        m.insert(1383, "gwha");
-        m.insert(128, "gss-xlicen");
-        m.insert(19150, "gkrellm");
-        m.insert(1453, "genie-lm");
-        m.insert(190, "gacp");
-        m.insert(1505, "funkproxy");
-        m.insert(1371, "fc-cli");
-        m.insert(533, "netwall");
-        m.insert(27009, "flexlm9");
-        m.insert(27007, "flexlm7");
-        m.insert(27005, "flexlm5");
-        m.insert(27003, "flexlm3");
-        m.insert(27002, "flexlm2");
-        m.insert(744, "flexlm");
-        m.insert(1423, "essbase");
-        m.insert(1374, "molly");
-        m.insert(141, "emfis-cntl");
-        m.insert(1440, "eicon-slp");
-        m.insert(1396, "dvl-activemail");
-        m.insert(352, "dtag-ste-sb");
-        m.insert(96, "dixie");
-        m.insert(48, "auditd");
-        m.insert(552, "deviceshare");
-        m.insert(570, "meter");
-        m.insert(217, "dbase");
-        m.insert(528, "custix");
-        m.insert(452, "sfs-config");
-        m.insert(451, "sfs-smp-net");
-        m.insert(2766, "listen");
-        m.insert(2108, "rkinit");
-        m.insert(132, "cisco-sys");
-        m.insert(1993, "snmp-tcp-port");
-        m.insert(1987, "tr-rsrb-p1");
-        m.insert(130, "cisco-fna");
-        m.insert(18187, "opsec-ela");
-        m.insert(216, "atls");
-        m.insert(3421, "bmap");
-        m.insert(142, "bl-idm");
-        m.insert(13721, "netbackup");
-        m.insert(67, "dhcps");
-        m.insert(15151, "bo2k");
-        m.insert(364, "aurora-cmgr");
-        m.insert(1411, "af");
-        m.insert(205, "at-5");
-        m.insert(6548, "powerchuteplus");
-        m.insert(124, "ansatrader");
-        m.insert(116, "ansanotify");
-        m.insert(5193, "aol-3");
-        m.insert(258, "fw1-mc-gui");
-        m.insert(485, "powerburst");
-        m.insert(599, "acp");
-        m.insert(149, "aed-512");
-        m.insert(1469, "aal-lm");
-        m.insert(775, "entomb");
-        m.insert(2019, "whosockami");
-        m.insert(516, "videotex");
-        m.insert(986, "unknown");
-        m.insert(977, "unknown");
-        m.insert(976, "unknown");
-        m.insert(955, "unknown");
-        m.insert(954, "unknown");
-        m.insert(937, "unknown");
-        m.insert(932, "unknown");
-        m.insert(8, "unknown");
-        m.insert(896, "unknown");
-        m.insert(893, "unknown");
-        m.insert(845, "unknown");
-        m.insert(768, "unknown");
-        m.insert(766, "unknown");
-        m.insert(739, "unknown");
-        m.insert(337, "unknown");
-        m.insert(329, "unknown");
-        m.insert(326, "unknown");
-        m.insert(305, "unknown");
-        m.insert(295, "unknown");
-        m.insert(294, "unknown");
-        m.insert(293, "unknown");
-        m.insert(289, "unknown");
-        m.insert(288, "unknown");
-        m.insert(277, "unknown");
-        m.insert(238, "unknown");
-        m.insert(234, "unknown");
-        m.insert(229, "unknown");
-        m.insert(228, "unknown");
-        m.insert(226, "unknown");
-        m.insert(522, "ulp");
-        m.insert(2028, "submitsertver");
-        m.insert(150, "sql-net");
-        m.insert(572, "sonar");
-        m.insert(596, "smsd");
-        m.insert(420, "smpte");
-        m.insert(460, "skronk");
-        m.insert(1543, "simba-cs");
-        m.insert(358, "shrinkwrap");
-        m.insert(361, "semantix");
-        m.insert(470, "scx-proxy");
-        m.insert(360, "scoi2odialog");
-        m.insert(457, "scohelp");
-        m.insert(643, "sanity");
-        m.insert(322, "rtsps");
-        m.insert(168, "rsvd");
-        m.insert(753, "rrh");
-        m.insert(369, "rpc2portmap");
-        m.insert(185, "remote-kis");
-        m.insert(43188, "reachout");
-        m.insert(1541, "rds2");
-        m.insert(1540, "rds");
-        m.insert(752, "qrh");
-        m.insert(496, "pim-rp-disc");
-        m.insert(662, "pftp");
-        m.insert(1449, "peport");
-        m.insert(1480, "pacerforum");
-        m.insert(1473, "openmath");
-        m.insert(184, "ocsertver");
-        m.insert(1672, "netview-aix-12");
-        m.insert(1671, "netview-aix-11");
-        m.insert(1670, "netview-aix-10");
-        m.insert(435, "mobilip-mn");
-        m.insert(434, "mobileip-agent");
-        m.insert(1532, "miroconnect");
-        m.insert(1360, "mimer");
-        m.insert(174, "mailq");
-        m.insert(472, "ljk-login");
-        m.insert(1361, "linx");
-        m.insert(17007, "isode-dua");
-        m.insert(414, "infoseek");
-        m.insert(535, "iiop");
-        m.insert(432, "iasd");
-        m.insert(479, "iafsertver");
-        m.insert(473, "hybrid-pop");
-        m.insert(151, "hems");
-        m.insert(1542, "gridgen-elmd");
-        m.insert(438, "dsfgw");
-        m.insert(1488, "docstor");
-        m.insert(1508, "diagmond");
-        m.insert(618, "dei-icda");
-        m.insert(316, "decauth");
-        m.insert(1367, "dcs");
-        m.insert(439, "dasp");
-        m.insert(284, "corerjd");
-        m.insert(542, "commerce");
-        m.insert(370, "codaauth2");
-        m.insert(2016, "bootsertver");
-        m.insert(248, "bhfhs");
-        m.insert(1491, "anynetgateway");
-        m.insert(44123, "z-wave-tunnel");
-        m.insert(41230, "z-wave-s");
-        m.insert(7173, "zsecure");
-        m.insert(5670, "filemq");
-        m.insert(18136, "racf");
-        m.insert(3925, "zmp");
-        m.insert(7088, "zixi-transport");
-        m.insert(1425, "zion-lm");
-        m.insert(17755, "zigbee-ip");
-        m.insert(17756, "zigbee-ips");
-        m.insert(4072, "zieto-sock");
-        m.insert(5841, "shiprush-d-ch");
-        m.insert(2102, "zephyr-srv");
-        m.insert(4123, "z-wave");
-        m.insert(2989, "zarkov");
-        m.insert(10051, "zabbix-trapper");
-        m.insert(10050, "zabbix-agent");
-        m.insert(31029, "yawn");
-        m.insert(3726, "array-manager");
-        m.insert(5243, "xycstatus");
-        m.insert(9978, "xybrid-rt");
-        m.insert(9925, "xybrid-cloud");
-        m.insert(6061, "x11");
-        m.insert(6058, "x11");
-        m.insert(6057, "x11");
-        m.insert(6056, "x11");
-        m.insert(6054, "x11");
-        m.insert(6053, "x11");
-        m.insert(6049, "x11");
-        m.insert(6048, "x11");
-        m.insert(6047, "x11");
-        m.insert(6046, "x11");
-        m.insert(6045, "x11");
-        m.insert(6044, "x11");
-        m.insert(6043, "x11");
-        m.insert(6042, "x11");
-        m.insert(6041, "x11");
-        m.insert(6040, "x11");
-        m.insert(6039, "x11");
-        m.insert(6038, "x11");
-        m.insert(6037, "x11");
-        m.insert(6036, "x11");
-        m.insert(6035, "x11");
-        m.insert(6034, "x11");
-        m.insert(6033, "x11");
-        m.insert(6032, "x11");
-        m.insert(6031, "x11");
-        m.insert(6029, "x11");
-        m.insert(6028, "x11");
-        m.insert(6027, "x11");
-        m.insert(6026, "x11");
-        m.insert(6024, "x11");
-        m.insert(6023, "x11");
-        m.insert(6022, "x11");
-        m.insert(6020, "x11");
-        m.insert(6019, "x11");
-        m.insert(6018, "x11");
-        m.insert(6016, "x11");
-        m.insert(6014, "x11");
-        m.insert(6013, "x11");
-        m.insert(6012, "x11");
-        m.insert(6011, "x11");
-        m.insert(36462, "xw-control");
-        m.insert(5793, "xtreamx");
-        m.insert(3423, "xtrm");
-        m.insert(3424, "xtrms");
-        m.insert(4095, "xtgui");
-        m.insert(3646, "xss-srv-port");
-        m.insert(3510, "xss-port");
-        m.insert(3722, "xsertveraid");
-        m.insert(2459, "community");
-        m.insert(3651, "xrpc-registry");
-        m.insert(14500, "xpra");
-        m.insert(3865, "xpl");
-        m.insert(15345, "xpilot");
-        m.insert(3763, "xo-wave");
-        m.insert(38422, "xn-control");
-        m.insert(3877, "xmpcr-interface");
-        m.insert(9092, "XmlIpcRegSvc");
-        m.insert(5344, "xkotodrcp");
-        m.insert(2341, "xiostatus");
-        m.insert(6116, "xtlsertv");
-        m.insert(2157, "xnds");
-        m.insert(165, "xns-courier");
-        m.insert(6936, "xsmsvc");
-        m.insert(8041, "enguity-xccetp");
-        m.insert(4888, "xcap-portal");
-        m.insert(4889, "xcap-control");
-        m.insert(3074, "xbox");
-        m.insert(2165, "x-bone-api");
-        m.insert(4389, "xandros-cms");
-        m.insert(5770, "spramsd");
-        m.insert(5769, "spramsca");
-        m.insert(16619, "xoms");
-        m.insert(11876, "xoraya");
-        m.insert(11877, "x2e-disc");
-        m.insert(3741, "wysdma");
-        m.insert(3633, "wacp");
-        m.insert(3840, "flirtmitmir");
-        m.insert(3717, "wv-csp-udp-cir");
-        m.insert(3716, "wv-csp-sms-cir");
-        m.insert(3590, "wv-csp-sms");
-        m.insert(2805, "wta-wsp-s");
-        m.insert(4537, "wssauthsvc");
-        m.insert(9762, "tungsten-http");
-        m.insert(5007, "wsm-sertver-ssl");
-        m.insert(5006, "wsm-sertver");
-        m.insert(5358, "wsdapi-s");
-        m.insert(4879, "wsdl-event");
-        m.insert(6114, "wrspice");
-        m.insert(4185, "wcpp");
-        m.insert(2784, "www-dev");
-        m.insert(3724, "blizwow");
-        m.insert(2596, "worldfusion2");
-        m.insert(2595, "worldfusion1");
-        m.insert(4417, "workflowdir");
-        m.insert(4845, "wcr-remlib");
-        m.insert(22321, "wnn6_Tw");
-        m.insert(22289, "wnn6_Cn");
-        m.insert(3219, "wms-messenger");
-        m.insert(1338, "wmc-log-svc");
-        m.insert(36411, "wlcp");
-        m.insert(3861, "winshadow-hd");
-        m.insert(5166, "winpcs");
-        m.insert(3674, "wininstall-ipc");
-        m.insert(1785, "windlm");
-        m.insert(534, "mm-admin");
-        m.insert(6602, "wsscomfrmwk");
-        m.insert(47001, "winrm");
-        m.insert(5363, "net-projection");
-        m.insert(8912, "wcbackup");
-        m.insert(2231, "wimaxasncp");
-        m.insert(5747, "tunatic");
-        m.insert(5748, "tunalyzer");
-        m.insert(11208, "wifree");
-        m.insert(7236, "display");
-        m.insert(4049, "wafs");
-        m.insert(4050, "cisco-wafs");
-        m.insert(22347, "WibuKey");
-        m.insert(63, "via-ftp");
-        m.insert(3233, "whisker");
-        m.insert(3359, "wg-netforce");
-        m.insert(8908, "dpp");
-        m.insert(4177, "wello");
-        m.insert(48050, "weandsf");
-        m.insert(3111, "wsynch");
-        m.insert(3427, "websphere-snmp");
-        m.insert(5321, "bsfsvr-zn-ssl");
-        m.insert(5320, "bsfsertver-zn");
-        m.insert(3702, "ws-discovery");
-        m.insert(2907, "webmethods-b2b");
-        m.insert(8991, "https-wmap");
-        m.insert(8990, "http-wmap");
-        m.insert(2054, "weblogin");
-        m.insert(4847, "wfc");
-        m.insert(9802, "davsrcs");
-        m.insert(9800, "davsrc");
-        m.insert(4368, "wxbrief");
-        m.insert(5990, "wbem-exp-https");
-        m.insert(3563, "watcomdebug");
-        m.insert(5744, "watchdoc");
-        m.insert(5743, "watchdoc-pod");
-        m.insert(12321, "warehouse-sss");
-        m.insert(12322, "warehouse");
-        m.insert(9206, "wap-vcard-s");
-        m.insert(9204, "wap-vcard");
-        m.insert(9205, "wap-vcal");
-        m.insert(9201, "wap-wsp-wtp");
-        m.insert(9203, "wap-wsp-wtp-s");
-        m.insert(2949, "wap-pushsecure");
-        m.insert(2948, "wap-push");
-        m.insert(6626, "wago-sertvice");
-        m.insert(37472, "3gpp-w1ap");
-        m.insert(8199, "vvr-data");
-        m.insert(4145, "vvr-control");
-        m.insert(3482, "vulture");
-        m.insert(2216, "vtu-comms");
-        m.insert(13708, "netbackup");
-        m.insert(3786, "upstriggervsw");
-        m.insert(3375, "vsnm-agent");
-        m.insert(7566, "vsi-omega");
-        m.insert(2539, "vsiadmin");
-        m.insert(2387, "vsamredirector");
-        m.insert(3317, "vsaiport");
-        m.insert(2410, "vrts-registry");
-        m.insert(2255, "vrtp");
-        m.insert(3883, "vrpn");
-        m.insert(4299, "vrml-multi-use");
-        m.insert(4296, "vrml-multi-use");
-        m.insert(4295, "vrml-multi-use");
-        m.insert(4293, "vrml-multi-use");
-        m.insert(4292, "vrml-multi-use");
-        m.insert(4291, "vrml-multi-use");
-        m.insert(4290, "vrml-multi-use");
-        m.insert(4289, "vrml-multi-use");
-        m.insert(4288, "vrml-multi-use");
-        m.insert(4287, "vrml-multi-use");
-        m.insert(4286, "vrml-multi-use");
-        m.insert(4285, "vrml-multi-use");
-        m.insert(4284, "vrml-multi-use");
-        m.insert(4283, "vrml-multi-use");
-        m.insert(4282, "vrml-multi-use");
-        m.insert(4281, "vrml-multi-use");
-        m.insert(4280, "vrml-multi-use");
-        m.insert(4278, "vrml-multi-use");
-        m.insert(4277, "vrml-multi-use");
-        m.insert(4276, "vrml-multi-use");
-        m.insert(4275, "vrml-multi-use");
-        m.insert(4274, "vrml-multi-use");
-        m.insert(4273, "vrml-multi-use");
-        m.insert(4272, "vrml-multi-use");
-        m.insert(4271, "vrml-multi-use");
-        m.insert(4270, "vrml-multi-use");
-        m.insert(4269, "vrml-multi-use");
-        m.insert(4268, "vrml-multi-use");
-        m.insert(4267, "vrml-multi-use");
-        m.insert(4266, "vrml-multi-use");
-        m.insert(4265, "vrml-multi-use");
-        m.insert(4264, "vrml-multi-use");
-        m.insert(4263, "vrml-multi-use");
-        m.insert(4261, "vrml-multi-use");
-        m.insert(4260, "vrml-multi-use");
-        m.insert(4259, "vrml-multi-use");
-        m.insert(4258, "vrml-multi-use");
-        m.insert(4257, "vrml-multi-use");
-        m.insert(4256, "vrml-multi-use");
-        m.insert(4255, "vrml-multi-use");
-        m.insert(4254, "vrml-multi-use");
-        m.insert(4253, "vrml-multi-use");
-        m.insert(4251, "vrml-multi-use");
-        m.insert(4250, "vrml-multi-use");
-        m.insert(4249, "vrml-multi-use");
-        m.insert(4248, "vrml-multi-use");
-        m.insert(4247, "vrml-multi-use");
-        m.insert(4246, "vrml-multi-use");
-        m.insert(4245, "vrml-multi-use");
-        m.insert(4244, "vrml-multi-use");
-        m.insert(4241, "vrml-multi-use");
-        m.insert(4240, "vrml-multi-use");
-        m.insert(4239, "vrml-multi-use");
-        m.insert(4238, "vrml-multi-use");
-        m.insert(4237, "vrml-multi-use");
-        m.insert(4236, "vrml-multi-use");
-        m.insert(4235, "vrml-multi-use");
-        m.insert(4233, "vrml-multi-use");
-        m.insert(4232, "vrml-multi-use");
-        m.insert(4231, "vrml-multi-use");
-        m.insert(4230, "vrml-multi-use");
-        m.insert(4229, "vrml-multi-use");
-        m.insert(4228, "vrml-multi-use");
-        m.insert(4227, "vrml-multi-use");
-        m.insert(4226, "vrml-multi-use");
-        m.insert(4225, "vrml-multi-use");
-        m.insert(4223, "vrml-multi-use");
-        m.insert(4222, "vrml-multi-use");
-        m.insert(4221, "vrml-multi-use");
-        m.insert(4219, "vrml-multi-use");
-        m.insert(4218, "vrml-multi-use");
-        m.insert(4217, "vrml-multi-use");
-        m.insert(4216, "vrml-multi-use");
-        m.insert(4215, "vrml-multi-use");
-        m.insert(4214, "vrml-multi-use");
-        m.insert(4213, "vrml-multi-use");
-        m.insert(4212, "vrml-multi-use");
-        m.insert(4211, "vrml-multi-use");
-        m.insert(4210, "vrml-multi-use");
-        m.insert(4209, "vrml-multi-use");
-        m.insert(4208, "vrml-multi-use");
-        m.insert(4207, "vrml-multi-use");
-        m.insert(4205, "vrml-multi-use");
-        m.insert(4204, "vrml-multi-use");
-        m.insert(4203, "vrml-multi-use");
-        m.insert(4202, "vrml-multi-use");
-        m.insert(4201, "vrml-multi-use");
-        m.insert(2530, "vrcommerce");
-        m.insert(5164, "vpa");
-        m.insert(28200, "voxelstorm");
-        m.insert(3845, "v-one-spp");
-        m.insert(3541, "voispeed-port");
-        m.insert(4052, "interact");
-        m.insert(21590, "vofr-gateway");
-        m.insert(1796, "vocaltec-admin");
-        m.insert(25793, "vocaltec-hos");
-        m.insert(8699, "vnyx");
-        m.insert(8182, "vmware-fdm");
-        m.insert(4991, "vrt");
-        m.insert(2474, "vitalanalysis");
-        m.insert(5780, "vts-rpc");
-        m.insert(3676, "va-pacbase");
-        m.insert(24249, "vista-4gl");
-        m.insert(1631, "visitview");
-        m.insert(6672, "vision_sertver");
-        m.insert(6673, "vision_elmd");
-        m.insert(3601, "visinet-gui");
-        m.insert(5046, "vpm-udp");
-        m.insert(3509, "vt-ssl");
-        m.insert(1852, "virtual-time");
-        m.insert(2386, "virtualtape");
-        m.insert(8473, "vp2p");
-        m.insert(7802, "vns-tp");
-        m.insert(4789, "vxlan");
-        m.insert(3555, "razor");
-        m.insert(12013, "vipera-ssl");
-        m.insert(12012, "vipera");
-        m.insert(3752, "vipremoteagent");
-        m.insert(3245, "vieo-fe");
-        m.insert(3231, "vidigo");
-        m.insert(16666, "vtp");
-        m.insert(6678, "vfbp");
-        m.insert(17184, "vestasdlp");
-        m.insert(9086, "net2display");
-        m.insert(9598, "vscp");
-        m.insert(3073, "vcrp");
-        m.insert(2074, "vrtl-vmf-sa");
-        m.insert(1956, "vrtl-vmf-ds");
-        m.insert(2610, "versa-tek");
-        m.insert(3738, "versatalk");
-        m.insert(2994, "veritas-vis2");
-        m.insert(2993, "veritas-vis1");
-        m.insert(2802, "veritas-tcp1");
-        m.insert(1885, "vrtstrapsertver");
-        m.insert(14149, "vrts-tdd");
-        m.insert(13786, "nomdb");
-        m.insert(10100, "itap-ddtp");
-        m.insert(9284, "visd");
-        m.insert(14150, "vcscmd");
-        m.insert(10107, "bctp-sertver");
-        m.insert(4032, "vrts-auth-port");
-        m.insert(2821, "vrts-at-port");
-        m.insert(3207, "vx-auth-port");
-        m.insert(14154, "vad");
-        m.insert(24323, "vrmg-ip");
-        m.insert(2771, "vergencecm");
-        m.insert(5646, "vfmobile");
-        m.insert(2426, "vcmp");
-        m.insert(18668, "vdmmesh");
-        m.insert(2554, "vcnet-link-v10");
-        m.insert(4188, "vatata");
-        m.insert(3654, "vaprtm");
-        m.insert(8034, "vantronix-mgmt");
-        m.insert(5675, "v5ua");
-        m.insert(15118, "v2g-secc");
-        m.insert(4031, "suucp");
-        m.insert(2529, "utsftp");
-        m.insert(2248, "ums");
-        m.insert(1142, "edtools");
-        m.insert(19194, "ua-secureagent");
-        m.insert(433, "nnsp");
-        m.insert(3534, "urld-port");
-        m.insert(3664, "ups-engine");
-        m.insert(2537, "upgrade");
-        m.insert(519, "utime");
-        m.insert(2655, "unglue");
-        m.insert(4184, "universe_suite");
-        m.insert(1506, "utcd");
-        m.insert(3098, "umm-port");
-        m.insert(7887, "ubroker");
-        m.insert(37654, "unisys-eportal");
-        m.insert(1979, "unisql-java");
-        m.insert(9629, "uniport");
-        m.insert(2357, "unihub-sertver");
-        m.insert(1889, "unify-adapter");
-        m.insert(3314, "uohost");
-        m.insert(3313, "uorb");
-        m.insert(4867, "unify-debug");
-        m.insert(2696, "unifyadmin");
-        m.insert(3217, "unite");
-        m.insert(6306, "ufmp");
-        m.insert(1189, "unet");
-        m.insert(5281, "undo-lm");
-        m.insert(8953, "ub-dns-control");
-        m.insert(1910, "ultrabac");
-        m.insert(13894, "ucontrol");
-        m.insert(372, "ulistsertv");
-        m.insert(3720, "ufastro-instr");
-        m.insert(1382, "udt_os");
-        m.insert(2542, "udrawgraph");
-        m.insert(3584, "u-dbap");
-        m.insert(4034, "ubxd");
-        m.insert(145, "uaac");
-        m.insert(27999, "tw-auth-key");
-        m.insert(3791, "tvnetworkvideo");
-        m.insert(21800, "tvpm");
-        m.insert(2670, "tve-announce");
-        m.insert(3492, "tvdumtray-port");
-        m.insert(24678, "proactivate");
-        m.insert(34249, "turbonote-2");
-        m.insert(39681, "turbonote-1");
-        m.insert(1846, "tunstall-pnc");
-        m.insert(5197, "tunstall-lwp");
-        m.insert(5462, "ttl-publisher");
-        m.insert(5463, "ttlpriceproxy");
-        m.insert(2862, "ttg-protocol");
-        m.insert(2977, "ttc-etap-ns");
-        m.insert(2978, "ttc-etap-ds");
-        m.insert(3468, "ttcmremotectrl");
-        m.insert(2675, "ttc-etap");
-        m.insert(3474, "ttntspauto");
-        m.insert(4422, "tsepisp");
-        m.insert(12753, "tsaf");
-        m.insert(13709, "netbackup");
-        m.insert(2573, "trustestablish");
-        m.insert(3012, "twsdss");
-        m.insert(4307, "visicron-vs");
-        m.insert(4725, "truckstar");
-        m.insert(3346, "trnsprntproxy");
-        m.insert(3686, "tnmpv2");
-        m.insert(4070, "tripe");
-        m.insert(9555, "trispen-sra");
-        m.insert(4711, "trinity-dist");
-        m.insert(4323, "trim-ice");
-        m.insert(4322, "trim-event");
-        m.insert(10200, "trisoap");
-        m.insert(7727, "trident-data");
-        m.insert(3608, "trendchip-dcp");
-        m.insert(3959, "treehopper");
-        m.insert(2405, "trc-netpoll");
-        m.insert(3858, "trap-port-mom");
-        m.insert(3857, "trap-port");
-        m.insert(24322, "hid");
-        m.insert(6118, "tipc");
-        m.insert(4176, "tl-ipcproxy");
-        m.insert(6442, "tarp");
-        m.insert(8937, "twds");
-        m.insert(17224, "trdp-pd");
-        m.insert(17225, "trdp-md");
-        m.insert(7234, "asa-gateways");
-        m.insert(33434, "traceroute");
-        m.insert(1906, "tpmd");
-        m.insert(22351, "codemeter-cmwan");
-        m.insert(2158, "touchnetplus");
-        m.insert(5153, "toruxsertver");
-        m.insert(3885, "topflow-ssl");
-        m.insert(24465, "tonidods");
-        m.insert(3040, "tomato-springs");
-        m.insert(20167, "tolfab");
-        m.insert(8066, "toad-bi-appsrvr");
-        m.insert(474, "tn-tl-w1");
-        m.insert(2739, "tn-timing");
-        m.insert(3308, "tns-sertver");
-        m.insert(590, "tns-cml");
-        m.insert(3309, "tns-adv");
-        m.insert(7902, "tnos-dp");
-        m.insert(7901, "tnos-sp");
-        m.insert(7903, "tnos-dps");
-        m.insert(20046, "tmophl7mts");
-        m.insert(5582, "fac-restore");
-        m.insert(5583, "tmo-icon-sync");
-        m.insert(7872, "mipv6tls");
-        m.insert(13716, "netbackup");
-        m.insert(13717, "netbackup");
-        m.insert(13705, "netbackup");
-        m.insert(6252, "tl1-ssh");
-        m.insert(2915, "tksocket");
-        m.insert(1965, "tivoli-npm");
-        m.insert(3459, "integral");
-        m.insert(3160, "tip-app-sertver");
-        m.insert(3754, "timestenbroker");
-        m.insert(3243, "timelot");
-        m.insert(10261, "tile-ml");
-        m.insert(7932, "t2-drm");
-        m.insert(7933, "t2-brm");
-        m.insert(5450, "tiepie");
-        m.insert(11971, "tibsd");
-        m.insert(379, "is99c");
-        m.insert(7548, "tidp");
-        m.insert(1832, "tht-treasure");
-        m.insert(28080, "thor-engine");
-        m.insert(3805, "tcpdatasertver");
-        m.insert(16789, "cadsisvr");
-        m.insert(8320, "tnp-discover");
-        m.insert(8321, "tnp");
-        m.insert(4423, "thingkit");
-        m.insert(2296, "theta-lm");
-        m.insert(7359, "swx");
-        m.insert(7358, "swx");
-        m.insert(7357, "swx");
-        m.insert(7356, "swx");
-        m.insert(7355, "swx");
-        m.insert(7354, "swx");
-        m.insert(7353, "swx");
-        m.insert(7352, "swx");
-        m.insert(7351, "swx");
-        m.insert(7350, "swx");
-        m.insert(7349, "swx");
-        m.insert(7348, "swx");
-        m.insert(7347, "swx");
-        m.insert(7346, "swx");
-        m.insert(7344, "swx");
-        m.insert(7343, "swx");
-        m.insert(7342, "swx");
-        m.insert(7341, "swx");
-        m.insert(7340, "swx");
-        m.insert(7339, "swx");
-        m.insert(7338, "swx");
-        m.insert(7337, "swx");
-        m.insert(7336, "swx");
-        m.insert(7335, "swx");
-        m.insert(7334, "swx");
-        m.insert(7333, "swx");
-        m.insert(7332, "swx");
-        m.insert(7331, "swx");
-        m.insert(7330, "swx");
-        m.insert(7329, "swx");
-        m.insert(7328, "swx");
-        m.insert(7327, "swx");
-        m.insert(7324, "swx");
-        m.insert(7323, "swx");
-        m.insert(7322, "swx");
-        m.insert(7321, "swx");
-        m.insert(7319, "swx");
-        m.insert(7318, "swx");
-        m.insert(7317, "swx");
-        m.insert(7316, "swx");
-        m.insert(7315, "swx");
-        m.insert(7314, "swx");
-        m.insert(7313, "swx");
-        m.insert(7312, "swx");
-        m.insert(7311, "swx");
-        m.insert(7310, "swx");
-        m.insert(7309, "swx");
-        m.insert(7308, "swx");
-        m.insert(7307, "swx");
-        m.insert(7306, "swx");
-        m.insert(7305, "swx");
-        m.insert(7304, "swx");
-        m.insert(7303, "swx");
-        m.insert(7302, "swx");
-        m.insert(7301, "swx");
-        m.insert(8140, "puppet");
-        m.insert(5196, "ampl-tableproxy");
-        m.insert(5195, "ampl-lic");
-        m.insert(6130, "damewaremobgtwy");
-        m.insert(5474, "apsolab-rpc");
-        m.insert(5471, "apsolab-cols");
-        m.insert(5472, "apsolab-tag");
-        m.insert(5470, "apsolab-col");
-        m.insert(4146, "tgcconnect");
-        m.insert(3713, "tftps");
-        m.insert(5048, "texai");
-        m.insert(31457, "tetrinet");
-        m.insert(7631, "tesla-sys-msg");
-        m.insert(3544, "teredo");
-        m.insert(41121, "tentacle");
-        m.insert(11600, "tempest-port");
-        m.insert(3696, "telnetcpcd");
-        m.insert(3549, "tellumat-nms");
-        m.insert(1380, "telesis-licman");
-        m.insert(22951, "brf-gw");
-        m.insert(22800, "aws-brf");
-        m.insert(3521, "mc3ss");
-        m.insert(2060, "teleniumdaemon");
-        m.insert(6083, "miami-bcast");
-        m.insert(9668, "tec5-sdctp");
-        m.insert(3552, "tasertver");
-        m.insert(1814, "tdp-suite");
-        m.insert(1977, "tcoaddressbook");
-        m.insert(2576, "tclprodebugger");
-        m.insert(2729, "tcim-control");
-        m.insert(24680, "tcc-http");
-        m.insert(13710, "netbackup");
-        m.insert(13712, "netbackup");
-        m.insert(25900, "tasp-net");
-        m.insert(2403, "taskmaster2000");
-        m.insert(2402, "taskmaster2000");
-        m.insert(2470, "taskman-port");
-        m.insert(5203, "targus-getdata3");
-        m.insert(3579, "ttat3lb");
-        m.insert(2306, "tappi-boxnet");
-        m.insert(1450, "dwf");
-        m.insert(7015, "talon-websertver");
-        m.insert(7012, "talon-engine");
-        m.insert(7011, "talon-disc");
-        m.insert(22763, "talikasertver");
-        m.insert(2156, "trp");
-        m.insert(2493, "talarian-mqs");
-        m.insert(4019, "talarian-mcast5");
-        m.insert(4018, "talarian-mcast4");
-        m.insert(4017, "talarian-mcast3");
-        m.insert(4015, "talarian-mcast1");
-        m.insert(2392, "tacticalauth");
-        m.insert(3175, "t1-e1-over-ip");
-        m.insert(32249, "t1distproc60");
-        m.insert(1627, "t128-gateway");
-        m.insert(10104, "swdtp");
-        m.insert(2609, "system-monitor");
-        m.insert(5406, "systemics-sox");
-        m.insert(3251, "sysscanner");
-        m.insert(4094, "sysrqd");
-        m.insert(3241, "sysorb");
-        m.insert(6514, "syslog-tls");
-        m.insert(6418, "sysertverremote");
-        m.insert(3734, "synel-data");
-        m.insert(2679, "syncsertverssl");
-        m.insert(4953, "dbsyncarbiter");
-        m.insert(5008, "synapsis-edge");
-        m.insert(2880, "synapse");
-        m.insert(8243, "synapse-nhttps");
-        m.insert(8280, "synapse-nhttp");
-        m.insert(26133, "scscp");
-        m.insert(8555, "d-fence");
-        m.insert(5629, "symantec-sfdb");
-        m.insert(3547, "symantec-sim");
-        m.insert(5639, "ics");
-        m.insert(5638, "flcrs");
-        m.insert(5637, "cssc");
-        m.insert(5115, "autobuild");
-        m.insert(3723, "sychrond");
-        m.insert(4950, "sybasesrvmon");
-        m.insert(3895, "syam-smc");
-        m.insert(3894, "syam-agent");
-        m.insert(3491, "swr-port");
-        m.insert(3318, "ssrip");
-        m.insert(6419, "svdrp");
-        m.insert(3185, "smpppd");
-        m.insert(243, "sur-meas");
-        m.insert(3212, "surveyinst");
-        m.insert(9536, "laes-bf");
-        m.insert(1925, "discovery-port");
-        m.insert(11171, "snss");
-        m.insert(8404, "svcloud");
-        m.insert(8405, "svbackup");
-        m.insert(8989, "sunwebadmins");
-        m.insert(6787, "smc-admin");
-        m.insert(6483, "SunVTS-RMI");
-        m.insert(3867, "dzoglsertver");
-        m.insert(3866, "dzdaemon");
-        m.insert(1860, "sunscalar-svc");
-        m.insert(1870, "sunscalar-dns");
-        m.insert(5306, "sun-mc-grp");
-        m.insert(3816, "sunlps-http");
-        m.insert(7588, "sun-lm");
-        m.insert(6786, "smc-jmx");
-        m.insert(2084, "sunclustergeo");
-        m.insert(11165, "suncacao-websvc");
-        m.insert(11161, "suncacao-snmp");
-        m.insert(11163, "suncacao-rmi");
-        m.insert(11162, "suncacao-jmxmp");
-        m.insert(11164, "suncacao-csa");
-        m.insert(3708, "sun-as-iiops");
-        m.insert(4850, "sun-as-nodeagt");
-        m.insert(7677, "sun-usert-https");
-        m.insert(16959, "subseven");
-        m.insert(247, "subntbcst_tftp");
-        m.insert(3478, "stun");
-        m.insert(5349, "stuns");
-        m.insert(3854, "stryker-com");
-        m.insert(5397, "stresstester");
-        m.insert(7411, "daqstream");
-        m.insert(9612, "streamcomm-ds");
-        m.insert(11173, "t5-straton");
-        m.insert(9293, "storview");
-        m.insert(5027, "strexec-s");
-        m.insert(5026, "strexec-d");
-        m.insert(5705, "storageos");
-        m.insert(8778, "uec");
-        m.insert(527, "stx");
-        m.insert(1312, "sti-envision");
-        m.insert(8808, "ssports-bcast");
-        m.insert(6144, "statsci1-lm");
-        m.insert(4157, "stat-scanner");
-        m.insert(4156, "stat-results");
-        m.insert(3249, "ssp");
-        m.insert(7471, "sttunnel");
-        m.insert(3615, "start-network");
-        m.insert(5777, "dali-port");
-        m.insert(2154, "stdptc");
-        m.insert(45966, "ssr-sertvermgr");
-        m.insert(17235, "ssh-mgmt");
-        m.insert(3018, "srvc_registry");
-        m.insert(38800, "sruth");
-        m.insert(2737, "srp-feedback");
-        m.insert(156, "sqlsrv");
-        m.insert(3807, "spugna");
-        m.insert(2876, "sps-tunnel");
-        m.insert(1759, "spss-lm");
-        m.insert(7981, "sossd-collect");
-        m.insert(3606, "splitlock");
-        m.insert(3647, "splitlock-gw");
-        m.insert(3438, "spiral-admin");
-        m.insert(4683, "spike");
-        m.insert(9306, "sphinxql");
-        m.insert(9312, "sphinxapi");
-        m.insert(7016, "spg");
-        m.insert(33334, "speedtrace");
-        m.insert(3413, "svnet");
-        m.insert(3834, "spectardata");
-        m.insert(3835, "spectardb");
-        m.insert(2440, "spearway");
-        m.insert(6121, "spdy");
-        m.insert(2568, "spamtrap");
-        m.insert(17185, "soundsvirtual");
-        m.insert(7982, "sossd-agent");
-        m.insert(2290, "sonus-logging");
-        m.insert(2569, "sonuscallsig");
-        m.insert(2863, "sonardata");
-        m.insert(1964, "solid-e-engine");
-        m.insert(4738, "solera-lpn");
-        m.insert(2132, "solera-epmap");
-        m.insert(17777, "sw-orion");
-        m.insert(16162, "solaris-audit");
-        m.insert(6551, "sum");
-        m.insert(3230, "sftdst-port");
-        m.insert(4538, "swx-gate");
-        m.insert(3884, "softrack-meter");
-        m.insert(9282, "swtp-port2");
-        m.insert(9281, "swtp-port1");
-        m.insert(4882, "socp-c");
-        m.insert(5146, "social-alarm");
-        m.insert(580, "sntp-heartbeat");
-        m.insert(1967, "sns-quote");
-        m.insert(2659, "sns-query");
-        m.insert(2409, "sns-protocol");
-        m.insert(5416, "sns-gateway");
-        m.insert(2657, "sns-dispatcher");
-        m.insert(3380, "sns-channels");
-        m.insert(5417, "sns-agent");
-        m.insert(2658, "sns-admin");
-        m.insert(5161, "snmpssh");
-        m.insert(5162, "snmpssh-trap");
-        m.insert(10162, "snmptls-trap");
-        m.insert(10161, "snmptls");
-        m.insert(33656, "snip");
-        m.insert(7560, "sncp");
-        m.insert(2599, "snapd");
-        m.insert(2704, "sms-remctrl");
-        m.insert(2703, "sms-chat");
-        m.insert(4170, "d-cinema-csp");
-        m.insert(7734, "smip");
-        m.insert(9522, "sma-spw");
-        m.insert(3158, "stvp");
-        m.insert(4426, "beacon-port-2");
-        m.insert(4786, "smart-install");
-        m.insert(2721, "smart-diagnose");
-        m.insert(1608, "smart-lm");
-        m.insert(3516, "smartcard-port");
-        m.insert(4988, "smar-se-port2");
-        m.insert(4408, "slscc");
-        m.insert(1847, "slp-notify");
-        m.insert(36423, "slmap");
-        m.insert(2826, "slc-systemlog");
-        m.insert(2827, "slc-ctrlrloops");
-        m.insert(3556, "sky-transport");
-        m.insert(8111, "skynetflow");
-        m.insert(6456, "skip-cert-send");
-        m.insert(6455, "skip-cert-recv");
-        m.insert(3874, "sixxsconfig");
-        m.insert(3611, "six-degrees");
-        m.insert(2629, "sitarasertver");
-        m.insert(2630, "sitaramgmt");
-        m.insert(166, "s-net");
-        m.insert(5059, "sds");
-        m.insert(3110, "sim-control");
-        m.insert(1733, "siipat");
-        m.insert(40404, "sptx");
-        m.insert(2257, "simple-tx-rx");
-        m.insert(2278, "s3db");
-        m.insert(4750, "ssad");
-        m.insert(4303, "srcp");
-        m.insert(3688, "simple-push-s");
-        m.insert(4751, "spocp");
-        m.insert(5794, "spdp");
-        m.insert(4752, "snap");
-        m.insert(7626, "simco");
-        m.insert(16950, "sgcip");
-        m.insert(3273, "sxmp");
-        m.insert(3896, "sdo-tls");
-        m.insert(3635, "sdo");
-        m.insert(1959, "simp-all");
-        m.insert(4753, "simon");
-        m.insert(2857, "simctlp");
-        m.insert(4163, "silverpeakpeer");
-        m.insert(1659, "sg-lm");
-        m.insert(2905, "m3ua");
-        m.insert(2904, "m2ua");
-        m.insert(2733, "signet-ctf");
-        m.insert(4936, "an-signaling");
-        m.insert(5032, "signacert-agent");
-        m.insert(3048, "pctrader");
-        m.insert(29000, "saltd-licensing");
-        m.insert(28240, "siemensgsm");
-        m.insert(2320, "siebel-ns");
-        m.insert(4742, "sicct");
-        m.insert(22335, "shrewd-control");
-        m.insert(22333, "showcockpit-net");
-        m.insert(5043, "swxadmin");
-        m.insert(4105, "shofarplayer");
-        m.insert(1257, "shockwave2");
-        m.insert(3841, "zfirm-shiprush3");
-        m.insert(43210, "shaperai");
-        m.insert(4366, "shadowstream");
-        m.insert(5163, "sbackup");
-        m.insert(11106, "sgi-lk");
-        m.insert(5434, "sgi-arrayd");
-        m.insert(6444, "sge_qmaster");
-        m.insert(6445, "sge_execd");
-        m.insert(5634, "xprtld");
-        m.insert(5636, "sfm-db-sertver");
-        m.insert(5635, "sfmsso");
-        m.insert(6343, "sflow");
-        m.insert(4546, "sf-lm");
-        m.insert(3242, "sdp-id-port");
-        m.insert(5568, "sdt");
-        m.insert(4057, "wfm");
-        m.insert(24666, "sdtvwcam");
-        m.insert(21221, "aigairsertver");
-        m.insert(6488, "sun-sr-jmx");
-        m.insert(6484, "sun-sr-jms");
-        m.insert(6486, "sun-sr-iiops");
-        m.insert(6485, "sun-sr-iiop");
-        m.insert(6487, "sun-sr-iiop-aut");
-        m.insert(6443, "sun-sr-https");
-        m.insert(6480, "sun-sr-http");
-        m.insert(6489, "sun-sr-admin");
-        m.insert(7690, "sovd");
-        m.insert(2603, "ripngd");
-        m.insert(4787, "sia-ctrl-plane");
-        m.insert(2367, "sertvice-ctrl");
-        m.insert(9212, "sertverviewdbms");
-        m.insert(9213, "sertverstart");
-        m.insert(5445, "smbdirect");
-        m.insert(45824, "dai-shell");
-        m.insert(8351, "sertver-find");
-        m.insert(13711, "netbackup");
-        m.insert(4076, "sertaph");
-        m.insert(5099, "sentlm-srv2srv");
-        m.insert(2316, "sent-lm");
-        m.insert(3588, "sentinel");
-        m.insert(5093, "sentinel-lm");
-        m.insert(9450, "sntlkeyssrvr");
-        m.insert(8056, "senomix05");
-        m.insert(8055, "senomix04");
-        m.insert(8054, "senomix03");
-        m.insert(8059, "senomix08");
-        m.insert(8058, "senomix07");
-        m.insert(8057, "senomix06");
-        m.insert(8053, "senomix02");
-        m.insert(3090, "stss");
-        m.insert(3255, "semaphore");
-        m.insert(2254, "seispoc");
-        m.insert(2479, "ssm-els");
-        m.insert(2477, "ssm-cvs");
-        m.insert(2478, "ssm-cssps");
-        m.insert(4194, "spdm");
-        m.insert(3496, "seclayer-tls");
-        m.insert(3495, "seclayer-tcp");
-        m.insert(2089, "sep");
-        m.insert(38865, "secrmmsafecopya");
-        m.insert(9026, "swa-4");
-        m.insert(9025, "swa-3");
-        m.insert(9024, "swa-2");
-        m.insert(9023, "swa-1");
-        m.insert(3480, "plethora");
-        m.insert(1905, "sugp");
-        m.insert(3550, "ssmpp");
-        m.insert(7801, "ssp-client");
-        m.insert(2189, "radware-rpm-s");
-        m.insert(5361, "ms-s-sideshow");
-        m.insert(32635, "sec-ntb-clnt");
-        m.insert(3782, "iso-tp0s");
-        m.insert(3432, "osdcp");
-        m.insert(3978, "secure-cfg-svr");
-        m.insert(6629, "nexgen-aux");
-        m.insert(3143, "seaview");
-        m.insert(7784, "s-bfd");
-        m.insert(2342, "manage-exec");
-        m.insert(2309, "sdsertver");
-        m.insert(2705, "sds-admin");
-        m.insert(2310, "sdclient");
-        m.insert(2384, "sd-request");
-        m.insert(6315, "scup");
-        m.insert(5343, "kfsertver");
-        m.insert(9899, "sctp-tunneling");
-        m.insert(5168, "scte30");
-        m.insert(5167, "scte104");
-        m.insert(3927, "natdatasertvice");
-        m.insert(266, "sst");
-        m.insert(2577, "scipticslsrvr");
-        m.insert(5307, "sco-aip");
-        m.insert(3838, "sos");
-        m.insert(19007, "scintilla");
-        m.insert(7708, "scinet");
-        m.insert(37475, "neckar");
-        m.insert(7701, "nfapi");
-        m.insert(5435, "sceanics");
-        m.insert(3499, "sccip-media");
-        m.insert(2719, "scan-change");
-        m.insert(3352, "ssql");
-        m.insert(25576, "sauterdongle");
-        m.insert(3942, "srdp");
-        m.insert(1644, "saiseh");
-        m.insert(3755, "sas-remote-hlp");
-        m.insert(5574, "lsi-bobcat");
-        m.insert(5573, "sdmmp");
-        m.insert(7542, "saratoga");
-        m.insert(9310, "sapms");
-        m.insert(1129, "saphostctrls");
-        m.insert(4079, "santools");
-        m.insert(3038, "santak-ups");
-        m.insert(8768, "sandpolis");
-        m.insert(4033, "sanavigator");
-        m.insert(9401, "sec-t4net-clt");
-        m.insert(9402, "sec-pc2fax-srv");
-        m.insert(20012, "ss-idi-disc");
-        m.insert(20013, "ss-idi");
-        m.insert(30832, "samsung-disc");
-        m.insert(1606, "slm-api");
-        m.insert(5410, "salient-usrmgr");
-        m.insert(5422, "salient-mux");
-        m.insert(5409, "salient-dtasrv");
-        m.insert(9801, "sstp-2");
-        m.insert(7743, "sstp-1");
-        m.insert(14034, "sage-best-com2");
-        m.insert(14033, "sage-best-com1");
-        m.insert(4952, "sagxtsds");
-        m.insert(21801, "sal");
-        m.insert(3452, "sabp-signal");
-        m.insert(2760, "sabams");
-        m.insert(3153, "s8-client-port");
-        m.insert(23272, "s102");
-        m.insert(2578, "rvs-isdn-dcp");
-        m.insert(5156, "rugameonline");
-        m.insert(8554, "rtsp-alt");
-        m.insert(7401, "rtps-dd-ut");
-        m.insert(3771, "paging-port");
-        m.insert(3138, "rtnt-2");
-        m.insert(3137, "rtnt-1");
-        m.insert(3500, "rtmp-port");
-        m.insert(6900, "rtimeviewer");
-        m.insert(363, "rsvp_tunnel");
-        m.insert(3455, "prsvp");
-        m.insert(1698, "rsvp-encap-1");
-        m.insert(13217, "rs-pias");
-        m.insert(2752, "rsisysaccess");
-        m.insert(3864, "asap-tcp-tls");
-        m.insert(10201, "rsms");
-        m.insert(6568, "canit_store");
-        m.insert(2377, "swarm");
-        m.insert(3677, "roverlog");
-        m.insert(520, "efs");
-        m.insert(2258, "rcts");
-        m.insert(4124, "tigv2");
-        m.insert(8051, "rocrail");
-        m.insert(2223, "rockwell-csp2");
-        m.insert(3194, "magbind");
-        m.insert(4041, "houston");
-        m.insert(48653, "robotraconteur");
-        m.insert(8270, "robot-remote");
-        m.insert(5693, "rbsystem");
-        m.insert(25471, "rna");
-        m.insert(2416, "rmtsertver");
-        m.insert(5994, "rms-agent");
-        m.insert(9208, "rjcdb-vcards");
-        m.insert(7810, "rbt-wanopt");
-        m.insert(7870, "rbt-smc");
-        m.insert(2249, "rfmp");
-        m.insert(7473, "rise");
-        m.insert(4664, "ems");
-        m.insert(4590, "rid");
-        m.insert(2777, "ridgeway2");
-        m.insert(2776, "ridgeway1");
-        m.insert(2057, "rich-cp");
-        m.insert(6148, "ricardo-lm");
-        m.insert(3296, "rib-slm");
-        m.insert(4410, "itwo-sertver");
-        m.insert(4684, "rfid-rp1");
-        m.insert(8230, "rexecj");
-        m.insert(5842, "reversion");
-        m.insert(1431, "rgtp");
-        m.insert(12109, "rets-ssl");
-        m.insert(4756, "RDCenter");
-        m.insert(4336, "restconf-ch-tls");
-        m.insert(324, "rpki-rtr-tls");
-        m.insert(323, "rpki-rtr");
-        m.insert(3019, "resource_mgr");
-        m.insert(39, "rlp");
-        m.insert(2225, "rcip-itu");
-        m.insert(4733, "resorcs");
-        m.insert(30100, "rwp");
-        m.insert(2999, "remoteware-un");
-        m.insert(3422, "rusb-sys-port");
-        m.insert(107, "rtelnet");
-        m.insert(1232, "first-defense");
-        m.insert(3418, "rnmap");
-        m.insert(3537, "ni-visa-remote");
-        m.insert(5, "rje");
-        m.insert(8184, "itach");
-        m.insert(3789, "remotedeploy");
-        m.insert(5231, "crusecontrol");
-        m.insert(4731, "remcap");
-        m.insert(4373, "remctl");
-        m.insert(45045, "synctest");
-        m.insert(3974, "citysearch");
-        m.insert(12302, "rads");
-        m.insert(2373, "remographlm");
-        m.insert(6084, "p2p-sip");
-        m.insert(16665, "axon-tunnel");
-        m.insert(16385, "rdgs");
-        m.insert(18635, "rds-ip");
-        m.insert(18634, "rds-ib");
-        m.insert(10253, "eapol-relay");
-        m.insert(7227, "ramp");
-        m.insert(3572, "megaregsvrport");
-        m.insert(3032, "redwood-chat");
-        m.insert(5786, "cisco-redu");
-        m.insert(2346, "redstorm_join");
-        m.insert(2348, "redstorm_info");
-        m.insert(2347, "redstorm_find");
-        m.insert(2349, "redstorm_diag");
-        m.insert(45002, "rs-status");
-        m.insert(3553, "rbr-discovery");
-        m.insert(43191, "noit-transport");
-        m.insert(5313, "rrdp");
-        m.insert(3707, "rt-event-s");
-        m.insert(3706, "rt-event");
-        m.insert(3736, "rs-rmi");
-        m.insert(32811, "retp");
-        m.insert(1942, "res");
-        m.insert(44553, "rbr-debug");
-        m.insert(35001, "rt-viewer");
-        m.insert(35002, "rt-sound");
-        m.insert(35005, "rt-labtracker");
-        m.insert(35006, "rt-helper");
-        m.insert(35003, "rt-devicemapper");
-        m.insert(35004, "rt-classmanager");
-        m.insert(532, "netnews");
-        m.insert(2214, "rpi");
-        m.insert(5569, "rdmnet-ctrl");
-        m.insert(3142, "apt-cacher");
-        m.insert(2332, "rcc-host");
-        m.insert(3768, "rblcheckd");
-        m.insert(2774, "rbakcup2");
-        m.insert(2773, "rbakcup1");
-        m.insert(6099, "raxa-mgmt");
-        m.insert(2167, "raw-sertial");
-        m.insert(2714, "raventdm");
-        m.insert(2713, "raventbs");
-        m.insert(3533, "raven-rdp");
-        m.insert(4037, "ravehd");
-        m.insert(2457, "rapido-ip");
-        m.insert(1953, "rapidbase");
-        m.insert(9345, "rancher");
-        m.insert(21553, "rdm-tfs");
-        m.insert(2408, "optimanet");
-        m.insert(2736, "radwiz-nms-srv");
-        m.insert(2188, "radware-rpm");
-        m.insert(18104, "radpdf");
-        m.insert(1813, "radius-acct");
-        m.insert(469, "rcp");
-        m.insert(1596, "radio-sm");
-        m.insert(3178, "radclientport");
-        m.insert(5430, "radec-corp");
-        m.insert(5676, "raadmin");
-        m.insert(2177, "qwave");
-        m.insert(4841, "quosa");
-        m.insert(5028, "qvr");
-        m.insert(7980, "quest-vista");
-        m.insert(3166, "sossecollector");
-        m.insert(3554, "questnotify");
-        m.insert(3566, "quest-data-hub");
-        m.insert(3843, "quest-agent");
-        m.insert(5677, "questdb2-lnchr");
-        m.insert(7040, "quest-disc");
-        m.insert(2589, "quartus-tcl");
-        m.insert(8153, "quantastor");
-        m.insert(10055, "qptlmd");
-        m.insert(5464, "quailnet");
-        m.insert(2497, "quaddb");
-        m.insert(4354, "qsnet-trans");
-        m.insert(9222, "teamcoherence");
-        m.insert(5083, "qfp");
-        m.insert(5082, "qcp");
-        m.insert(45825, "qdb2sertvice");
-        m.insert(2612, "qpasa-agent");
-        m.insert(6980, "qolyester");
-        m.insert(5689, "qmvideo");
-        m.insert(6209, "qmtps");
-        m.insert(2523, "qke-llc-v3");
-        m.insert(2490, "qip-qdhcp");
-        m.insert(2468, "qip-msgd");
-        m.insert(3543, "qftest-lookup");
-        m.insert(7794, "q3ade");
-        m.insert(4193, "pvxplusio");
-        m.insert(4951, "pwgwims");
-        m.insert(3951, "pwgippfax");
-        m.insert(4093, "pvxpluscs");
-        m.insert(7747, "prgp");
-        m.insert(7997, "pushns");
-        m.insert(8117, "purityrpc");
-        m.insert(6140, "pulsonixnls");
-        m.insert(2873, "psrt");
-        m.insert(4329, "publiqare-sync");
-        m.insert(320, "ptp-general");
-        m.insert(319, "ptp-event");
-        m.insert(597, "ptcnamesertvice");
-        m.insert(3453, "pscupd");
-        m.insert(4457, "prRegister");
-        m.insert(2303, "proxy-gateway");
-        m.insert(5360, "ms-sideshow");
-        m.insert(4487, "prex-tcp");
-        m.insert(409, "prm-nm");
-        m.insert(344, "pdap");
-        m.insert(1460, "proshare2");
-        m.insert(5716, "prosharerequest");
-        m.insert(5715, "prosharedata");
-        m.insert(9640, "pqsflows");
-        m.insert(5798, "enlabel-dpl");
-        m.insert(7663, "rome");
-        m.insert(7798, "pnet-enc");
-        m.insert(7797, "pnet-conn");
-        m.insert(4352, "pjlink");
-        m.insert(15999, "programmar");
-        m.insert(34962, "profinet-rt");
-        m.insert(34963, "profinet-rtm");
-        m.insert(34964, "profinet-cm");
-        m.insert(4749, "profilemac");
-        m.insert(8032, "pro-ed");
-        m.insert(4182, "pcptcpsertvice");
-        m.insert(1283, "productinfo");
-        m.insert(1778, "prodigy-intrnet");
-        m.insert(3248, "procos-lm");
-        m.insert(2722, "proactivesrvr");
-        m.insert(2039, "prizma");
-        m.insert(3650, "prismiq-plugin");
-        m.insert(3133, "prism-deploy");
-        m.insert(2618, "priority-e-com");
-        m.insert(4168, "pslicsert");
-        m.insert(10631, "printopia");
-        m.insert(1392, "iclpv-pm");
-        m.insert(3910, "prnrequest");
-        m.insert(6716, "princity-agent");
-        m.insert(47809, "presonus-ucnet");
-        m.insert(38638, "psqlmws");
-        m.insert(4690, "prelude");
-        m.insert(9280, "pgps");
-        m.insert(6163, "pscribe");
-        m.insert(2315, "precise-sft");
-        m.insert(3607, "precise-i3");
-        m.insert(5630, "precise-comm");
-        m.insert(4455, "prchat-usert");
-        m.insert(4456, "prchat-sertver");
-        m.insert(1587, "pra_elmd");
-        m.insert(28001, "pqsp");
-        m.insert(5134, "ppactivation");
-        m.insert(13224, "powwow-sertver");
-        m.insert(13223, "powwow-client");
-        m.insert(5507, "psl-management");
-        m.insert(2443, "powerclientcsf");
-        m.insert(4150, "PowerAlert-nsa");
-        m.insert(8432, "pgbackrest");
-        m.insert(7172, "metalbend");
-        m.insert(3710, "portgate-auth");
-        m.insert(9889, "gt-proxy");
-        m.insert(6464, "ieee11073-20701");
-        m.insert(7787, "popup-reminders");
-        m.insert(6771, "plysrv-https");
-        m.insert(6770, "plysrv-http");
-        m.insert(3055, "policysertver");
-        m.insert(2487, "pns");
-        m.insert(16310, "pduncs");
-        m.insert(16311, "pdefmns");
-        m.insert(3540, "pnrp-port");
-        m.insert(34379, "p-net-remote");
-        m.insert(34378, "p-net-local");
-        m.insert(2972, "pmsm-webrctl");
-        m.insert(7633, "pmdfmgt");
-        m.insert(6355, "pmcs");
-        m.insert(188, "mumps");
-        m.insert(2790, "plgproxy");
-        m.insert(32400, "plex");
-        m.insert(4351, "plcy-net-svcs");
-        m.insert(3934, "sunfm-port");
-        m.insert(3933, "plbsertve-port");
-        m.insert(4659, "playsta2-lob");
-        m.insert(1819, "plato-lm");
-        m.insert(5586, "att-mt-sms");
-        m.insert(5863, "ppsuitemsg");
-        m.insert(17010, "ncpu");
-        m.insert(9318, "secure-ts");
-        m.insert(318, "pkix-timestamp");
-        m.insert(5318, "pkix-cmc");
-        m.insert(2634, "pk-electronics");
-        m.insert(4416, "pjj-player");
-        m.insert(5078, "pixelpusher");
-        m.insert(3189, "pcle-infex");
-        m.insert(6924, "split-ping");
-        m.insert(3010, "gw");
-        m.insert(15740, "ptp");
-        m.insert(1603, "picodbc");
-        m.insert(2787, "piccolo");
-        m.insert(4390, "wiegand");
-        m.insert(468, "photuris");
-        m.insert(4869, "phrelaydbg");
-        m.insert(4868, "phrelay");
-        m.insert(3177, "phonex-port");
-        m.insert(3347, "phoenix-rpc");
-        m.insert(6124, "pnbs");
-        m.insert(2350, "psbsertver");
-        m.insert(3208, "pfu-prcallback");
-        m.insert(2520, "pvsw");
-        m.insert(2441, "pvsw-inet");
-        m.insert(3109, "personnel");
-        m.insert(3557, "personalos-001");
-        m.insert(281, "personal-link");
-        m.insert(1916, "persona");
-        m.insert(4313, "perrla");
-        m.insert(5312, "permabit-cs");
-        m.insert(4066, "pmas");
-        m.insert(345, "pawsertv");
-        m.insert(9630, "peoctlr");
-        m.insert(9631, "peocoll");
-        m.insert(6817, "pentbox-sim");
-        m.insert(3582, "press");
-        m.insert(9279, "pegasus-ctl");
-        m.insert(9278, "pegasus");
-        m.insert(8027, "papachi-p2p-srv");
-        m.insert(3587, "p2pgroup");
-        m.insert(4747, "buschtrommel");
-        m.insert(2178, "bitspeer");
-        m.insert(5112, "pm-cmdsvr");
-        m.insert(3135, "peerbook-port");
-        m.insert(5443, "spss");
-        m.insert(7880, "pss");
-        m.insert(1980, "pearldoc-xact");
-        m.insert(6086, "pdtp");
-        m.insert(3254, "pda-sys");
-        m.insert(4012, "pda-gate");
-        m.insert(9597, "pd-admin");
-        m.insert(3253, "pda-data");
-        m.insert(2274, "pcttunnell");
-        m.insert(2299, "pc-telecommute");
-        m.insert(8444, "pcsync-http");
-        m.insert(6655, "pcs-sf-ui-man");
-        m.insert(44322, "pmcdproxy");
-        m.insert(44321, "pmcd");
-        m.insert(5351, "nat-pmp");
-        m.insert(5350, "nat-pmp-status");
-        m.insert(5172, "pcoip-mgmt");
-        m.insert(4172, "pcoip");
-        m.insert(1332, "pcia-rxp-b");
-        m.insert(2256, "pcc-mfp");
-        m.insert(8129, "paycash-wbp");
-        m.insert(8128, "paycash-online");
-        m.insert(4097, "patrolview");
-        m.insert(8161, "patrol-snmp");
-        m.insert(2665, "patrol-mq-nm");
-        m.insert(2664, "patrol-mq-gm");
-        m.insert(6162, "patrol-coll");
-        m.insert(4189, "pcep");
-        m.insert(1333, "passwrd-policy");
-        m.insert(3735, "pwdis");
-        m.insert(586, "password-chg");
-        m.insert(6581, "parsec-peer");
-        m.insert(6582, "parsec-game");
-        m.insert(4681, "parliant");
-        m.insert(4312, "pscl-mgt");
-        m.insert(4989, "parallel");
-        m.insert(7216, "PS-Capture-Pro");
-        m.insert(3348, "pangolin-lasert");
-        m.insert(3095, "panasas");
-        m.insert(6657, "palcom-disc");
-        m.insert(30002, "pago-sertvices2");
-        m.insert(7237, "pads");
-        m.insert(3435, "pacom");
-        m.insert(2246, "pc-mta-addrmap");
-        m.insert(1675, "pdp");
-        m.insert(31400, "pace-licensed");
-        m.insert(4311, "p6ssmc");
-        m.insert(9559, "p4runtime");
-        m.insert(6671, "p4p-portal");
-        m.insert(6679, "osaut");
-        m.insert(3034, "osmosis-aeea");
-        m.insert(40853, "ortec-disc");
-        m.insert(11103, "origo-sync");
-        m.insert(3274, "ordinox-sertver");
-        m.insert(3355, "ordinox-dbase");
-        m.insert(3078, "orbix-cfg-ssl");
-        m.insert(3075, "orbix-locator");
-        m.insert(3076, "orbix-config");
-        m.insert(8070, "ucs-isc");
-        m.insert(2484, "ttc-ssl");
-        m.insert(2483, "ttc");
-        m.insert(3891, "rtc-pm-port");
-        m.insert(1571, "rdb-dbs-disp");
-        m.insert(1830, "net8-cman");
-        m.insert(1630, "oraclenet8cman");
-        m.insert(8997, "oracle-ms-ens");
-        m.insert(8102, "kz-migr");
-        m.insert(2482, "giop-ssl");
-        m.insert(2481, "giop");
-        m.insert(5155, "asctrl-agent");
-        m.insert(5575, "ora-oap");
-        m.insert(3718, "opus-sertvices");
-        m.insert(22005, "optohost004");
-        m.insert(22004, "optohost004");
-        m.insert(22003, "optohost003");
-        m.insert(22002, "optohost002");
-        m.insert(2524, "optiwave-lm");
-        m.insert(1829, "optika-emedia");
-        m.insert(2237, "optech-port1-lm");
-        m.insert(3977, "opswmanager");
-        m.insert(3976, "opswagent");
-        m.insert(3303, "opsession-clnt");
-        m.insert(19191, "opsec-uaa");
-        m.insert(3433, "alta-smp");
-        m.insert(5724, "omsdk");
-        m.insert(2400, "opequus-sertver");
-        m.insert(7629, "xdas");
-        m.insert(6640, "ovsdb");
-        m.insert(2389, "ovsessionmgr");
-        m.insert(30999, "ovobs");
-        m.insert(2447, "ovwdb");
-        m.insert(3673, "mediavault-gui");
-        m.insert(7430, "xmpv7");
-        m.insert(7429, "opi-sock");
-        m.insert(7426, "pmdmgr");
-        m.insert(7431, "pmd");
-        m.insert(7428, "ovladmgr");
-        m.insert(7427, "oveadmgr");
-        m.insert(9390, "otp");
-        m.insert(4317, "opentelemetry");
-        m.insert(35357, "openstack-id");
-        m.insert(7728, "osvr");
-        m.insert(8004, "p2pevolvenet");
-        m.insert(5045, "osp");
-        m.insert(8688, "openremote-ctrl");
-        m.insert(1258, "opennl");
-        m.insert(5757, "x500ms");
-        m.insert(5729, "openmail");
-        m.insert(5767, "s-openmail");
-        m.insert(5766, "openmailns");
-        m.insert(5755, "openmailg");
-        m.insert(5768, "openmailpxy");
-        m.insert(4743, "openhpid");
-        m.insert(9008, "ogs-sertver");
-        m.insert(9007, "ogs-client");
-        m.insert(3187, "odi-port");
-        m.insert(20014, "opendeploy");
-        m.insert(4089, "opencore");
-        m.insert(3434, "opencm");
-        m.insert(4840, "opcua-tcp");
-        m.insert(4843, "opcua-tls");
-        m.insert(3100, "opcon-xps");
-        m.insert(314, "opalis-robot");
-        m.insert(3154, "ccmrmi");
-        m.insert(9994, "palace-3");
-        m.insert(9993, "palace-2");
-        m.insert(8767, "core-of-source");
-        m.insert(4304, "owsertver");
-        m.insert(2428, "ott");
-        m.insert(2199, "onehome-help");
-        m.insert(2198, "onehome-remote");
-        m.insert(2185, "onbase-dds");
-        m.insert(4428, "omvisertver");
-        m.insert(4429, "omviagent");
-        m.insert(4162, "omstopology");
-        m.insert(4395, "omnivisionesx");
-        m.insert(2056, "omnisky");
-        m.insert(5402, "mftp");
-        m.insert(3340, "anet-m");
-        m.insert(3339, "anet-l");
-        m.insert(3341, "anet-h");
-        m.insert(3338, "anet-b");
-        m.insert(7275, "oma-ulp");
-        m.insert(7274, "oma-rlp-s");
-        m.insert(7277, "oma-ilp-s");
-        m.insert(7276, "oma-ilp");
-        m.insert(4359, "omabcastltkm");
-        m.insert(2077, "tsrmagt");
-        m.insert(8769, "oktaauthenticat");
-        m.insert(9966, "odnsp");
-        m.insert(4732, "ohmtrigger");
-        m.insert(3320, "officelink2000");
-        m.insert(11175, "oemcacao-websvc");
-        m.insert(11174, "oemcacao-rmi");
-        m.insert(11172, "oemcacao-jmxmp");
-        m.insert(13706, "netbackup");
-        m.insert(3523, "odeumsertvlink");
-        m.insert(429, "ocs_amu");
-        m.insert(2697, "oce-snmp-trap");
-        m.insert(18186, "ohsc");
-        m.insert(3442, "connect-sertver");
-        m.insert(3441, "connect-client");
-        m.insert(29167, "otmp");
-        m.insert(36602, "obsertvium-agent");
-        m.insert(7030, "op-probe");
-        m.insert(1894, "o2sertver-port");
-        m.insert(28000, "nxlmd");
-        m.insert(126, "unitary");
-        m.insert(4420, "nvm-express");
-        m.insert(2184, "nvd");
-        m.insert(3780, "nnp");
-        m.insert(49001, "nusrp");
-        m.insert(11235, "xcompute");
-        m.insert(4128, "nufw");
-        m.insert(8711, "nvc");
-        m.insert(10810, "nmc-disc");
-        m.insert(45001, "asmps");
-        m.insert(5415, "ns-sertver");
-        m.insert(4453, "nssalertmgr");
-        m.insert(359, "tenebris_nts");
-        m.insert(3266, "ns-cfg-sertver");
-        m.insert(36424, "nq-ap");
-        m.insert(2868, "npep-messaging");
-        m.insert(7724, "nsdeepfreezectl");
-        m.insert(396, "netware-ip");
-        m.insert(2645, "novell-ipx-cmd");
-        m.insert(23402, "novar-global");
-        m.insert(23400, "novar-dbase");
-        m.insert(23401, "novar-alarm");
-        m.insert(3016, "notify_srvr");
-        m.insert(21010, "notezilla-lan");
-        m.insert(5215, "noteza");
-        m.insert(4663, "noteit");
-        m.insert(4803, "notateit");
-        m.insert(2338, "norton-lambert");
-        m.insert(15126, "swgps");
-        m.insert(5209, "nomad");
-        m.insert(3406, "nokia-ann-ch2");
-        m.insert(3405, "nokia-ann-ch1");
-        m.insert(5627, "ninaf");
-        m.insert(4088, "npsp");
-        m.insert(2210, "noaaport");
-        m.insert(2244, "nmssertver");
-        m.insert(2817, "nmsigport");
-        m.insert(10111, "nmea-onenet");
-        m.insert(10110, "nmea-0183");
-        m.insert(1242, "nmasoverip");
-        m.insert(5299, "nlg-data");
-        m.insert(2252, "njenet-ssl");
-        m.insert(3649, "nmmp");
-        m.insert(6421, "nim-wan");
-        m.insert(6420, "nim-vdrshell");
-        m.insert(1617, "nimrod-agent");
-        m.insert(48001, "nimspooler");
-        m.insert(48002, "nimhub");
-        m.insert(48003, "nimgtw");
-        m.insert(48005, "nimbusdbctrl");
-        m.insert(48004, "nimbusdb");
-        m.insert(48000, "nimcontroller");
-        m.insert(61, "ni-mail");
-        m.insert(4134, "nifty-hmi");
-        m.insert(38412, "ng-control");
-        m.insert(20048, "mountd");
-        m.insert(7393, "nfoldman");
-        m.insert(4021, "nexus-portal");
-        m.insert(178, "nextstep");
-        m.insert(8457, "nexentamv");
-        m.insert(550, "new-rwho");
-        m.insert(2058, "newwavesearch");
-        m.insert(2075, "newlixengine");
-        m.insert(2076, "newlixconfig");
-        m.insert(3165, "newgenpay");
-        m.insert(6133, "nbt-wol");
-        m.insert(2614, "neveroffline");
-        m.insert(2585, "netx-sertver");
-        m.insert(4702, "netxms-sync");
-        m.insert(4701, "netxms-mgmt");
-        m.insert(2586, "netx-agent");
-        m.insert(3203, "netwatcher-mon");
-        m.insert(3204, "netwatcher-db");
-        m.insert(4460, "ntske");
-        m.insert(16361, "netsertialext2");
-        m.insert(16367, "netsertialext3");
-        m.insert(16360, "netsertialext1");
-        m.insert(16368, "netsertialext4");
-        m.insert(4159, "nss");
-        m.insert(170, "print-srv");
-        m.insert(2293, "npdbgmngr");
-        m.insert(4703, "npqes-test");
-        m.insert(8981, "nod-client");
-        m.insert(3409, "networklens");
-        m.insert(7549, "nls-tl");
-        m.insert(171, "multiplex");
-        m.insert(20049, "nfsrdma");
-        m.insert(1155, "nfa");
-        m.insert(537, "nmsp");
-        m.insert(3196, "ncu-2");
-        m.insert(3195, "ncu-1");
-        m.insert(2411, "netwave-ap-mgmt");
-        m.insert(2788, "fryesertv");
-        m.insert(4127, "unikeypro");
-        m.insert(6777, "ntz-tracker");
-        m.insert(6778, "ntz-p2p-storage");
-        m.insert(1879, "nettgain-nms");
-        m.insert(5421, "netsupport2");
-        m.insert(3440, "ans-console");
-        m.insert(2128, "net-steward");
-        m.insert(21846, "netspeak-is");
-        m.insert(21849, "netspeak-cps");
-        m.insert(21847, "netspeak-cs");
-        m.insert(21848, "netspeak-acd");
-        m.insert(395, "netcp");
-        m.insert(154, "netsc-prod");
-        m.insert(155, "netsc-dev");
-        m.insert(4425, "netrockey6");
-        m.insert(2328, "netrix-sftm");
-        m.insert(3129, "netport-id");
-        m.insert(3641, "netplay-port2");
-        m.insert(3640, "netplay-port1");
-        m.insert(1970, "netop-rc");
-        m.insert(2486, "netobjects2");
-        m.insert(2485, "netobjects1");
-        m.insert(6842, "netmo-http");
-        m.insert(6841, "netmo-default");
-        m.insert(3149, "nm-game-sertver");
-        m.insert(3148, "nm-game-admin");
-        m.insert(3150, "nm-asses-admin");
-        m.insert(3151, "nm-assessor");
-        m.insert(1406, "netlabs-lm");
-        m.insert(218, "mpp");
-        m.insert(10116, "netiq-voipa");
-        m.insert(10114, "netiq-qcheck");
-        m.insert(2219, "netiq-ncap");
-        m.insert(2735, "netiq-mc");
-        m.insert(10117, "iqrm");
-        m.insert(10113, "netiq-endpoint");
-        m.insert(2220, "netiq");
-        m.insert(3725, "na-er-tip");
-        m.insert(5229, "jaxflow");
-        m.insert(4350, "net-device");
-        m.insert(6513, "netconf-tls");
-        m.insert(4335, "netconf-ch-tls");
-        m.insert(4334, "netconf-ch-ssh");
-        m.insert(5681, "ncxcp");
-        m.insert(1676, "netcomm1");
-        m.insert(2971, "netclip");
-        m.insert(4409, "netcabinet-com");
-        m.insert(3131, "netbookmark");
-        m.insert(4441, "netblox");
-        m.insert(1612, "netbill-trans");
-        m.insert(1616, "netbill-prod");
-        m.insert(1613, "netbill-keyrep");
-        m.insert(1614, "netbill-cred");
-        m.insert(13785, "nbdb");
-        m.insert(11104, "netapp-icmgmt");
-        m.insert(11105, "netapp-icdata");
-        m.insert(3829, "neteh-ext");
-        m.insert(11095, "weave");
-        m.insert(3507, "nesh-broker");
-        m.insert(3213, "neon24x7");
-        m.insert(7474, "neo4j");
-        m.insert(3886, "nei-management");
-        m.insert(4043, "nirp");
-        m.insert(2730, "nec-raidplus");
-        m.insert(377, "tnETOS");
-        m.insert(378, "dsETOS");
-        m.insert(3024, "nds_sso");
-        m.insert(2738, "ndl-tcp-ois-gw");
-        m.insert(2528, "ncr_ccl");
-        m.insert(4844, "gw-log");
-        m.insert(4842, "gw-asv");
-        m.insert(5979, "ncd-conf-tcp");
-        m.insert(1888, "ncconfig");
-        m.insert(2093, "nbx-cc");
-        m.insert(2094, "nbx-au");
-        m.insert(20034, "nburn_id");
-        m.insert(2163, "navisphere-sec");
-        m.insert(3159, "navegaweb-port");
-        m.insert(6317, "nav-data-cmd");
-        m.insert(4361, "nacnl");
-        m.insert(2895, "natuslink");
-        m.insert(3753, "nattysertver");
-        m.insert(2343, "nati-logos");
-        m.insert(3015, "nati-dstp");
-        m.insert(1790, "nmsp");
-        m.insert(3950, "namemunge");
-        m.insert(6363, "ndn");
-        m.insert(9286, "n2receive");
-        m.insert(9285, "n2h2sertver");
-        m.insert(7282, "mzca-action");
-        m.insert(6446, "mysql-proxy");
-        m.insert(2273, "mysql-im");
-        m.insert(33060, "mysqlx");
-        m.insert(2388, "mynahautostart");
-        m.insert(9119, "mxit");
-        m.insert(3733, "multip-msg");
-        m.insert(32801, "mlsn");
-        m.insert(4421, "scaleft");
-        m.insert(7420, "ipluminary");
-        m.insert(9903, "multicast-ping");
-        m.insert(6622, "mcftp");
-        m.insert(5354, "mdnsresponder");
-        m.insert(7742, "msss");
-        m.insert(2305, "mt-scalesertver");
-        m.insert(2791, "mtport-regist");
-        m.insert(8115, "mtl8000-matrix");
-        m.insert(3122, "vtr-emulator");
-        m.insert(2855, "msrp");
-        m.insert(8276, "pando-sec");
-        m.insert(2871, "msi-selectplay");
-        m.insert(4554, "msfrs");
-        m.insert(2171, "msfw-storage");
-        m.insert(2172, "msfw-s-storage");
-        m.insert(2173, "msfw-replica");
-        m.insert(2174, "msfw-array");
-        m.insert(7680, "pando-pub");
-        m.insert(3343, "ms-cluster-net");
-        m.insert(7392, "mrssrendezvous");
-        m.insert(3958, "mqe-agent");
-        m.insert(3358, "mpsysrmsvr");
-        m.insert(46, "mpm-snd");
-        m.insert(6634, "mpls-pm");
-        m.insert(8503, "lsp-self-ping");
-        m.insert(3924, "mpl-gprs-port");
-        m.insert(2488, "moy-corp");
-        m.insert(10544, "MOS-soap-opt");
-        m.insert(10543, "MOS-soap");
-        m.insert(10541, "MOS-upper");
-        m.insert(10540, "MOS-lower");
-        m.insert(10542, "MOS-aux");
-        m.insert(4691, "mtn");
-        m.insert(8666, "monetra-admin");
-        m.insert(1576, "moldflow-lm");
-        m.insert(4986, "mrip");
-        m.insert(6997, "MobilitySrv");
-        m.insert(3732, "m-wnn");
-        m.insert(4688, "mobile-p2p");
-        m.insert(7871, "mdm");
-        m.insert(9632, "mc-comm");
-        m.insert(7869, "mobileanalyzer");
-        m.insert(2593, "mns-mail");
-        m.insert(3764, "mni-prot-rout");
-        m.insert(5237, "mnet-discovery");
-        m.insert(4668, "mmaeds");
-        m.insert(4173, "mma-discovery");
-        m.insert(4667, "mmacomm");
-        m.insert(8077, "mles");
-        m.insert(4310, "mirrtex");
-        m.insert(7606, "mipi-debug");
-        m.insert(5136, "minotaur-sa");
-        m.insert(4069, "minger");
-        m.insert(21554, "dfsertver");
-        m.insert(7391, "mindfilesys");
-        m.insert(9445, "mindarray-ca");
-        m.insert(2180, "mc-gt-srv");
-        m.insert(3180, "mc-brk-srv");
-        m.insert(2621, "miles-apart");
-        m.insert(4551, "ieee-mih");
-        m.insert(3008, "midnight-tech");
-        m.insert(7013, "microtalon-dis");
-        m.insert(7014, "microtalon-com");
-        m.insert(5362, "sertverwsd2");
-        m.insert(6601, "mstmg-sstp");
-        m.insert(1512, "wins");
-        m.insert(5356, "ms-smlbiz");
-        m.insert(6074, "max");
-        m.insert(5726, "ms-ilm-sts");
-        m.insert(5364, "kdnet");
-        m.insert(5725, "ms-ilm");
-        m.insert(6076, "msft-dpm-cert");
-        m.insert(6075, "dpm-acm");
-        m.insert(2175, "airsync");
-        m.insert(3132, "ms-rule-engine");
-        m.insert(5359, "ms-alerter");
-        m.insert(2176, "rapi");
-        m.insert(5022, "mice");
-        m.insert(4679, "mgesupervision");
-        m.insert(4680, "mgemanagement");
-        m.insert(6509, "mgcs-mfp-port");
-        m.insert(2266, "mfsertver");
-        m.insert(6382, "metatude-mds");
-        m.insert(2230, "queueadm");
-        m.insert(6390, "metaedit-ws");
-        m.insert(6370, "metaedit-se");
-        m.insert(6360, "metaedit-mu");
-        m.insert(393, "dis");
-        m.insert(2311, "messagesertvice");
-        m.insert(8787, "msgsrvr");
-        m.insert(18, "msp");
-        m.insert(8786, "msgclnt");
-        m.insert(47000, "mbus");
-        m.insert(19788, "mle");
-        m.insert(1960, "nasmanager");
-        m.insert(9596, "mercury-disc");
-        m.insert(4603, "menandmice-upg");
-        m.insert(4151, "menandmice_noh");
-        m.insert(4552, "menandmice-mon");
-        m.insert(11211, "memcache");
-        m.insert(3569, "mbg-ctrl");
-        m.insert(4883, "wmlsertver");
-        m.insert(3571, "megardsvr-port");
-        m.insert(2944, "megaco-h248");
-        m.insert(2945, "h248-binary");
-        m.insert(2272, "mmcal");
-        m.insert(7720, "medimageportal");
-        m.insert(5157, "mediat");
-        m.insert(3445, "monp");
-        m.insert(2427, "mgcp-gateway");
-        m.insert(2727, "mgcp-callagent");
-        m.insert(2363, "mediacntrlnfsd");
-        m.insert(46999, "mediabox");
-        m.insert(2789, "media-agent");
-        m.insert(13930, "medevolve");
-        m.insert(3232, "mdtp");
-        m.insert(2688, "md-cg-http");
-        m.insert(3235, "mdap-port");
-        m.insert(5598, "mctfeed");
-        m.insert(3115, "mctet-master");
-        m.insert(3117, "mctet-jsertv");
-        m.insert(3116, "mctet-gateway");
-        m.insert(3331, "mcs-messaging");
-        m.insert(3332, "mcs-mailsvr");
-        m.insert(3302, "mcs-fastmail");
-        m.insert(3330, "mcs-calypsoicf");
-        m.insert(3558, "mcp-port");
-        m.insert(8809, "3gpp-monp");
-        m.insert(3570, "mccwebsvr-port");
-        m.insert(4153, "mbl-battd");
-        m.insert(2591, "maytagshuffle");
-        m.insert(4179, "MaxumSP");
-        m.insert(4171, "ml-svnet");
-        m.insert(3276, "maxim-asics");
-        m.insert(5540, "sdreport");
-        m.insert(4360, "matrix_vnet");
-        m.insert(4458, "mcp");
-        m.insert(7421, "mtportmon");
-        m.insert(49000, "matahari");
-        m.insert(7073, "martalk");
-        m.insert(3836, "markem-dcp");
-        m.insert(5282, "transmit-port");
-        m.insert(8384, "marathontp");
-        m.insert(36700, "mapx");
-        m.insert(4686, "msp-os");
-        m.insert(269, "manet");
-        m.insert(9255, "mon");
-        m.insert(6201, "thermo-calc");
-        m.insert(2544, "novell-zen");
-        m.insert(2516, "maincontrol");
-        m.insert(5092, "magpie");
-        m.insert(2243, "magicom");
-        m.insert(4902, "magiccontrol");
-        m.insert(313, "magenta-logic");
-        m.insert(3691, "magaya-network");
-        m.insert(2453, "madge-ltd");
-        m.insert(4345, "m4-network-as");
-        m.insert(44900, "m3da");
-        m.insert(36444, "m3ap");
-        m.insert(3565, "m2pa");
-        m.insert(36443, "m2ap");
-        m.insert(4894, "lyskom");
-        m.insert(3747, "linktest-s");
-        m.insert(3746, "linktest");
-        m.insert(5044, "lxi-evntsvc");
-        m.insert(6471, "lvision-lm");
-        m.insert(3079, "lv-frontpanel");
-        m.insert(4913, "lutcp");
-        m.insert(4741, "lumimgrd");
-        m.insert(10805, "lpdg");
-        m.insert(3487, "ltctcp");
-        m.insert(3068, "ls3bcast");
-        m.insert(8162, "lpar2rrd");
-        m.insert(4083, "lorica-out-sec");
-        m.insert(4082, "lorica-out");
-        m.insert(4081, "lorica-in-sec");
-        m.insert(7026, "loreji-panel");
-        m.insert(1983, "lhtp");
-        m.insert(2289, "dict-lookup");
-        m.insert(1629, "lontalk-urgnt");
-        m.insert(1628, "lontalk-norm");
-        m.insert(1634, "loaprobe");
-        m.insert(8101, "ldoms-migr");
-        m.insert(6482, "ldoms-mgmt");
-        m.insert(5254, "logcabin");
-        m.insert(5058, "locus-disc");
-        m.insert(4044, "ltp");
-        m.insert(3591, "gtrack-sertver");
-        m.insert(3592, "gtrack-ne");
-        m.insert(1903, "linkname");
-        m.insert(5062, "na-localise");
-        m.insert(6087, "ldss");
-        m.insert(2090, "lrp");
-        m.insert(2465, "lbm");
-        m.insert(2466, "lbf");
-        m.insert(6200, "lm-x");
-        m.insert(8208, "lm-webwatcher");
-        m.insert(8207, "lm-ssertver");
-        m.insert(8204, "lm-perfworks");
-        m.insert(31620, "lm-mon");
-        m.insert(8205, "lm-instmgr");
-        m.insert(8206, "lm-dta");
-        m.insert(3278, "lkcmsertver");
-        m.insert(2145, "lv-pici");
-        m.insert(2143, "lv-jc");
-        m.insert(2147, "lv-auth");
-        m.insert(2146, "lv-not");
-        m.insert(3767, "listmgr-port");
-        m.insert(46336, "inedo");
-        m.insert(10933, "octopustentacle");
-        m.insert(4341, "lisp-data");
-        m.insert(1969, "lipsinc1");
-        m.insert(10809, "nbd");
-        m.insert(12300, "linogridengine");
-        m.insert(8191, "limnerpressure");
-        m.insert(517, "talk");
-        m.insert(4670, "light");
-        m.insert(7365, "lcm-sertver");
-        m.insert(3028, "LiebDevMgmt_DM");
-        m.insert(3027, "LiebDevMgmt_C");
-        m.insert(3029, "LiebDevMgmt_A");
-        m.insert(1203, "ssslic-mgr");
-        m.insert(1886, "leoip");
-        m.insert(11430, "lsdp");
-        m.insert(374, "legent-2");
-        m.insert(2212, "leecopossertver");
-        m.insert(3407, "ldap-admin");
-        m.insert(2816, "lbc-watchdog");
-        m.insert(2779, "lbc-sync");
-        m.insert(2815, "lbc-measure");
-        m.insert(2780, "lbc-control");
-        m.insert(3373, "lavenir-lm");
-        m.insert(3739, "launchbird-lm");
-        m.insert(3815, "lansurveyorxml");
-        m.insert(4347, "lansurveyor");
-        m.insert(11796, "lanschool");
-        m.insert(3970, "lanrevagent");
-        m.insert(4547, "lanner-lm");
-        m.insert(1764, "landesk-rc");
-        m.insert(2395, "lan900_remote");
-        m.insert(4372, "l2c-data");
-        m.insert(4432, "l-acoustics");
-        m.insert(9747, "l5nas-parchan");
-        m.insert(4371, "l2c-control");
-        m.insert(3360, "kv-sertver");
-        m.insert(3361, "kv-agent");
-        m.insert(4331, "ktickets-rest");
-        m.insert(40023, "k-patentssensor");
-        m.insert(27504, "kopek-httphead");
-        m.insert(2294, "konshus-lm");
-        m.insert(5253, "kpdp");
-        m.insert(7697, "klio");
-        m.insert(35354, "kitim");
-        m.insert(186, "kis");
-        m.insert(30260, "kingdomsonline");
-        m.insert(4566, "kwtc");
-        m.insert(584, "keysertver");
-        m.insert(5696, "kmip");
-        m.insert(6623, "ktelnet");
-        m.insert(6620, "kftp-data");
-        m.insert(6621, "kftp");
-        m.insert(2502, "kentrox-prot");
-        m.insert(3112, "ksysguard");
-        m.insert(36865, "kastenxpipe");
-        m.insert(2918, "kastenchasepad");
-        m.insert(4661, "kar2ouche");
-        m.insert(31016, "ka-sddp");
-        m.insert(26262, "k3software-svr");
-        m.insert(26263, "k3software-cli");
-        m.insert(3642, "juxml-port");
-        m.insert(48048, "juka");
-        m.insert(5309, "jprinter");
-        m.insert(3155, "jpegmpeg");
-        m.insert(4166, "joost");
-        m.insert(27442, "jesmsjc");
-        m.insert(6583, "joaJewelSuite");
-        m.insert(3215, "jmq-daemon-2");
-        m.insert(3214, "jmq-daemon-1");
-        m.insert(8901, "jmb-cds2");
-        m.insert(19020, "j-link");
-        m.insert(4160, "jini-discovery");
-        m.insert(3094, "rapidmq-reg");
-        m.insert(3093, "rapidmq-center");
-        m.insert(3777, "jibe-eb");
-        m.insert(1937, "jwsertver");
-        m.insert(1938, "jwclient");
-        m.insert(1939, "jvsertver");
-        m.insert(1940, "jvclient");
-        m.insert(2097, "jetformpreview");
-        m.insert(1936, "jetcmesertver");
-        m.insert(1810, "jerand-lm");
-        m.insert(6244, "jeol-nsdtp-4");
-        m.insert(6243, "jeol-nsdtp-3");
-        m.insert(6242, "jeol-nsdtp-2");
-        m.insert(6241, "jeol-nsdtp-1");
-        m.insert(4107, "j-ac");
-        m.insert(19541, "jcp");
-        m.insert(3529, "jboss-iiop-ssl");
-        m.insert(3528, "jboss-iiop");
-        m.insert(5230, "jaxflow-data");
-        m.insert(4327, "jaxer-web");
-        m.insert(5883, "jute");
-        m.insert(2205, "jps");
-        m.insert(7095, "jdp-disc");
-        m.insert(3794, "jaus");
-        m.insert(3473, "jaugsremotec-2");
-        m.insert(3472, "jaugsremotec-1");
-        m.insert(7181, "janus-disc");
-        m.insert(5034, "jtnetd-status");
-        m.insert(3627, "jamsertverport");
-        m.insert(8091, "jamlink");
-        m.insert(1578, "jacobus-lm");
-        m.insert(5673, "jms");
-        m.insert(5049, "ivocalize");
-        m.insert(4880, "hislip");
-        m.insert(3258, "ivecon-port");
-        m.insert(2828, "itm-lm");
-        m.insert(3719, "itelsertverport");
-        m.insert(7478, "openit");
-        m.insert(7280, "itactionsertver1");
-        m.insert(1636, "isdc");
-        m.insert(1637, "islc");
-        m.insert(3775, "ispmmgr");
-        m.insert(24321, "ild");
-        m.insert(499, "iso-ill");
-        m.insert(3205, "isns");
-        m.insert(1950, "ismaeasdaqtest");
-        m.insert(1949, "ismaeasdaqlive");
-        m.insert(3226, "isi-irp");
-        m.insert(8148, "isdd");
-        m.insert(5047, "iscape");
-        m.insert(4075, "perimlan");
-        m.insert(17223, "isa100-gci");
-        m.insert(21000, "irtrans");
-        m.insert(3504, "ironstorm");
-        m.insert(3206, "ironmail");
-        m.insert(2632, "irdg-post");
-        m.insert(529, "irc");
-        m.insert(4073, "iRAPP");
-        m.insert(32034, "iracinghelper");
-        m.insert(18769, "ique");
-        m.insert(2527, "iqsertver");
-        m.insert(4593, "ipt-anri-anri");
-        m.insert(4792, "unified-bus");
-        m.insert(4791, "roce");
-        m.insert(7031, "iposplanet");
-        m.insert(33435, "mtrace");
-        m.insert(4740, "ipfixs");
-        m.insert(4739, "ipfix");
-        m.insert(4068, "ipfltbcst");
-        m.insert(20202, "ipdtp-port");
-        m.insert(4737, "ipdr-sp");
-        m.insert(9214, "ipdcesgbs");
-        m.insert(2215, "ipcore");
-        m.insert(3743, "ipcs-command");
-        m.insert(2088, "ip-blf");
-        m.insert(7410, "ionixnetmon");
-        m.insert(5728, "io-dist-data");
-        m.insert(45054, "invision-ag");
-        m.insert(3614, "sigma-port");
-        m.insert(8020, "intu-ec-svcdisc");
-        m.insert(11751, "intrepid-ssl");
-        m.insert(2202, "imtc-map");
-        m.insert(6697, "ircs-u");
-        m.insert(4744, "ifsp");
-        m.insert(1884, "idmaps");
-        m.insert(3699, "kpn-icw");
-        m.insert(6714, "ibprotocol");
-        m.insert(1611, "ill");
-        m.insert(7202, "pon-ictp");
-        m.insert(4569, "iax");
-        m.insert(3508, "interactionweb");
-        m.insert(24386, "intel_rci");
-        m.insert(16995, "amt-redir-tls");
-        m.insert(16994, "amt-redir-tcp");
-        m.insert(1674, "proshare-mc-2");
-        m.insert(1673, "proshare-mc-1");
-        m.insert(7128, "scenidm");
-        m.insert(4746, "intelliadm-disc");
-        m.insert(17234, "integrius-stp");
-        m.insert(9215, "insis");
-        m.insert(4486, "icms");
-        m.insert(484, "integra-sme");
-        m.insert(5057, "intecom-ps2");
-        m.insert(5056, "intecom-ps1");
-        m.insert(7624, "indi");
-        m.insert(2980, "wimd");
-        m.insert(4109, "izm");
-        m.insert(49150, "inspider");
-        m.insert(215, "softpc");
-        m.insert(23005, "inovaport6");
-        m.insert(23004, "inovaport5");
-        m.insert(23003, "inovaport4");
-        m.insert(23002, "inovaport3");
-        m.insert(23001, "inovaport2");
-        m.insert(23000, "inovaport1");
-        m.insert(2716, "inova-ip-disco");
-        m.insert(3560, "inisertve-port");
-        m.insert(5597, "ininmessaging");
-        m.insert(134, "ingres-net");
-        m.insert(38001, "ivs-inserttion");
-        m.insert(38000, "ivs-database");
-        m.insert(4067, "idp");
-        m.insert(1428, "informatik-lm");
-        m.insert(2480, "powerexchange");
-        m.insert(5029, "infobright");
-        m.insert(8067, "infi-async");
-        m.insert(5069, "i-net-2000-npr");
-        m.insert(3156, "indura");
-        m.insert(3139, "incognitorv");
-        m.insert(244, "dayna");
-        m.insert(7675, "imqtunnel");
-        m.insert(7673, "imqstomps");
-        m.insert(7672, "imqstomp");
-        m.insert(7674, "imqtunnels");
-        m.insert(2637, "imdocsvc");
-        m.insert(4139, "thrtx");
-        m.insert(3783, "bim-pem");
-        m.insert(3657, "immedianet-bcn");
-        m.insert(11320, "imip-channels");
-        m.insert(8615, "imink");
-        m.insert(585, "imap4-ssl");
-        m.insert(48128, "isnetsertv");
-        m.insert(2239, "imagequery");
-        m.insert(3596, "iw-mmogame");
-        m.insert(2055, "iop");
-        m.insert(3186, "iiw-port");
-        m.insert(19000, "igrid");
-        m.insert(5165, "ife_icorp");
-        m.insert(3420, "ifcp-port");
-        m.insert(17220, "avtp");
-        m.insert(17221, "avdecc");
-        m.insert(19998, "iec-104-sec");
-        m.insert(2404, "iec-104");
-        m.insert(2079, "idware-router");
-        m.insert(4152, "idig_mux");
-        m.insert(4604, "irp");
-        m.insert(25604, "idtp");
-        m.insert(5742, "ida-discover2");
-        m.insert(5741, "ida-discover1");
-        m.insert(4553, "icshostsvc");
-        m.insert(2799, "icon-discover");
-        m.insert(4801, "iwec");
-        m.insert(4802, "ilss");
-        m.insert(2063, "icg-bridge");
-        m.insert(14143, "icpps");
-        m.insert(14142, "icpp");
-        m.insert(4061, "ice-location");
-        m.insert(4062, "ice-slocation");
-        m.insert(4063, "ice-router");
-        m.insert(4064, "ice-srouter");
-        m.insert(31948, "iceedcp_tx");
-        m.insert(31949, "iceedcp_rx");
-        m.insert(2276, "ibridge-mgmt");
-        m.insert(2275, "ibridge-data");
-        m.insert(1881, "ibm-mqserties2");
-        m.insert(2078, "tpcsrvr");
-        m.insert(3660, "can-nds-ssl");
-        m.insert(3661, "can-ferret-ssl");
-        m.insert(1920, "can-ferret");
-        m.insert(1919, "can-dch");
-        m.insert(9085, "ibm-rsyscon");
-        m.insert(424, "opc-job-track");
-        m.insert(1933, "xmapi");
-        m.insert(1934, "xaapi");
-        m.insert(9089, "sqlexec-ssl");
-        m.insert(9088, "sqlexec");
-        m.insert(3667, "infoexch");
-        m.insert(3666, "esertver-pap");
-        m.insert(12003, "entextmed");
-        m.insert(12004, "entextlow");
-        m.insert(3539, "ibm-diradm-ssl");
-        m.insert(3538, "ibm-diradm");
-        m.insert(3267, "ibm-dial-out");
-        m.insert(385, "ibm-app");
-        m.insert(3494, "ibm3494");
-        m.insert(4594, "ias-session");
-        m.insert(4595, "ias-paging");
-        m.insert(4596, "ias-neighbor");
-        m.insert(3898, "senip");
-        m.insert(9614, "iadt-tls");
-        m.insert(4169, "iadt");
-        m.insert(5674, "hyperscsi-port");
-        m.insert(2374, "hydra");
-        m.insert(5105, "hughes-ap");
-        m.insert(8313, "hub-open-net");
-        m.insert(44323, "pmwebapi");
-        m.insert(5628, "htrust");
-        m.insert(2570, "hs-port");
-        m.insert(2113, "hsl-storm");
-        m.insert(4591, "l3t-at-an");
-        m.insert(4592, "hrpd-ith-at-an");
-        m.insert(5228, "hpvroom");
-        m.insert(5224, "hpvirtctrl");
-        m.insert(5227, "perfd");
-        m.insert(2207, "hpssd");
-        m.insert(4484, "hpssmgmt");
-        m.insert(3037, "hp-san-mgmt");
-        m.insert(2209, "rimf-ps");
-        m.insert(2448, "hpppssvr");
-        m.insert(3101, "hp-pxpib");
-        m.insert(382, "hp-managed-node");
-        m.insert(381, "hp-collector");
-        m.insert(3209, "netwkpathengine");
-        m.insert(7510, "ovhpas");
-        m.insert(2206, "hpocbus");
-        m.insert(2690, "hp-nnm-data");
-        m.insert(2208, "hpiod");
-        m.insert(7738, "aiagent");
-        m.insert(5317, "hpdevms");
-        m.insert(3329, "hp-device-disc");
-        m.insert(5316, "hpdevms");
-        m.insert(3449, "hotu-chat");
-        m.insert(2029, "hsrpv6");
-        m.insert(1985, "hsrp");
-        m.insert(10125, "cimple");
-        m.insert(2597, "homesteadglory");
-        m.insert(3634, "hlibmgr");
-        m.insert(8231, "hncp-udp-port");
-        m.insert(3250, "hicp");
-        m.insert(43438, "hmip-routing");
-        m.insert(4884, "hivestor");
-        m.insert(4117, "hillrsertv");
-        m.insert(2467, "high-criteria");
-        m.insert(4148, "hhb-handheld");
-        m.insert(18516, "heythings");
-        m.insert(7397, "hexarc");
-        m.insert(22370, "hpnpd");
-        m.insert(8807, "hes-clip");
-        m.insert(3921, "herodotus-net");
-        m.insert(4306, "pinghgl");
-        m.insert(10860, "helix");
-        m.insert(6440, "heliosd");
-        m.insert(3740, "heartbeat");
-        m.insert(1161, "health-polling");
-        m.insert(2641, "hdl-srv");
-        m.insert(7630, "hawk");
-        m.insert(3804, "iqnet-port");
-        m.insert(4197, "hctl");
-        m.insert(11108, "myq-termlink");
-        m.insert(9954, "hinp");
-        m.insert(6791, "hnm");
-        m.insert(3623, "haipe-discover");
-        m.insert(3769, "haipe-otnk");
-        m.insert(3036, "hagel-dump");
-        m.insert(5315, "hacl-poll");
-        m.insert(5305, "hacl-test");
-        m.insert(3542, "hacl-monitor");
-        m.insert(5304, "hacl-local");
-        m.insert(11720, "h323callsigalt");
-        m.insert(2517, "call-sig-trans");
-        m.insert(3179, "h2gf-w-2m");
-        m.insert(2979, "h263-video");
-        m.insert(2356, "gxtelmd");
-        m.insert(3745, "gw-call-port");
-        m.insert(18262, "gv-pf");
-        m.insert(2186, "gtaua");
-        m.insert(35356, "guttersnex");
-        m.insert(3436, "gc-config");
-        m.insert(2152, "gtp-usert");
-        m.insert(2123, "gtp-control");
-        m.insert(1452, "gtegsc-lm");
-        m.insert(4729, "gsmtap");
-        m.insert(3761, "gsakmp");
-        m.insert(3136, "grubd");
-        m.insert(28010, "gruber-cashreg");
-        m.insert(9340, "gribi");
-        m.insert(9339, "gnmi-gnoi");
-        m.insert(8710, "semi-grpc");
-        m.insert(30400, "gs-realtime");
-        m.insert(6267, "gld");
-        m.insert(6269, "grid-alt");
-        m.insert(6268, "grid");
-        m.insert(3757, "grf-port");
-        m.insert(4755, "gre-udp-dtls");
-        m.insert(4754, "gre-in-udp");
-        m.insert(4026, "as-debug");
-        m.insert(5117, "gradecam");
-        m.insert(9277, "traingpsdata");
-        m.insert(2947, "gpsd");
-        m.insert(3386, "gprs-data");
-        m.insert(2217, "gotodevice");
-        m.insert(37483, "gdrive-sync");
-        m.insert(16002, "gsms");
-        m.insert(5687, "gog-multiplayer");
-        m.insert(2072, "msync");
-        m.insert(1909, "global-wlink");
-        m.insert(9122, "grcmp");
-        m.insert(9123, "grcp");
-        m.insert(4131, "stars");
-        m.insert(3912, "gbmt-stars");
-        m.insert(3229, "global-cd-port");
-        m.insert(1880, "vsat-control");
-        m.insert(5688, "ggz");
-        m.insert(4332, "getty-focus");
-        m.insert(10800, "gap");
-        m.insert(4985, "gerhcs");
-        m.insert(3108, "geolocate");
-        m.insert(3475, "genisar-port");
-        m.insert(6080, "gue");
-        m.insert(4790, "vxlan-gpe");
-        m.insert(23053, "gntp");
-        m.insert(6081, "geneve");
-        m.insert(8190, "gcp-rphy");
-        m.insert(7017, "grasp");
-        m.insert(7283, "genstat");
-        m.insert(4730, "gearman");
-        m.insert(2159, "gdbremote");
-        m.insert(3429, "gcsp");
-        m.insert(2660, "gcmonitor");
-        m.insert(14145, "gcm-app");
-        m.insert(3484, "gbs-stp");
-        m.insert(3762, "gbs-smp");
-        m.insert(3222, "glbp");
-        m.insert(8322, "garmin-marine");
-        m.insert(1421, "gandalf-lm");
-        m.insert(1859, "gammafetchsvr");
-        m.insert(31765, "gamesmith-port");
-        m.insert(2914, "gamelobby");
-        m.insert(3051, "galaxy-sertver");
-        m.insert(38201, "galaxy7-data");
-        m.insert(8881, "galaxy4d");
-        m.insert(4340, "gaia");
-        m.insert(8074, "gadugadu");
-        m.insert(2678, "gadgetgate2way");
-        m.insert(2677, "gadgetgate1way");
-        m.insert(4110, "g2tag");
-        m.insert(2731, "fyre-messanger");
-        m.insert(286, "fxp");
-        m.insert(3402, "fxaengine-net");
-        m.insert(3272, "usert-manager");
-        m.insert(1514, "fujitsu-dtcns");
-        m.insert(3382, "fujitsu-neat");
-        m.insert(1904, "fjicl-tep-c");
-        m.insert(1902, "fjicl-tep-b");
-        m.insert(3648, "fjcp");
-        m.insert(2975, "fjmpcm");
-        m.insert(574, "ftp-agent");
-        m.insert(8502, "ftnmtp");
-        m.insert(3488, "fs-rh-srv");
-        m.insert(9217, "fsc-port");
-        m.insert(4130, "fronet");
-        m.insert(7726, "freezexsertvice");
-        m.insert(5556, "freeciv");
-        m.insert(7244, "frc-hicp");
-        m.insert(4319, "fox-skytale");
-        m.insert(41111, "fs-qos");
-        m.insert(4411, "found");
-        m.insert(4084, "fortisphere-vm");
-        m.insert(2242, "foliocorp");
-        m.insert(4396, "fly");
-        m.insert(4901, "flr_agent");
-        m.insert(7545, "nta-us");
-        m.insert(7544, "nta-ds");
-        m.insert(27008, "flexlm8");
-        m.insert(27006, "flexlm6");
-        m.insert(27004, "flexlm4");
-        m.insert(5579, "fdtracks");
-        m.insert(2884, "flashmsg");
-        m.insert(3035, "fjsv-gssagt");
-        m.insert(1193, "fiveacross");
-        m.insert(5618, "efr");
-        m.insert(7018, "fisa-svc");
-        m.insert(2673, "firstcall42");
-        m.insert(4086, "ftsync");
-        m.insert(8043, "fs-sertver");
-        m.insert(8044, "fs-mgmt");
-        m.insert(3192, "firemonrcc");
-        m.insert(3729, "fksp-audit");
-        m.insert(1855, "fiorano-rtrsvc");
-        m.insert(1856, "fiorano-msgsvc");
-        m.insert(1784, "finle-lm");
-        m.insert(24922, "find");
-        m.insert(1887, "filex-lport");
-        m.insert(7164, "fsr");
-        m.insert(4349, "fsportmap");
-        m.insert(7394, "fse");
-        m.insert(16021, "jwpc-bin");
-        m.insert(16020, "jwpc");
-        m.insert(6715, "fibotrader-com");
-        m.insert(4915, "frcs");
-        m.insert(4122, "fprams");
-        m.insert(3216, "ferrari-foam");
-        m.insert(14250, "cps");
-        m.insert(3152, "feitianrockey");
-        m.insert(1776, "femis");
-        m.insert(36524, "febooti-aw");
-        m.insert(4320, "fdt-rcatp");
-        m.insert(4727, "fcis");
-        m.insert(3225, "fcip-port");
-        m.insert(2819, "fc-faultnotify");
-        m.insert(4038, "fazzt-ptp");
-        m.insert(6417, "faxcomsertvice");
-        m.insert(347, "fatsertv");
-        m.insert(3047, "hlsertver");
-        m.insert(2495, "fast-rem-sertv");
-        m.insert(10081, "famdc");
-        m.insert(38202, "fairview");
-        m.insert(19790, "faircom-db");
-        m.insert(2515, "facsys-router");
-        m.insert(2514, "facsys-ntp");
-        m.insert(4353, "f5-iquery");
-        m.insert(38472, "f1-control");
-        m.insert(10102, "ezproxy-2");
-        m.insert(4085, "ezmessagesrv");
-        m.insert(3953, "xmlink-connect");
-        m.insert(4788, "xmcp");
-        m.insert(3088, "xdtp");
-        m.insert(3134, "ecp");
-        m.insert(3639, "xap-ha");
-        m.insert(4309, "dsertver");
-        m.insert(2755, "expresspay");
-        m.insert(1928, "emsd-port");
-        m.insert(5075, "pvaccess");
-        m.insert(26486, "exoline-tcp");
-        m.insert(5401, "excerpts");
-        m.insert(3759, "exapt-lmgr");
-        m.insert(43440, "ew-mgmt");
-        m.insert(1926, "egs");
-        m.insert(1982, "estamp");
-        m.insert(1798, "etp");
-        m.insert(9981, "pumpkindb");
-        m.insert(4536, "ehs-ssl");
-        m.insert(4535, "ehs");
-        m.insert(1504, "evb-elm");
-        m.insert(592, "eudora-set");
-        m.insert(1267, "epc");
-        m.insert(6935, "ethoscan");
-        m.insert(2036, "e-dpnet");
-        m.insert(6316, "abb-escp");
-        m.insert(2221, "rockwell-csp1");
-        m.insert(44818, "EtherNetIP-2");
-        m.insert(34980, "ethercat");
-        m.insert(2380, "etcd-sertver");
-        m.insert(2379, "etcd-client");
-        m.insert(6107, "etc-control");
-        m.insert(1772, "essweb-gw");
-        m.insert(8416, "espeech");
-        m.insert(8417, "espeech-rtp");
-        m.insert(8266, "espeasy-p2p");
-        m.insert(4023, "esnm-zoning");
-        m.insert(3629, "escvpnet");
-        m.insert(9617, "erunbook_sertver");
-        m.insert(3679, "newton-dock");
-        m.insert(3727, "e-mdu");
-        m.insert(4942, "eq-office-4942");
-        m.insert(4941, "eq-office-4941");
-        m.insert(4940, "eq-office-4940");
-        m.insert(43439, "eq3-update");
-        m.insert(3628, "ept-machine");
-        m.insert(3620, "ep-pcp");
-        m.insert(5116, "emb-proj-cmd");
-        m.insert(3259, "epncdp2");
-        m.insert(4666, "eportcomm");
-        m.insert(4669, "eportcommdata");
-        m.insert(3819, "epl-slp");
-        m.insert(37601, "eftp");
-        m.insert(5084, "llrp");
-        m.insert(5085, "encrypted-llrp");
-        m.insert(3383, "esp-lm");
-        m.insert(5599, "esinstall");
-        m.insert(5600, "esmmanager");
-        m.insert(5601, "esmagent");
-        m.insert(3665, "ent-engine");
-        m.insert(1818, "etftp");
-        m.insert(3044, "epp");
-        m.insert(1295, "ehtp");
-        m.insert(7962, "generalsync");
-        m.insert(7117, "rothaga");
-        m.insert(121, "erpc");
-        m.insert(17754, "zep");
-        m.insert(6636, "mpls-udp-dtls");
-        m.insert(6635, "mpls-udp");
-        m.insert(20480, "emwavemsg");
-        m.insert(23333, "elxmgmt");
-        m.insert(3585, "emprise-lls");
-        m.insert(6322, "emp-sertver2");
-        m.insert(6321, "emp-sertver1");
-        m.insert(4091, "ewinstaller");
-        m.insert(4092, "ewdgs");
-        m.insert(140, "emfis-data");
-        m.insert(6656, "emgmsg");
-        m.insert(3693, "easl");
-        m.insert(11623, "emc-xsw-dconfig");
-        m.insert(11723, "emc-xsw-dcache");
-        m.insert(13218, "emc-vcas-tcp");
-        m.insert(3682, "cas-mapi");
-        m.insert(3218, "smartpackets");
-        m.insert(9083, "emc-pp-mgmtsvc");
-        m.insert(3197, "embrace-dp-s");
-        m.insert(3198, "embrace-dp-c");
-        m.insert(394, "embl-ndt");
-        m.insert(2526, "ema-sent-lm");
-        m.insert(7700, "em7-secom");
-        m.insert(7707, "sync-em7");
-        m.insert(2916, "elvin_sertver");
-        m.insert(2917, "elvin_client");
-        m.insert(4370, "elpro_tunnel");
-        m.insert(6515, "elipse-rec");
-        m.insert(12010, "edbsrvr");
-        m.insert(5398, "elektron-admin");
-        m.insert(3564, "esimport");
-        m.insert(4346, "elanlm");
-        m.insert(1378, "elan");
-        m.insert(1893, "elad");
-        m.insert(3525, "eisport");
-        m.insert(3638, "ehp-backup");
-        m.insert(2228, "ehome-ms");
-        m.insert(6632, "mxodbc-connect");
-        m.insert(3392, "efi-lm");
-        m.insert(3671, "efcp");
-        m.insert(6159, "efb-aci");
-        m.insert(3462, "track");
-        m.insert(3461, "edm-stager");
-        m.insert(3464, "edm-mgr-sync");
-        m.insert(3465, "edm-mgr-cntrl");
-        m.insert(3460, "edm-manager");
-        m.insert(3463, "edm-adm-notify");
-        m.insert(3123, "edix");
-        m.insert(34567, "dhanalakshmi");
-        m.insert(8149, "eor-game");
-        m.insert(6703, "e-design-web");
-        m.insert(6702, "e-design-net");
-        m.insert(2263, "ecwcfg");
-        m.insert(3477, "ecomm");
-        m.insert(3524, "ecmport");
-        m.insert(6160, "ecmp");
-        m.insert(17729, "ea");
-        m.insert(3711, "edb-sertver2");
-        m.insert(45678, "eba");
-        m.insert(2168, "easy-soft-mux");
-        m.insert(3328, "egptlm");
-        m.insert(38462, "e1-interface");
-        m.insert(3932, "dyn-site");
-        m.insert(3295, "dyniplookup");
-        m.insert(2164, "ddns-v3");
-        m.insert(3395, "dyna-lm");
-        m.insert(2874, "dxmessagebase1");
-        m.insert(3246, "dvt-system");
-        m.insert(3247, "dvt-data");
-        m.insert(4191, "dsmipv6");
-        m.insert(4028, "dtsertver-port");
-        m.insert(3489, "dtp-dia");
-        m.insert(4556, "dtn-bundle-tcp");
-        m.insert(5684, "coaps");
-        m.insert(13929, "dta-systems");
-        m.insert(31685, "dsx_monitor");
-        m.insert(9987, "dsm-scm-target");
-        m.insert(4060, "dsmeter_iatc");
-        m.insert(13819, "dsmcc-session");
-        m.insert(13820, "dsmcc-passthru");
-        m.insert(13821, "dsmcc-download");
-        m.insert(13818, "dsmcc-config");
-        m.insert(13822, "dsmcc-ccp");
-        m.insert(2420, "dslremote-mgmt");
-        m.insert(7547, "cwmp");
-        m.insert(3685, "dsx-agent");
-        m.insert(2193, "drwcs");
-        m.insert(4427, "drizzle");
-        m.insert(1930, "driveappsertver");
-        m.insert(8913, "dragonfly");
-        m.insert(7021, "dpsertveadmin");
-        m.insert(7020, "dpsertve");
-        m.insert(5719, "dpm-agent");
-        m.insert(5565, "hpe-dp-bura");
-        m.insert(5245, "downtools");
-        m.insert(6326, "dt-vra");
-        m.insert(6320, "repsvc");
-        m.insert(6325, "dt-mgmtsvc");
-        m.insert(3522, "nssocketport");
-        m.insert(44544, "domiq");
-        m.insert(13400, "doip-data");
-        m.insert(6088, "doglms");
-        m.insert(3568, "oap-s");
-        m.insert(8567, "oap-admin");
-        m.insert(3567, "oap");
-        m.insert(5567, "m-oap");
-        m.insert(7165, "doc-sertver");
-        m.insert(4142, "oidocsvc");
-        m.insert(3161, "doc1lm");
-        m.insert(5352, "dns-llq");
-        m.insert(195, "dn6-nlm-aud");
-        m.insert(1172, "dnap");
-        m.insert(5993, "cim-rs");
-        m.insert(3199, "dmod-workspace");
-        m.insert(3574, "dmaf-sertver");
-        m.insert(4059, "dlms-cosem");
-        m.insert(1177, "dkmessenger");
-        m.insert(3624, "dist-upgrade");
-        m.insert(19999, "dnp-sec");
-        m.insert(4646, "dots-signal");
-        m.insert(21212, "trinket-agent");
-        m.insert(246, "dsp3270");
-        m.insert(5107, "actifioreplic");
-        m.insert(14002, "scotty-disc");
-        m.insert(7171, "drm-production");
-        m.insert(3448, "dnc-port");
-        m.insert(3336, "directv-tick");
-        m.insert(3335, "directv-soft");
-        m.insert(3337, "directv-catlg");
-        m.insert(198, "dls-mon");
-        m.insert(197, "dls");
-        m.insert(3447, "directnet");
-        m.insert(5031, "dmp");
-        m.insert(4605, "sixchat");
-        m.insert(2464, "direcpc-si");
-        m.insert(2227, "di-msg");
-        m.insert(3223, "digivote");
-        m.insert(1335, "digital-notary");
-        m.insert(2226, "di-drm");
-        m.insert(33333, "dgi-sertv");
-        m.insert(2762, "dicom-tls");
-        m.insert(2761, "dicom-iscl");
-        m.insert(3227, "dwnmshttp");
-        m.insert(3228, "dwmsgsertver");
-        m.insert(33331, "diamondport");
-        m.insert(2861, "dialpad-voice2");
-        m.insert(2860, "dialpad-voice1");
-        m.insert(2098, "dialog-port");
-        m.insert(4301, "d-data");
-        m.insert(3252, "dhe");
-        m.insert(547, "dhcpv6-sertver");
-        m.insert(546, "dhcpv6-client");
-        m.insert(6785, "dgpf-exchg");
-        m.insert(8750, "dey-keyneg");
-        m.insert(4330, "dey-sapi");
-        m.insert(3776, "dvcprov-port");
-        m.insert(24850, "assoc-disc");
-        m.insert(8805, "pfcp");
-        m.insert(2763, "desktop-dna");
-        m.insert(4167, "ddgn");
-        m.insert(2092, "descent3");
-        m.insert(3444, "denali-sertver");
-        m.insert(8415, "dlpx-sp");
-        m.insert(3714, "delos-dms");
-        m.insert(1278, "dellwebadmin-1");
-        m.insert(5700, "supportassist");
-        m.insert(3668, "dell-rm-port");
-        m.insert(7569, "dell-eql-asm");
-        m.insert(365, "dtk");
-        m.insert(8894, "ddi-tcp-7");
-        m.insert(8893, "ddi-tcp-6");
-        m.insert(8891, "ddi-tcp-4");
-        m.insert(8890, "ddi-tcp-3");
-        m.insert(11202, "dcsl-backup");
-        m.insert(3988, "dcs-config");
-        m.insert(1160, "olsv");
-        m.insert(3938, "dbcontrol_agent");
-        m.insert(6117, "daylitetouch");
-        m.insert(6624, "datascaler-db");
-        m.insert(6625, "datascaler-ctl");
-        m.insert(2073, "gxs-data-port");
-        m.insert(461, "datasurfsrv");
-        m.insert(3612, "hp-dataprotect");
-        m.insert(3578, "data-port");
-        m.insert(11109, "sgi-dmfmgr");
-        m.insert(2229, "datalens");
-        m.insert(1775, "vdab");
-        m.insert(2764, "data-insurance");
-        m.insert(3678, "ipr-dglt");
-        m.insert(6511, "dccp-udp");
-        m.insert(1133, "dfn");
-        m.insert(29999, "bingbang");
-        m.insert(2594, "dts");
-        m.insert(3881, "idac");
-        m.insert(3498, "dashpas-port");
-        m.insert(8732, "dtp-net");
-        m.insert(2378, "dali");
-        m.insert(3394, "d2k-tapestry2");
-        m.insert(3393, "d2k-tapestry1");
-        m.insert(2298, "d2k-datamover2");
-        m.insert(2297, "d2k-datamover1");
-        m.insert(9388, "d2ddatatrans");
-        m.insert(9387, "d2dconfig");
-        m.insert(3120, "d2000websertver");
-        m.insert(3297, "cytel-lm");
-        m.insert(1898, "cymtec-port");
-        m.insert(8442, "cybro-a-bus");
-        m.insert(9888, "cyborg-systems");
-        m.insert(4183, "gmmp");
-        m.insert(4673, "cxws");
-        m.insert(3778, "c-h-it-port");
-        m.insert(5271, "cuelink");
-        m.insert(3127, "ctx-bridge");
-        m.insert(1932, "ctt-broker");
-        m.insert(4451, "ctisystemmsg");
-        m.insert(2563, "cti-redwood");
-        m.insert(4452, "ctiprogramload");
-        m.insert(9346, "ctechlicensing");
-        m.insert(7022, "ctdp");
-        m.insert(3631, "cs-sertvices");
-        m.insert(3630, "cs-remote-db");
-        m.insert(105, "csnet-ns");
-        m.insert(3271, "csoft-prev");
-        m.insert(2699, "csoft-plusclnt");
-        m.insert(3004, "csoftragent");
-        m.insert(2129, "cs-live");
-        m.insert(4187, "csc_proxy");
-        m.insert(3113, "cs-auth-svr");
-        m.insert(2314, "cr-websystems");
-        m.insert(8380, "cruise-update");
-        m.insert(8377, "cruise-swroute");
-        m.insert(8376, "cruise-enum");
-        m.insert(8379, "cruise-diags");
-        m.insert(8378, "cruise-config");
-        m.insert(20810, "crtech-nlm");
-        m.insert(3818, "crinis-hb");
-        m.insert(41797, "crestron-ctps");
-        m.insert(41796, "crestron-cips");
-        m.insert(38002, "cresco-control");
-        m.insert(3364, "creativesertver");
-        m.insert(3366, "creativepartnr");
-        m.insert(2824, "cqg-netlan-1");
-        m.insert(2823, "cqg-netlan");
-        m.insert(3609, "cpdi-pidas-cm");
-        m.insert(4055, "ccu-comm-3");
-        m.insert(4054, "ccu-comm-2");
-        m.insert(4053, "ccu-comm-1");
-        m.insert(2654, "corel_vncadmin");
-        m.insert(19220, "cora");
-        m.insert(9093, "copycat");
-        m.insert(3183, "cops-tls");
-        m.insert(2565, "coord-svr");
-        m.insert(4078, "cssp");
-        m.insert(4774, "converge");
-        m.insert(2153, "ctlptc");
-        m.insert(17222, "cpsp");
-        m.insert(7551, "controlone-con");
-        m.insert(7563, "cfw");
-        m.insert(3072, "csd-monitor");
-        m.insert(4047, "ctp-state");
-        m.insert(9695, "ccnx");
-        m.insert(4846, "contamac_icm");
-        m.insert(5992, "consul-insight");
-        m.insert(5683, "coap");
-        m.insert(4692, "conspiracy");
-        m.insert(3191, "csvr-sslproxy");
-        m.insert(3417, "csvr");
-        m.insert(7169, "ccag-pib");
-        m.insert(3973, "progistics");
-        m.insert(46998, "spremotetablet");
-        m.insert(16384, "connected");
-        m.insert(3947, "ccp");
-        m.insert(47100, "jvl-mactalk");
-        m.insert(6970, "conductor");
-        m.insert(2491, "conclave-cpp");
-        m.insert(7023, "ct2nmcs");
-        m.insert(10321, "cosir");
-        m.insert(42508, "candp");
-        m.insert(3822, "acp-discovery");
-        m.insert(2417, "composit-sertver");
-        m.insert(2555, "compaq-wcp");
-        m.insert(3257, "cpqrpm-sertver");
-        m.insert(3256, "cpqrpm-agent");
-        m.insert(22343, "cis-secure");
-        m.insert(64, "covia");
-        m.insert(7215, "PS-sertver");
-        m.insert(20003, "commtact-https");
-        m.insert(4450, "camp");
-        m.insert(3751, "gprs-cube");
-        m.insert(3605, "comcam-io");
-        m.insert(2534, "combox-web-acc");
-        m.insert(3490, "colubris");
-        m.insert(4419, "cbp");
-        m.insert(7689, "collaber");
-        m.insert(21213, "cohesity-agent");
-        m.insert(7574, "coherence");
-        m.insert(3377, "cogsys-lm");
-        m.insert(3779, "cognima");
-        m.insert(44444, "cognex-dataman");
-        m.insert(3039, "cogitate");
-        m.insert(2415, "codima-rtp");
-        m.insert(2183, "cgn-config");
-        m.insert(26257, "cockroach");
-        m.insert(3576, "cmc-port");
-        m.insert(3575, "ccm-port");
-        m.insert(2976, "cns-srv-port");
-        m.insert(7168, "cnckadsertver");
-        m.insert(8501, "cmtp-mgt");
-        m.insert(164, "cmip-agent");
-        m.insert(3384, "hp-clic");
-        m.insert(7550, "cloudsignaling");
-        m.insert(45514, "cloudcheck");
-        m.insert(356, "cloanto-net-1");
-        m.insert(2617, "cmadmin");
-        m.insert(3730, "client-ctrl");
-        m.insert(6688, "clever-tcpip");
-        m.insert(6687, "clever-ctrace");
-        m.insert(6690, "cleverdetect");
-        m.insert(7683, "dmt");
-        m.insert(2052, "clearvisn");
-        m.insert(3481, "cleanerliverc");
-        m.insert(4136, "cl-db-request");
-        m.insert(4137, "cl-db-remote");
-        m.insert(9087, "classic");
-        m.insert(172, "cl-1");
-        m.insert(1729, "citynl");
-        m.insert(4980, "ctxs-vpp");
-        m.insert(7229, "citrixuppg");
-        m.insert(7228, "citrixupp");
-        m.insert(24754, "cslg");
-        m.insert(2897, "citrix-rtmp");
-        m.insert(7279, "ctxlic");
-        m.insert(2512, "citrixima");
-        m.insert(2513, "citrixadmin");
-        m.insert(4870, "cc-tracking");
-        m.insert(22305, "wnn6_Kr");
-        m.insert(5787, "waascluster");
-        m.insert(6633, "cisco-vpath-tun");
-        m.insert(131, "cisco-tna");
-        m.insert(15555, "cisco-snat");
-        m.insert(4051, "cppdp");
-        m.insert(4785, "cncp");
-        m.insert(43441, "ciscocsdb");
-        m.insert(5784, "ibar");
-        m.insert(7546, "cfs");
-        m.insert(8017, "cisco-cloudsec");
-        m.insert(3887, "ciphire-data");
-        m.insert(5194, "cpscomm");
-        m.insert(1743, "cinegrfx-lm");
-        m.insert(2891, "cinegrfx-elmd");
-        m.insert(3770, "cindycollab");
-        m.insert(1377, "cichlid");
-        m.insert(4316, "choiceview-clt");
-        m.insert(4314, "choiceview-agt");
-        m.insert(3099, "chmd");
-        m.insert(1572, "chip-lm");
-        m.insert(39063, "vroa");
-        m.insert(1891, "childkey-notif");
-        m.insert(1892, "childkey-ctrl");
-        m.insert(3349, "chevinsertvices");
-        m.insert(18241, "checkpoint-rtm");
-        m.insert(18243, "clusterxl");
-        m.insert(18242, "iclid");
-        m.insert(18185, "opsec-omi");
-        m.insert(5505, "checkoutdb");
-        m.insert(6556, "checkmk-agent");
-        m.insert(562, "chshell");
-        m.insert(531, "conference");
-        m.insert(3772, "ctp");
-        m.insert(5065, "ca-2");
-        m.insert(5064, "ca-1");
-        m.insert(2182, "cgn-stat");
-        m.insert(3893, "cgi-starapi");
-        m.insert(2921, "cesdcdman");
-        m.insert(2922, "cesdcdtrn");
-        m.insert(4074, "cequint-cityid");
-        m.insert(4140, "cedros_fds");
-        m.insert(4115, "cds");
-        m.insert(3056, "cdl-sertver");
-        m.insert(3616, "cd3o-protocol");
-        m.insert(3559, "cctv-port");
-        m.insert(4970, "ccss-qsm");
-        m.insert(4969, "ccss-qmm");
-        m.insert(3114, "ccmad");
-        m.insert(3157, "e3consultants");
-        m.insert(3750, "cbos-ip-port");
-        m.insert(12168, "cawas");
-        m.insert(2122, "caupc-remote");
-        m.insert(7129, "scenccs");
-        m.insert(7162, "caistoragemgr");
-        m.insert(7167, "casrmagent");
-        m.insert(5270, "xmp");
-        m.insert(1197, "carrius-rshell");
-        m.insert(9060, "CardWeb-IO");
-        m.insert(3106, "cardbox-http");
-        m.insert(5247, "capwap-data");
-        m.insert(5246, "capwap-control");
-        m.insert(3290, "caps-lm");
-        m.insert(4728, "capmux");
-        m.insert(8998, "canto-roboflow");
-        m.insert(8610, "canon-mfnp");
-        m.insert(8609, "canon-cpp-disc");
-        m.insert(3756, "canon-capt");
-        m.insert(8614, "canon-bjnp4");
-        m.insert(8613, "canon-bjnp3");
-        m.insert(8612, "canon-bjnp2");
-        m.insert(8611, "canon-bjnp1");
-        m.insert(1872, "canocentral1");
-        m.insert(3583, "canex-watch");
-        m.insert(24676, "canditv");
-        m.insert(4377, "cp-spxsvr");
-        m.insert(5079, "cp-spxrpts");
-        m.insert(4378, "cp-spxdpy");
-        m.insert(1734, "cambertx-lm");
-        m.insert(3545, "camac");
-        m.insert(7262, "cnap");
-        m.insert(3675, "calltrax");
-        m.insert(2552, "call-logging");
-        m.insert(22537, "caldsoft-backup");
-        m.insert(3709, "ca-idms");
-        m.insert(14414, "ca-web-update");
-        m.insert(5251, "caevms");
-        m.insert(1882, "ecsqdmn");
-        m.insert(42509, "candrp");
-        m.insert(2318, "cadencecontrol");
-        m.insert(4326, "geognosis");
-        m.insert(1563, "cadabra-lm");
-        m.insert(7163, "cacsambroker");
-        m.insert(1554, "caci-lm");
-        m.insert(7161, "cabsm-comm");
-        m.insert(595, "cab-protocol");
-        m.insert(348, "csi-sgwp");
-        m.insert(282, "cableport-ax");
-        m.insert(8026, "ca-audit-ds");
-        m.insert(5249, "caaclang2");
-        m.insert(5248, "caacws");
-        m.insert(5154, "bzflag");
-        m.insert(10880, "bveapi");
-        m.insert(3626, "bvcdaemon-port");
-        m.insert(4990, "busycal");
-        m.insert(3107, "business");
-        m.insert(6410, "boe-resssvr4");
-        m.insert(6409, "boe-resssvr3");
-        m.insert(6408, "boe-resssvr2");
-        m.insert(6407, "boe-resssvr1");
-        m.insert(6406, "boe-processsvr");
-        m.insert(6405, "boe-pagesvr");
-        m.insert(6404, "boe-filesvr");
-        m.insert(4677, "bcs");
-        m.insert(581, "bdp");
-        m.insert(4671, "acter");
-        m.insert(2964, "bullant-srap");
-        m.insert(2965, "bullant-rap");
-        m.insert(28589, "bosswave");
-        m.insert(47808, "bacnet");
-        m.insert(3966, "bflckmgr");
-        m.insert(2446, "bues_sertvice");
-        m.insert(1854, "buddy-draw");
-        m.insert(1961, "bts-appsertver");
-        m.insert(2444, "btpp2sectrans");
-        m.insert(2277, "bluectrlproxy");
-        m.insert(4175, "bccp");
-        m.insert(3188, "brcm-comm-port");
-        m.insert(3043, "brp");
-        m.insert(9380, "boxp");
-        m.insert(3692, "intelsync");
-        m.insert(5682, "brightcore");
-        m.insert(2155, "brdptc");
-        m.insert(4104, "brlp-3");
-        m.insert(4103, "brlp-2");
-        m.insert(4102, "brlp-1");
-        m.insert(3593, "bpmd");
-        m.insert(2845, "bpcp-trap");
-        m.insert(2844, "bpcp-poll");
-        m.insert(4186, "boxbackupstore");
-        m.insert(2218, "bounzza");
-        m.insert(4678, "traversal");
-        m.insert(2017, "cypress-stat");
-        m.insert(2913, "boosterware");
-        m.insert(7648, "cuseeme");
-        m.insert(4914, "bones");
-        m.insert(7687, "bolt");
-        m.insert(6501, "boks_sertvc");
-        m.insert(9750, "board-voip");
-        m.insert(3344, "bnt-manager");
-        m.insert(1896, "b-novative-ls");
-        m.insert(4568, "bmc-reporting");
-        m.insert(10128, "bmc-perf-sd");
-        m.insert(6768, "bmc-perf-mgrd");
-        m.insert(6767, "bmc-perf-agent");
-        m.insert(3182, "bmcpatrolrnvu");
-        m.insert(1313, "bmc_patroldb");
-        m.insert(3181, "bmcpatrolagent");
-        m.insert(2059, "bmc-messaging");
-        m.insert(3604, "bmc-jmx-port");
-        m.insert(6300, "bmc-grx");
-        m.insert(10129, "bmc-gms");
-        m.insert(3695, "bmc-data-coll");
-        m.insert(6301, "bmc_ctd_ldap");
-        m.insert(2494, "bmc-ar");
-        m.insert(2625, "blwnkl-port");
-        m.insert(48129, "blp5");
-        m.insert(8195, "blp2");
-        m.insert(2574, "blockade-bpsp");
-        m.insert(5750, "rscd");
-        m.insert(13823, "bmdss");
-        m.insert(13216, "bcslogc");
-        m.insert(4027, "bxp");
-        m.insert(5068, "bitforestsrv");
-        m.insert(25955, "bf-master");
-        m.insert(25954, "bf-game");
-        m.insert(6946, "biosertver");
-        m.insert(3411, "biolink-auth");
-        m.insert(24577, "bilobit");
-        m.insert(5429, "base");
-        m.insert(4621, "ventoso");
-        m.insert(6784, "bfd-lag");
-        m.insert(4676, "dhct-alerts");
-        m.insert(4675, "dhct-status");
-        m.insert(4784, "bfd-multi-ctl");
-        m.insert(3785, "bfd-echo");
-        m.insert(5425, "br-channel");
-        m.insert(5424, "beyond-remote");
-        m.insert(4305, "batman");
-        m.insert(3960, "bess");
-        m.insert(3408, "BESApi");
-        m.insert(5584, "bis-web");
-        m.insert(5585, "bis-sync");
-        m.insert(1943, "beeyond-media");
-        m.insert(3124, "beacon-port");
-        m.insert(6508, "bdir_pub");
-        m.insert(6507, "bdir_priv");
-        m.insert(4155, "bzr");
-        m.insert(1120, "bnetfile");
-        m.insert(1929, "bandwiz-system");
-        m.insert(4324, "balour");
-        m.insert(10439, "bngsync");
-        m.insert(6506, "badm_pub");
-        m.insert(6505, "badm_priv");
-        m.insert(6122, "bex-webadmin");
-        m.insert(4971, "burp");
-        m.insert(3387, "backroomnet");
-        m.insert(152, "bftp");
-        m.insert(2635, "backburner");
-        m.insert(2169, "brain");
-        m.insert(6696, "babel");
-        m.insert(2204, "b2-license");
-        m.insert(3512, "aztec");
-        m.insert(2071, "acp-port");
-        m.insert(10260, "axis-wimp-port");
-        m.insert(35100, "axio-disc");
-        m.insert(4195, "aws-wsp");
-        m.insert(3277, "awg-proxy");
-        m.insert(3502, "avinstalldisc");
-        m.insert(2066, "aura");
-        m.insert(2238, "aviva-sna");
-        m.insert(4413, "avi-nms");
-        m.insert(20057, "avesterra");
-        m.insert(2992, "avenyo");
-        m.insert(2050, "av-emb-config");
-        m.insert(3965, "ati-ip-to-ncpe");
-        m.insert(10990, "rmiaux");
-        m.insert(31020, "autotrac-acp");
-        m.insert(4685, "autopac");
-        m.insert(1140, "autonoc");
-        m.insert(7508, "adcp");
-        m.insert(16003, "alfin");
-        m.insert(4071, "aibkup");
-        m.insert(3104, "autocuelog");
-        m.insert(3437, "autocueds");
-        m.insert(5067, "authentx");
-        m.insert(33123, "aurora-balaena");
-        m.insert(1146, "audit-transfer");
-        m.insert(44600, "asihpi");
-        m.insert(2264, "apx500api-1");
-        m.insert(7543, "atul");
-        m.insert(2419, "attachmate-s2s");
-        m.insert(32896, "idmgratm");
-        m.insert(2317, "attachmate-g32");
-        m.insert(3821, "pmcp");
-        m.insert(4937, "atsc-mh-ssc");
-        m.insert(1520, "atm-zip-office");
-        m.insert(11367, "atm-uhas");
-        m.insert(4154, "atlinks");
-        m.insert(3617, "sharp-sertver");
-        m.insert(20999, "athand-mmp");
-        m.insert(1170, "atc-lm");
-        m.insert(1171, "atc-appsertver");
-        m.insert(2864, "astromed-main");
-        m.insert(27876, "astrolink");
-        m.insert(4485, "assyst-dr");
-        m.insert(4704, "assuria-ins");
-        m.insert(7235, "aspcoordination");
-        m.insert(3087, "asoki-sma");
-        m.insert(45000, "asmp");
-        m.insert(4405, "ds-mail");
-        m.insert(4404, "ds-admin");
-        m.insert(4406, "ds-slp");
-        m.insert(4402, "ds-clnt");
-        m.insert(4403, "ds-usert");
-        m.insert(4400, "ds-srv");
-        m.insert(5727, "asgenf");
-        m.insert(11489, "asgcypresstcps");
-        m.insert(2192, "asdis");
-        m.insert(4077, "ascomalarm");
-        m.insert(4448, "asc-slmd");
-        m.insert(3581, "kfxaclicensing");
-        m.insert(5150, "atmp");
-        m.insert(13702, "netbackup");
-        m.insert(3451, "asam");
-        m.insert(386, "asa");
-        m.insert(8211, "aruba-papi");
-        m.insert(7166, "aruba-sertver");
-        m.insert(3518, "artifact-msg");
-        m.insert(27782, "ars-vista");
-        m.insert(3176, "ars-master");
-        m.insert(9292, "armtechdaemon");
-        m.insert(3174, "armi-sertver");
-        m.insert(9295, "armcenterhttps");
-        m.insert(9294, "armcenterhttp");
-        m.insert(3426, "arkivio");
-        m.insert(8423, "aritts");
-        m.insert(3140, "ariliamulti");
-        m.insert(7570, "aries-kfinder");
-        m.insert(421, "ariel2");
-        m.insert(2114, "newheights");
-        m.insert(6344, "streletz");
-        m.insert(2581, "argis-te");
-        m.insert(2582, "argis-ds");
-        m.insert(11321, "arena-sertver");
-        m.insert(384, "arns");
-        m.insert(23546, "areaguard-neo");
-        m.insert(1834, "ardusuni");
-        m.insert(1115, "ardus-trns");
-        m.insert(4165, "altcp");
-        m.insert(1557, "arbortext-lm");
-        m.insert(3758, "apw-registry");
-        m.insert(7847, "csoauth");
-        m.insert(5086, "aprigo-cs");
-        m.insert(4849, "appsertv-https");
-        m.insert(2037, "applus");
-        m.insert(1447, "apri-lm");
-        m.insert(3312, "appman-sertver");
-        m.insert(187, "aci");
-        m.insert(4488, "awacs-ice");
-        m.insert(2336, "appleugcontrol");
-        m.insert(387, "aurp");
-        m.insert(208, "at-8");
-        m.insert(207, "at-7");
-        m.insert(203, "at-3");
-        m.insert(3454, "mira");
-        m.insert(10548, "sertverdocs");
-        m.insert(4674, "appiq-mgmt");
-        m.insert(38203, "agpolicy");
-        m.insert(3239, "apparenet-ui");
-        m.insert(3236, "apparenet-ts");
-        m.insert(3237, "apparenet-tps");
-        m.insert(3238, "apparenet-as");
-        m.insert(4573, "cardifftec-back");
-        m.insert(2758, "apollo-status");
-        m.insert(10252, "apollo-relay");
-        m.insert(2759, "apollo-gms");
-        m.insert(8121, "apollo-data");
-        m.insert(2754, "apollo-cc");
-        m.insert(8122, "apollo-admin");
-        m.insert(3184, "apogeex-port");
-        m.insert(539, "apertus-ldp");
-        m.insert(6082, "p25cai");
-        m.insert(18888, "apc-necmp");
-        m.insert(9952, "apc-9952");
-        m.insert(9951, "apc-9951");
-        m.insert(7846, "apc-7846");
-        m.insert(7845, "apc-7845");
-        m.insert(6549, "apc-6549");
-        m.insert(5456, "apc-5456");
-        m.insert(5455, "apc-5455");
-        m.insert(5454, "apc-5454");
-        m.insert(4851, "derby-repli");
-        m.insert(5913, "ads-c");
-        m.insert(5072, "ayiya");
-        m.insert(3939, "aamp");
-        m.insert(2247, "antidotemgrsvr");
-        m.insert(1206, "anthony-data");
-        m.insert(3715, "anoto-rendezv");
-        m.insert(2646, "and-lm");
-        m.insert(3054, "amt-cnf-prot");
-        m.insert(5671, "amqps");
-        m.insert(8040, "ampify");
-        m.insert(376, "nip");
-        m.insert(2640, "sai_sentlm");
-        m.insert(30004, "amicon-fpsu-s");
-        m.insert(30003, "amicon-fpsu-ra");
-        m.insert(5192, "aol-2");
-        m.insert(4393, "apwi-rxspooler");
-        m.insert(4392, "apwi-rxsertver");
-        m.insert(4391, "apwi-imsertver");
-        m.insert(4394, "apwi-disc");
-        m.insert(1931, "amdsched");
-        m.insert(5506, "amc");
-        m.insert(8301, "amberon");
-        m.insert(4563, "amahi-anywhere");
-        m.insert(35355, "altova-lm");
-        m.insert(4011, "altsertviceboot");
-        m.insert(7799, "altbsdp");
-        m.insert(3265, "altav-tunnel");
-        m.insert(9209, "almobile-system");
-        m.insert(693, "connendp");
-        m.insert(36001, "allpeers");
-        m.insert(9956, "alljoyn");
-        m.insert(9955, "alljoyn-stm");
-        m.insert(6627, "nexgen");
-        m.insert(3234, "alchemy");
-        m.insert(2667, "alarm-clock-s");
-        m.insert(2668, "alarm-clock-c");
-        m.insert(3613, "alaris-disc");
-        m.insert(4804, "aja-ntv4-disc");
-        m.insert(2887, "aironetddp");
-        m.insert(3416, "commandport");
-        m.insert(3833, "aipn-auth");
-        m.insert(9216, "acme");
-        m.insert(2846, "aimpp-hello");
-        m.insert(17555, "ailith");
-        m.insert(2786, "aic-oncrpc");
-        m.insert(3316, "aicc-cmi");
-        m.insert(3021, "agrisertver");
-        m.insert(3026, "agri-gateway");
-        m.insert(4878, "inst-discovery");
-        m.insert(3917, "aftmux");
-        m.insert(4362, "afore-vdp-disc");
-        m.insert(7775, "inetfs");
-        m.insert(3224, "aes-discovery");
-        m.insert(23457, "aequus-alt");
-        m.insert(23456, "aequus");
-        m.insert(4549, "aegate");
-        m.insert(4431, "wspipe");
-        m.insert(2295, "advant-lm");
-        m.insert(3573, "tag-ups-1");
-        m.insert(5073, "tag-pm");
-        m.insert(3760, "adtempusclient");
-        m.insert(3357, "adtech-test");
-        m.insert(3954, "adrep");
-        m.insert(3705, "adobesertver-5");
-        m.insert(3704, "adobesertver-4");
-        m.insert(2692, "admins-lms");
-        m.insert(6769, "adi-gxp-srvprt");
-        m.insert(33890, "digilent-adept");
-        m.insert(7170, "nsrp");
-        m.insert(2521, "adaptecmgr");
-        m.insert(2085, "ada-cip");
-        m.insert(3096, "ndl-aps");
-        m.insert(2810, "netsteward");
-        m.insert(2859, "activememory");
-        m.insert(3431, "ndl-als");
-        m.insert(9389, "adws");
-        m.insert(3655, "abatemgr");
-        m.insert(5106, "actifioudsagent");
-        m.insert(5103, "actifio-c2c");
-        m.insert(44445, "acronis-backup");
-        m.insert(7509, "acplt");
-        m.insert(6801, "acnet");
-        m.insert(4013, "acl-manager");
-        m.insert(2476, "ace-svr-prop");
-        m.insert(2475, "ace-sertver");
-        m.insert(2334, "ace-client");
-        m.insert(12007, "accuracer");
-        m.insert(12008, "accuracer-dbms");
-        m.insert(6868, "acctopus-cc");
-        m.insert(4046, "acp-proto");
-        m.insert(18463, "ac-cluster");
-        m.insert(32483, "apm-link");
-        m.insert(4030, "jdmn-port");
-        m.insert(8793, "acd-pm");
-        m.insert(2259, "acd-pm");
-        m.insert(62, "acas");
-        m.insert(1955, "abr-secure");
-        m.insert(3781, "abcvoice-port");
-        m.insert(3619, "aairnet-2");
-        m.insert(3618, "aairnet-1");
-        m.insert(28119, "a27-ran-ran");
-        m.insert(4726, "a26-fap-fgw");
-        m.insert(4502, "a25-fap-fgw");
-        m.insert(4597, "a21-an-1xbs");
-        m.insert(4598, "a16-an-an");
-        m.insert(3598, "a15");
-        m.insert(3597, "a14");
-        m.insert(3125, "a13-an");
-        m.insert(4149, "agslb");
-        m.insert(9953, "acis");
-        m.insert(23294, "5afe-dir");
-        m.insert(2933, "4-tieropmgw");
-        m.insert(2934, "4-tieropmcli");
-        m.insert(5783, "3par-mgmt-ssl");
-        m.insert(5782, "3par-mgmt");
-        m.insert(5785, "3par-rcopy");
-        m.insert(5781, "3par-evts");
-        m.insert(15363, "3link");
-        m.insert(48049, "3gpp-cbsp");
-        m.insert(2339, "3com-webview");
-        m.insert(5265, "3com-njack-2");
-        m.insert(5264, "3com-njack-1");
-        m.insert(1181, "3comnetman");
-        m.insert(3446, "3comfaxrpc");
-        m.insert(3428, "twcss");
-        m.insert(15998, "2ping");
-        m.insert(3091, "1ci-smcs");
-        m.insert(2133, "zymed-zpp");
-        m.insert(3774, "zicom");
-        m.insert(317, "zannet");
-        m.insert(3832, "xxnetsertver");
-        m.insert(508, "xvttp");
-        m.insert(3721, "xsync");
-        m.insert(1619, "xs-openstorage");
-        m.insert(1716, "xmsg");
-        m.insert(2279, "xmquery");
-        m.insert(3412, "xmlblaster");
-        m.insert(2327, "xingcsm");
-        m.insert(6558, "xdsxdm");
-        m.insert(2130, "xds");
-        m.insert(1760, "www-ldap-gw");
-        m.insert(5413, "wwiotalk");
-        m.insert(2396, "wusage");
-        m.insert(2923, "wta-wsp-wtp-s");
-        m.insert(3378, "wsicopy");
-        m.insert(3466, "workflow");
-        m.insert(2504, "wlbs");
-        m.insert(2720, "wkars");
-        m.insert(4871, "wired");
-        m.insert(7395, "winqedit");
-        m.insert(3926, "winport");
-        m.insert(1727, "winddx");
-        m.insert(1326, "wimsic");
-        m.insert(2518, "willy");
-        m.insert(1890, "wilkenlistener");
-        m.insert(2781, "whosells");
-        m.insert(565, "whoami");
-        m.insert(4984, "webyast");
-        m.insert(3342, "webtie");
-        m.insert(21845, "webphone");
-        m.insert(1963, "webmachine");
-        m.insert(2851, "webemshttp");
-        m.insert(3748, "webdata");
-        m.insert(1739, "webaccess");
-        m.insert(1269, "watilapp");
-        m.insert(2455, "wago-io-system");
-        m.insert(2547, "vytalvaultvsmp");
-        m.insert(2548, "vytalvaultpipe");
-        m.insert(2546, "vytalvaultbrtp");
-        m.insert(13882, "vunknown");
-        m.insert(7779, "vstat");
-        m.insert(2695, "vspread");
-        m.insert(312, "vslmp");
-        m.insert(2996, "vsixml");
-        m.insert(2893, "vseconnector");
-        m.insert(1589, "vqp");
-        m.insert(2649, "vpsipport");
-        m.insert(1224, "vpnz");
-        m.insert(1345, "vpjp");
-        m.insert(3625, "volley");
-        m.insert(2538, "vnwk-prapi");
-        m.insert(3321, "vnsstr");
-        m.insert(175, "vmnet");
-        m.insert(1868, "viziblebrowsert");
-        m.insert(4344, "vinainstall");
-        m.insert(1853, "vids-avtp");
-        m.insert(3058, "videobeans");
-        m.insert(3802, "vhd");
-        m.insert(78, "vettcp");
-        m.insert(2770, "veronica");
-        m.insert(3270, "verismart");
-        m.insert(575, "vemmi");
-        m.insert(1771, "vaultbase");
-        m.insert(4839, "varadero-2");
-        m.insert(4838, "varadero-1");
-        m.insert(4837, "varadero-0");
-        m.insert(671, "vacdsm-app");
-        m.insert(430, "utmpsd");
-        m.insert(431, "utmpcd");
-        m.insert(2745, "urbisnet");
-        m.insert(2648, "upsnotifyprot");
-        m.insert(3356, "upnotifyps");
-        m.insert(1957, "unix-status");
-        m.insert(2820, "univision");
-        m.insert(1978, "unisql");
-        m.insert(2927, "unimobilectrl");
-        m.insert(2499, "unicontrol");
-        m.insert(2437, "unicontrol");
-        m.insert(2138, "unbind-cluster");
-        m.insert(2110, "umsp");
-        m.insert(1797, "uma");
-        m.insert(1737, "ultimad");
-        m.insert(483, "ulpnet");
-        m.insert(390, "uis");
-        m.insert(1867, "udrive");
-        m.insert(1624, "udp-sr-port");
-        m.insert(1833, "udpradio");
-        m.insert(2879, "ucentric-ds");
-        m.insert(2767, "uadtc");
-        m.insert(2768, "uacs");
-        m.insert(2943, "ttnrepository");
-        m.insert(1568, "tsspmap");
-        m.insert(2489, "tsilb");
-        m.insert(1237, "tsdos390");
-        m.insert(2741, "tsb");
-        m.insert(2742, "tsb2");
-        m.insert(8804, "truecm");
-        m.insert(1588, "triquest-lm");
-        m.insert(6069, "trip");
-        m.insert(1869, "transact");
-        m.insert(2642, "tragic");
-        m.insert(20670, "track");
-        m.insert(594, "tpip");
-        m.insert(2885, "topflow");
-        m.insert(2669, "toad");
-        m.insert(476, "tn-tl-fd1");
-        m.insert(2798, "tmesis-upshot");
-        m.insert(3083, "tl1-telnet");
-        m.insert(3082, "tl1-raw");
-        m.insert(3081, "tl1-lv");
-        m.insert(2361, "tl1");
-        m.insert(5104, "tinymessage");
-        m.insert(1758, "tftp-mcast");
-        m.insert(7491, "telops-lmd");
-        m.insert(1728, "telindus");
-        m.insert(5428, "telaconsole");
-        m.insert(1946, "tekpls");
-        m.insert(559, "teedtap");
-        m.insert(1610, "taurus-wh");
-        m.insert(3144, "tarantella");
-        m.insert(1922, "tapestry");
-        m.insert(2726, "tams");
-        m.insert(6149, "tal-pod");
-        m.insert(1838, "talnet");
-        m.insert(4014, "taiclock");
-        m.insert(1274, "t1distproc");
-        m.insert(2647, "syncsertver");
-        m.insert(4106, "synchronite");
-        m.insert(6102, "synchronet-upd");
-        m.insert(4548, "synchromesh");
-        m.insert(19540, "sxuptp");
-        m.insert(1866, "swrmi");
-        m.insert(6965, "swistrap");
-        m.insert(6966, "swispol");
-        m.insert(6964, "swismgr2");
-        m.insert(6963, "swismgr1");
-        m.insert(1751, "swiftnet");
-        m.insert(1625, "svs-omagent");
-        m.insert(5453, "surebox");
-        m.insert(2709, "supermon");
-        m.insert(7967, "supercell");
-        m.insert(3354, "suitjd");
-        m.insert(566, "streettalk");
-        m.insert(4178, "storman");
-        m.insert(2986, "stonefalls");
-        m.insert(1226, "stgxfws");
-        m.insert(1836, "ste-smsc");
-        m.insert(1654, "stargatealerts");
-        m.insert(2838, "starbot");
-        m.insert(1692, "sstsys-lm");
-        m.insert(3644, "ssowatch");
-        m.insert(6071, "ssdtp");
-        m.insert(477, "ss7ns");
-        m.insert(478, "spsc");
-        m.insert(2507, "spock");
-        m.insert(1923, "spice");
-        m.insert(3193, "spandataport");
-        m.insert(2653, "sonus");
-        m.insert(2636, "solve");
-        m.insert(1621, "softdataphone");
-        m.insert(3379, "socorfs");
-        m.insert(2533, "sniffersertver");
-        m.insert(2892, "snifferdata");
-        m.insert(2452, "snifferclient");
-        m.insert(1684, "snaresecure");
-        m.insert(2333, "snapp");
-        m.insert(22000, "snapenetio");
-        m.insert(1553, "sna-cs");
-        m.insert(3536, "snac");
-        m.insert(11201, "smsqp");
-        m.insert(2775, "smpp");
-        m.insert(2942, "sm-pas-5");
-        m.insert(2941, "sm-pas-4");
-        m.insert(2940, "sm-pas-3");
-        m.insert(2939, "sm-pas-2");
-        m.insert(2938, "sm-pas-1");
-        m.insert(2613, "smntubootstrap");
-        m.insert(426, "smartsdp");
-        m.insert(4116, "smartcard-tls");
-        m.insert(4412, "smallchat");
-        m.insert(1966, "slush");
-        m.insert(3065, "slinterbase");
-        m.insert(1225, "slinkysearch");
-        m.insert(1705, "slingshot");
-        m.insert(1618, "skytelnet");
-        m.insert(1660, "skip-mc-gikreq");
-        m.insert(2545, "sis-emt");
-        m.insert(2676, "simslink");
-        m.insert(3687, "simple-push");
-        m.insert(2756, "simplement-tie");
-        m.insert(1599, "simbasertvices");
-        m.insert(2832, "silkp4");
-        m.insert(2831, "silkp3");
-        m.insert(2830, "silkp2");
-        m.insert(2829, "silkp1");
-        m.insert(5461, "silkmeter");
-        m.insert(2974, "signal");
-        m.insert(498, "siam");
-        m.insert(1626, "shockwave");
-        m.insert(3595, "shareapp");
-        m.insert(160, "sgmp-traps");
-        m.insert(153, "sgmp");
-        m.insert(3326, "sftu");
-        m.insert(1714, "sesi-lm");
-        m.insert(3172, "sertverview-rm");
-        m.insert(3173, "sertverview-icc");
-        m.insert(3171, "sertverview-gf");
-        m.insert(3170, "sertverview-asn");
-        m.insert(3169, "sertverview-as");
-        m.insert(2235, "sertcomm-wlink");
-        m.insert(6108, "sertcomm-scadmin");
-        m.insert(169, "send");
-        m.insert(5399, "securitychase");
-        m.insert(2471, "seaodbc");
-        m.insert(558, "sdnskmp");
-        m.insert(2308, "sdhelp");
-        m.insert(1681, "sd-elmd");
-        m.insert(2385, "sd-data");
-        m.insert(3562, "sdbproxy");
-        m.insert(5024, "scpi-telnet");
-        m.insert(5025, "scpi-raw");
-        m.insert(5427, "sco-peer-tta");
-        m.insert(3391, "savant");
-        m.insert(3744, "sasg");
-        m.insert(1646, "sa-msg-port");
-        m.insert(3275, "samd");
-        m.insert(3698, "sagectlpanel");
-        m.insert(2390, "rsmtp");
-        m.insert(1793, "rsc-robot");
-        m.insert(1647, "rsap");
-        m.insert(1697, "rrisat");
-        m.insert(1693, "rrirtr");
-        m.insert(1695, "rrilwm");
-        m.insert(1696, "rrifmm");
-        m.insert(2919, "roboer");
-        m.insert(9599, "robix");
-        m.insert(2423, "rnrp");
-        m.insert(3844, "rnm");
-        m.insert(2959, "rmopagt");
-        m.insert(2818, "rmlnk");
-        m.insert(1817, "rkb-oscs");
-        m.insert(521, "ripng");
-        m.insert(3147, "rfio");
-        m.insert(3163, "res-sap");
-        m.insert(2886, "responselogic");
-        m.insert(283, "rescap");
-        m.insert(2837, "repliweb");
-        m.insert(2543, "reftek");
-        m.insert(2928, "redstone-cpss");
-        m.insert(2240, "recipe");
-        m.insert(1343, "re101");
-        m.insert(2321, "rdlap");
-        m.insert(3467, "rcst");
-        m.insert(9753, "rasadv");
-        m.insert(1530, "rap-sertvice");
-        m.insert(2872, "radix");
-        m.insert(1595, "radio");
-        m.insert(2900, "quicksuite");
-        m.insert(1341, "qubes");
-        m.insert(2935, "qtp");
-        m.insert(3059, "qsoft");
-        m.insert(2724, "qotps");
-        m.insert(3385, "qnxnetman");
-        m.insert(2765, "qip-audup");
-        m.insert(368, "qbikgdp");
-        m.insert(2461, "qadmifoper");
-        m.insert(2462, "qadmifevent");
-        m.insert(1253, "q55-pcc");
-        m.insert(2680, "pxc-sapxom");
-        m.insert(3009, "pxc-ntfy");
-        m.insert(2434, "pxc-epmap");
-        m.insert(2694, "pwrsevent");
-        m.insert(2351, "psrsertver");
-        m.insert(2353, "pspsertver");
-        m.insert(2354, "psprsertver");
-        m.insert(1788, "psmond");
-        m.insert(2352, "pslsertver");
-        m.insert(3662, "psertver");
-        m.insert(2355, "psdbsertver");
-        m.insert(2091, "prp");
-        m.insert(1732, "proxim");
-        m.insert(8183, "proremote");
-        m.insert(1678, "prolink");
-        m.insert(2588, "privilege");
-        m.insert(2924, "precise-vip");
-        m.insert(2687, "pq-lic-mgmt");
-        m.insert(5071, "powerschool");
-        m.insert(1777, "powerguardian");
-        m.insert(2899, "powergemplus");
-        m.insert(494, "pov-ray");
-        m.insert(3875, "pnbscada");
-        m.insert(2937, "pnaconsult-lm");
-        m.insert(5437, "pmip6-data");
-        m.insert(5436, "pmip6-cntl");
-        m.insert(3469, "pluribus");
-        m.insert(3285, "plato");
-        m.insert(1293, "pkt-krb-ipsec");
-        m.insert(5272, "pk");
-        m.insert(2865, "pit-vpn");
-        m.insert(321, "pip");
-        m.insert(1280, "pictrography");
-        m.insert(1779, "pharmasoft");
-        m.insert(6432, "pgbouncer");
-        m.insert(1230, "periscope");
-        m.insert(2843, "pdnet");
-        m.insert(3033, "pdb");
-        m.insert(2566, "pcs-pcw");
-        m.insert(1562, "pconnectmgr");
-        m.insert(3085, "pcihreq");
-        m.insert(3892, "pcc-image-port");
-        m.insert(1246, "payrouter");
-        m.insert(1564, "pay-per-view");
-        m.insert(8160, "patrol");
-        m.insert(1633, "pammrpc");
-        m.insert(9997, "palace-6");
-        m.insert(9996, "palace-5");
-        m.insert(7511, "pafec-lm");
-        m.insert(5236, "padl2sim");
-        m.insert(3955, "p2pcommunity");
-        m.insert(2956, "ovrimosdbman");
-        m.insert(2954, "ovalarmsrv-cmd");
-        m.insert(2953, "ovalarmsrv");
-        m.insert(5310, "outlaws");
-        m.insert(2951, "ottp");
-        m.insert(2936, "otpatch");
-        m.insert(6951, "otlp");
-        m.insert(2413, "orion-rmi-reg");
-        m.insert(2407, "orion");
-        m.insert(1597, "orbplus-iiop");
-        m.insert(1570, "orbixd");
-        m.insert(2398, "orbiter");
-        m.insert(1809, "oracle-vp1");
-        m.insert(1575, "oraclenames");
-        m.insert(1754, "oracle-em2");
-        m.insert(1748, "oracle-em1");
-        m.insert(22001, "optocontrol");
-        m.insert(3855, "opentrac");
-        m.insert(2368, "opentable");
-        m.insert(8764, "openqueue");
-        m.insert(6653, "openflow");
-        m.insert(5314, "opalis-rbt-ipc");
-        m.insert(2267, "ontobroker");
-        m.insert(3244, "onesaf");
-        m.insert(2661, "olhost");
-        m.insert(2364, "oi-2000");
-        m.insert(506, "ohimsrv");
-        m.insert(2322, "ofsd");
-        m.insert(2498, "odn-castraq");
-        m.insert(3305, "odette-ftp");
-        m.insert(183, "ocbinder");
-        m.insert(650, "obex");
-        m.insert(2329, "nvd");
-        m.insert(5991, "nuxsl");
-        m.insert(1463, "nucleus");
-        m.insert(159, "nss-routing");
-        m.insert(8450, "npmp");
-        m.insert(1917, "noagent");
-        m.insert(1921, "noadmin");
-        m.insert(2839, "nmsigport");
-        m.insert(2503, "nms-dpnss");
-        m.insert(25903, "niprobe");
-        m.insert(25901, "niobsertver");
-        m.insert(25902, "nilinkanalyst");
-        m.insert(2556, "nicetec-nmsvc");
-        m.insert(2672, "nhsertver");
-        m.insert(1690, "ng-umds");
-        m.insert(2360, "nexstorindltd");
-        m.insert(2671, "newlixreg");
-        m.insert(1669, "netview-aix-9");
-        m.insert(1665, "netview-aix-5");
-        m.insert(1286, "netuitive");
-        m.insert(4138, "nettest");
-        m.insert(2592, "netrek");
-        m.insert(61441, "netprowler-sensor");
-        m.insert(61439, "netprowler-manager");
-        m.insert(61440, "netprowler-manager2");
-        m.insert(2983, "netplan");
-        m.insert(5465, "netops-broker");
-        m.insert(1843, "netopia-vo5");
-        m.insert(1842, "netopia-vo4");
-        m.insert(1841, "netopia-vo3");
-        m.insert(2061, "netmount");
-        m.insert(1329, "netdb-export");
-        m.insert(2451, "netchat");
-        m.insert(3701, "netcelera");
-        m.insert(3066, "netattachsdmp");
-        m.insert(2442, "netangel");
-        m.insert(5771, "netagent");
-        m.insert(2450, "netadmin");
-        m.insert(489, "nest-protocol");
-        m.insert(8834, "nessus-xmlrpc");
-        m.insert(1285, "neoiface");
-        m.insert(3262, "necp");
-        m.insert(2881, "ndsp");
-        m.insert(2883, "ndnp");
-        m.insert(43189, "ndm-agent-port");
-        m.insert(6064, "ndl-ahp-svc");
-        m.insert(1591, "ncpm-pm");
-        m.insert(1744, "ncpm-ft");
-        m.insert(405, "ncld");
-        m.insert(2397, "ncl");
-        m.insert(2683, "ncdloadbalance");
-        m.insert(2162, "navisphere");
-        m.insert(1288, "navbuddy");
-        m.insert(2286, "nas-metering");
-        m.insert(2236, "nani");
-        m.insert(167, "namp");
-        m.insert(1685, "n2nremote");
-        m.insert(1831, "myrtle");
-        m.insert(2981, "mylxamport");
-        m.insert(467, "mylex-mapd");
-        m.insert(1574, "mvel-lm");
-        m.insert(2743, "murx");
-        m.insert(19398, "mtrgtrans");
-        m.insert(2469, "mti-tcs-comm");
-        m.insert(2460, "ms-theater");
-        m.insert(1477, "ms-sna-sertver");
-        m.insert(1478, "ms-sna-base");
-        m.insert(5720, "ms-licensing");
-        m.insert(3535, "ms-la");
-        m.insert(1582, "msims");
-        m.insert(1731, "msiccp");
-        m.insert(679, "mrm");
-        m.insert(2684, "mpnjsosv");
-        m.insert(2686, "mpnjsomg");
-        m.insert(2681, "mpnjsomb");
-        m.insert(2685, "mpnjsocl");
-        m.insert(1952, "mpnjsc");
-        m.insert(9397, "mpidcagt");
-        m.insert(9344, "mphlpdmc");
-        m.insert(2952, "mpfwsas");
-        m.insert(2579, "mpfoncl");
-        m.insert(2561, "mosaixcc");
-        m.insert(1235, "mosaicsyssvc1");
-        m.insert(367, "mortgageware");
-        m.insert(8665, "monetra");
-        m.insert(471, "mondex");
-        m.insert(2926, "mobile-file-dl");
-        m.insert(1815, "mmpft");
-        m.insert(7786, "minivend");
-        m.insert(8033, "mindprint");
-        m.insert(1581, "mil-2045-47001");
-        m.insert(7979, "micromuse-ncps");
-        m.insert(1534, "micromuse-lm");
-        m.insert(490, "micom-pfs");
-        m.insert(3070, "mgxswitch");
-        m.insert(349, "mftp");
-        m.insert(1824, "metrics-pas");
-        m.insert(2511, "metastorm");
-        m.insert(1897, "metaagent");
-        m.insert(6070, "messageasap");
-        m.insert(2118, "mentasertver");
-        m.insert(2117, "mentaclient");
-        m.insert(1231, "menandmice-lpm");
-        m.insert(24003, "med-supp");
-        m.insert(24004, "med-ovw");
-        m.insert(24006, "med-net-svc");
-        m.insert(24000, "med-ltp");
-        m.insert(3594, "mediaspace");
-        m.insert(24002, "med-fsp-tx");
-        m.insert(24001, "med-fsp-rx");
-        m.insert(24005, "med-ci");
-        m.insert(5418, "mcntp");
-        m.insert(2698, "mck-ivpip");
-        m.insert(8763, "mc-appsertver");
-        m.insert(1820, "mcagent");
-        m.insert(1899, "mc2studios");
-        m.insert(2587, "masc");
-        m.insert(8911, "manyone-xml");
-        m.insert(8910, "manyone-http");
-        m.insert(1593, "mainsoft-lm");
-        m.insert(2535, "madcap");
-        m.insert(4181, "macbak");
-        m.insert(2559, "lstp");
-        m.insert(3069, "ls3");
-        m.insert(2620, "lpsrecommender");
-        m.insert(1298, "lpcp");
-        m.insert(2540, "lonworks");
-        m.insert(2541, "lonworks2");
-        m.insert(2125, "lockstep");
-        m.insert(1487, "localinfosrvr");
-        m.insert(2283, "lnvstatus");
-        m.insert(2284, "lnvmaps");
-        m.insert(2285, "lnvmailmon");
-        m.insert(2281, "lnvconsole");
-        m.insert(2282, "lnvalarm");
-        m.insert(2813, "llm-pass");
-        m.insert(5355, "llmnr");
-        m.insert(2814, "llm-csv");
-        m.insert(2795, "livestats");
-        m.insert(1555, "livelan");
-        m.insert(1968, "lipsinc");
-        m.insert(2611, "lionhead");
-        m.insert(245, "link");
-        m.insert(4042, "ldxp");
-        m.insert(1682, "lanyon-lantern");
-        m.insert(1485, "lansource");
-        m.insert(2560, "labrat");
-        m.insert(2841, "l3-ranger");
-        m.insert(2370, "l3-hbmon");
-        m.insert(2842, "l3-hawk");
-        m.insert(2840, "l3-exprt");
-        m.insert(398, "kryptolan");
-        m.insert(2424, "kofax-svr");
-        m.insert(1773, "kmscontrol");
-        m.insert(1649, "kermit");
-        m.insert(287, "k-block");
-        m.insert(2656, "kana");
-        m.insert(2213, "kali");
-        m.insert(2822, "ka0wuc");
-        m.insert(1289, "jwalksertver");
-        m.insert(3471, "jt400-ssl");
-        m.insert(3470, "jt400");
-        m.insert(3042, "journee");
-        m.insert(4114, "jomamqmonitor");
-        m.insert(6962, "jmevt2");
-        m.insert(6961, "jmact3");
-        m.insert(1567, "jlicelmd");
-        m.insert(2808, "j-lan-p");
-        m.insert(1706, "jetform");
-        m.insert(2406, "jedisertver");
-        m.insert(2508, "jdatastore");
-        m.insert(2506, "jbroker");
-        m.insert(1623, "jaleosnd");
-        m.insert(13160, "i-zipqd");
-        m.insert(2166, "iwsertver");
-        m.insert(2866, "iwlistener");
-        m.insert(2982, "iwb-whiteboard");
-        m.insert(1275, "ivcollector");
-        m.insert(1573, "itscomm-ns");
-        m.insert(4348, "itose");
-        m.insert(1828, "itm-mcell-u");
-        m.insert(3084, "itm-mccs");
-        m.insert(1609, "isysg-lm");
-        m.insert(2853, "ispipes");
-        m.insert(3589, "isomair");
-        m.insert(147, "iso-ip");
-        m.insert(3501, "isoft-p2p");
-        m.insert(1643, "isis-ambc");
-        m.insert(1642, "isis-am");
-        m.insert(1245, "isbconference2");
-        m.insert(43190, "ip-provision");
-        m.insert(2962, "iph-policy-cli");
-        m.insert(2963, "iph-policy-adm");
-        m.insert(576, "ipcd");
-        m.insert(2549, "ipass");
-        m.insert(1579, "ioc-sea-lm");
-        m.insert(1585, "intv");
-        m.insert(503, "intrinsa");
-        m.insert(1907, "intrastar");
-        m.insert(3202, "intraintra");
-        m.insert(3548, "interworld");
-        m.insert(3060, "intersertver");
-        m.insert(2652, "interpathpanel");
-        m.insert(2633, "interintelli");
-        m.insert(16991, "intel-rci-mp");
-        m.insert(495, "intecourier");
-        m.insert(1602, "inspect");
-        m.insert(1490, "insitu-conf");
-        m.insert(2793, "initlsmsad");
-        m.insert(18881, "infotos");
-        m.insert(2854, "infomover");
-        m.insert(2319, "infolibria");
-        m.insert(2233, "infocrypt");
-        m.insert(3345, "influence");
-        m.insert(2454, "indx-dds");
-        m.insert(8130, "indigo-vrmi");
-        m.insert(8131, "indigo-vbcp");
-        m.insert(2127, "index-pc-wb");
-        m.insert(2970, "index-net");
-        m.insert(2932, "incp");
-        m.insert(3164, "imprs");
-        m.insert(1710, "impera");
-        m.insert(11319, "imip");
-        m.insert(27345, "imagepump");
-        m.insert(2801, "igcp");
-        m.insert(1284, "iee-qfx");
-        m.insert(2995, "idrs");
-        m.insert(3797, "idps");
-        m.insert(2966, "idp-infotrieve");
-        m.insert(2590, "idotdist");
-        m.insert(549, "idfp");
-        m.insert(1725, "iden-ralp");
-        m.insert(2337, "ideesrv");
-        m.insert(3130, "icpv2");
-        m.insert(5813, "icmpd");
-        m.insert(25008, "icl-twobase9");
-        m.insert(25007, "icl-twobase8");
-        m.insert(25006, "icl-twobase7");
-        m.insert(25005, "icl-twobase6");
-        m.insert(25004, "icl-twobase5");
-        m.insert(25003, "icl-twobase4");
-        m.insert(25002, "icl-twobase3");
-        m.insert(25009, "icl-twobase10");
-        m.insert(6850, "iccrushmore");
-        m.insert(1344, "icap");
-        m.insert(1604, "icabrowsert");
-        m.insert(8733, "ibus");
-        m.insert(2572, "ibp");
-        m.insert(1260, "ibm-ssd");
-        m.insert(1586, "ibm-abtact");
-        m.insert(1726, "iberiagames");
-        m.insert(6999, "iatp-normalpri");
-        m.insert(6998, "iatp-highpri");
-        m.insert(2140, "ias-reg");
-        m.insert(2139, "ias-auth");
-        m.insert(2141, "ias-admind");
-        m.insert(1577, "hypercube-lm");
-        m.insert(4180, "httpx");
-        m.insert(4827, "htcp");
-        m.insert(1877, "hp-webqosdb");
-        m.insert(2715, "hpstgmgr2");
-        m.insert(19412, "hp-sessmon");
-        m.insert(19410, "hp-sco");
-        m.insert(19411, "hp-sca");
-        m.insert(5404, "hpoms-dps-lstn");
-        m.insert(5403, "hpoms-ci-lstn");
-        m.insert(2985, "hpidsagent");
-        m.insert(1803, "hp-hcip-gwy");
-        m.insert(2744, "honyaku");
-        m.insert(6790, "hnmp");
-        m.insert(2575, "hl7");
-        m.insert(12172, "hivep");
-        m.insert(1789, "hello");
-        m.insert(35000, "heathview");
-        m.insert(1281, "healthd");
-        m.insert(14937, "hde-lcesrvr-2");
-        m.insert(14936, "hde-lcesrvr-1");
-        m.insert(263, "hdap");
-        m.insert(375, "hassle");
-        m.insert(5094, "hart-ip");
-        m.insert(1816, "harp");
-        m.insert(2245, "hao");
-        m.insert(1238, "hacl-qs");
-        m.insert(2778, "gwen-sonya");
-        m.insert(9321, "guibase");
-        m.insert(2643, "gte-samp");
-        m.insert(2421, "g-talk");
-        m.insert(488, "gss-http");
-        m.insert(1850, "gsi");
-        m.insert(2458, "griffin");
-        m.insert(41, "graphics");
-        m.insert(2519, "globmsgsvc");
-        m.insert(6109, "globecast-id");
-        m.insert(1774, "global-dtsertv");
-        m.insert(2833, "glishd");
-        m.insert(3862, "giga-pocket");
-        m.insert(3381, "geneous");
-        m.insert(1590, "gemini-lm");
-        m.insert(2626, "gbjd816");
-        m.insert(1738, "gamegen1");
-        m.insert(2732, "g5m");
-        m.insert(19539, "fxuptp");
-        m.insert(2849, "fxp");
-        m.insert(2358, "futrix");
-        m.insert(1786, "funk-logger");
-        m.insert(1787, "funk-license");
-        m.insert(1657, "fujitsu-mmpdc");
-        m.insert(2429, "ft-role");
-        m.insert(1747, "ftrapid-2");
-        m.insert(1746, "ftrapid-1");
-        m.insert(5408, "foresyte-sec");
-        m.insert(5407, "foresyte-clear");
-        m.insert(2359, "flukesertver");
-        m.insert(24677, "flashfiler");
-        m.insert(1874, "fjswapsnp");
-        m.insert(2946, "fjsvmpor");
-        m.insert(2509, "fjmpss");
-        m.insert(1873, "fjmpjps");
-        m.insert(2747, "fjippol-swrly");
-        m.insert(2751, "fjippol-port2");
-        m.insert(2750, "fjippol-port1");
-        m.insert(2748, "fjippol-polsvr");
-        m.insert(2749, "fjippol-cnsl");
-        m.insert(9396, "fjinvmgr");
-        m.insert(3067, "fjhpjp");
-        m.insert(1848, "fjdocdist");
-        m.insert(9374, "fjdmimgr");
-        m.insert(2510, "fjappmgrbulk");
-        m.insert(2615, "firepower");
-        m.insert(1689, "firefox");
-        m.insert(4682, "finisar");
-        m.insert(3350, "findviatv");
-        m.insert(24242, "filesphere");
-        m.insert(3401, "filecast");
-        m.insert(3294, "fg-gip");
-        m.insert(3293, "fg-fps");
-        m.insert(5503, "fcp-srvr-inst2");
-        m.insert(5504, "fcp-cics-gw1");
-        m.insert(5746, "fcopys-sertver");
-        m.insert(5745, "fcopy-sertver");
-        m.insert(2344, "fcmsys");
-        m.insert(7437, "faximum");
-        m.insert(3353, "fatpipe");
-        m.insert(2689, "fastlynx");
-        m.insert(3873, "fagordnc");
-        m.insert(1561, "facilityview");
-        m.insert(1915, "facelink");
-        m.insert(2792, "f5-globalsite");
-        m.insert(10103, "ezrelay");
-        m.insert(26260, "ezproxy");
-        m.insert(26261, "ezmeeting");
-        m.insert(589, "eyelink");
-        m.insert(1948, "eye2eye");
-        m.insert(2666, "extensis");
-        m.insert(26489, "exonet");
-        m.insert(26487, "exoconfig");
-        m.insert(2769, "exce");
-        m.insert(2674, "ewnn");
-        m.insert(6066, "ewctsp");
-        m.insert(1876, "ewcappsrv");
-        m.insert(2835, "evtp-data");
-        m.insert(2834, "evtp");
-        m.insert(2782, "everydayrc");
-        m.insert(16309, "etb4j");
-        m.insert(2969, "essp");
-        m.insert(2867, "esps-portal");
-        m.insert(2797, "esp-encap");
-        m.insert(2950, "esip");
-        m.insert(1822, "es-elmd");
-        m.insert(1342, "esbroker");
-        m.insert(5135, "erp-scale");
-        m.insert(2650, "eristwoguns");
-        m.insert(2109, "ergolight");
-        m.insert(2051, "epnsdp");
-        m.insert(2912, "epicon");
-        m.insert(309, "entrusttime");
-        m.insert(1865, "entp");
-        m.insert(3289, "enpc");
-        m.insert(1804, "enl");
-        m.insert(3286, "e-net");
-        m.insert(1740, "encore");
-        m.insert(2211, "emwin");
-        m.insert(2707, "emcsymapiport");
-        m.insert(1273, "emc-gateway");
-        m.insert(2181, "eforward");
-        m.insert(2553, "efidiningport");
-        m.insert(2896, "ecovisiong6-1");
-        m.insert(2858, "ecnp");
-        m.insert(3610, "echonet");
-        m.insert(2651, "ebinsite");
-        m.insert(1325, "dx-instrument");
-        m.insert(2445, "dtn1");
-        m.insert(1265, "dssiapi");
-        m.insert(3053, "dsom-sertver");
-        m.insert(1292, "dsdn");
-        m.insert(1878, "drmsmc");
-        m.insert(4098, "drmsfsd");
-        m.insert(1780, "dpkeysertv");
-        m.insert(1795, "dpi-proxy");
-        m.insert(4099, "dpcp");
-        m.insert(1821, "donnyworld");
-        m.insert(2151, "docent");
-        m.insert(1227, "dns2go");
-        m.insert(436, "dna-cml");
-        m.insert(2287, "dna");
-        m.insert(32636, "DMExpress");
-        m.insert(1489, "dmdocbroker");
-        m.insert(1263, "dka");
-        m.insert(5419, "dj-ice");
-        m.insert(3041, "di-traceware");
-        m.insert(2496, "dirgis");
-        m.insert(3287, "directvdata");
-        m.insert(6073, "directplay8");
-        m.insert(2234, "directplay");
-        m.insert(242, "direct");
-        m.insert(1844, "direcpc-dll");
-        m.insert(2362, "digiman");
-        m.insert(11112, "dicom");
-        m.insert(1941, "dic-aida");
-        m.insert(3046, "di-ase");
-        m.insert(1945, "dialogic-elmd");
-        m.insert(6072, "diagnose-proc");
-        m.insert(2960, "dfoxsertver");
-        m.insert(5426, "devbasic");
-        m.insert(2753, "de-spot");
-        m.insert(3298, "deskview");
-        m.insert(1702, "deskshare");
-        m.insert(1256, "de-sertver");
-        m.insert(1254, "de-noc");
-        m.insert(1266, "dellpwrappks");
-        m.insert(2562, "delibo");
-        m.insert(1656, "dec-mbadmin-h");
-        m.insert(1655, "dec-mbadmin");
-        m.insert(579, "decbsrv");
-        m.insert(1255, "de-cache-query");
-        m.insert(1415, "dbstar");
-        m.insert(2365, "dbref");
-        m.insert(2345, "dbm");
-        m.insert(6104, "dbdb");
-        m.insert(8132, "dbabble");
-        m.insert(1908, "dawn");
-        m.insert(3282, "datusorb");
-        m.insert(1857, "datacaptor");
-        m.insert(1679, "darcorp-lm");
-        m.insert(2870, "daishi");
-        m.insert(3458, "d3winosfi");
-        m.insert(5420, "cylink-c");
-        m.insert(772, "cyclesertv2");
-        m.insert(3645, "cyc");
-        m.insert(551, "cybercash");
-        m.insert(1686, "cvmon");
-        m.insert(3773, "ctdhercules");
-        m.insert(4379, "ctdb");
-        m.insert(1851, "ctcd");
-        m.insert(3022, "csregagent");
-        m.insert(2807, "cspmulti");
-        m.insert(2890, "cspclmulti");
-        m.insert(1837, "csoft1");
-        m.insert(2955, "csnotify");
-        m.insert(3145, "csi-lfap");
-        m.insert(1471, "csdmbase");
-        m.insert(1468, "csdm");
-        m.insert(40841, "cscp");
-        m.insert(40842, "csccredir");
-        m.insert(40843, "csccfirewall");
-        m.insert(1724, "csbphone");
-        m.insert(2422, "crmsbits");
-        m.insert(6253, "crip");
-        m.insert(455, "creativepartnr");
-        m.insert(2746, "cpudpencap");
-        m.insert(3201, "cpq-tasksmart");
-        m.insert(5984, "couchdb");
-        m.insert(2324, "cosmocall");
-        m.insert(3288, "cops");
-        m.insert(5412, "continuus");
-        m.insert(2137, "connect");
-        m.insert(1648, "concurrent-lm");
-        m.insert(1802, "concomp1");
-        m.insert(4308, "compx-lockview");
-        m.insert(48556, "com-bardac-dw");
-        m.insert(2757, "cnrp");
-        m.insert(1757, "cnhrp");
-        m.insert(1294, "cmmdriver");
-        m.insert(7174, "clutild");
-        m.insert(1944, "close-combat");
-        m.insert(371, "clearcase");
-        m.insert(504, "citadel");
-        m.insert(1741, "cisco-net-mgmt");
-        m.insert(2931, "circle-x");
-        m.insert(3020, "cifs");
-        m.insert(17219, "chipper");
-        m.insert(3903, "charsetmgr");
-        m.insert(1768, "cft-7");
-        m.insert(1767, "cft-6");
-        m.insert(1766, "cft-5");
-        m.insert(1765, "cft-4");
-        m.insert(2856, "cesdinv");
-        m.insert(1640, "cert-responder");
-        m.insert(1639, "cert-initiator");
-        m.insert(1794, "cera-bcm");
-        m.insert(3987, "centerline");
-        m.insert(2571, "cecsvc");
-        m.insert(2412, "cdn");
-        m.insert(3315, "cdid");
-        m.insert(2116, "ccowcmr");
-        m.insert(3061, "cautcpd");
-        m.insert(2836, "catalyst");
-        m.insert(3450, "castorproxy");
-        m.insert(3105, "cardbox");
-        m.insert(1756, "capfast-lmd");
-        m.insert(9283, "callwaveiam");
-        m.insert(2906, "caller9");
-        m.insert(588, "cal");
-        m.insert(1202, "caiccipc");
-        m.insert(1375, "bytex");
-        m.insert(2803, "btprjctrl");
-        m.insert(2536, "btpp2audctr1");
-        m.insert(1252, "bspne-pcc");
-        m.insert(2619, "bruce");
-        m.insert(1323, "brcd");
-        m.insert(2990, "boscap");
-        m.insert(1304, "boomerang");
-        m.insert(2961, "boldsoft-lm");
-        m.insert(6402, "boe-eventsrv");
-        m.insert(6403, "boe-cachesvr");
-        m.insert(3561, "bmc-onekey");
-        m.insert(1770, "bmc-net-svc");
-        m.insert(1769, "bmc-net-adm");
-        m.insert(2877, "bluelance");
-        m.insert(10288, "blocks");
-        m.insert(2911, "blockade");
-        m.insert(2032, "blackboard");
-        m.insert(2663, "bintec-tapi");
-        m.insert(2662, "bintec-capi");
-        m.insert(1962, "biap-mp");
-        m.insert(310, "bhmds");
-        m.insert(357, "bhevent");
-        m.insert(354, "bh611");
-        m.insert(482, "bgs-nsi");
-        m.insert(2414, "beeyond");
-        m.insert(2852, "bears-01");
-        m.insert(1951, "bcs-lmsertver");
-        m.insert(1704, "bcs-broker");
-        m.insert(3327, "bbars");
-        m.insert(573, "banyan-vip");
-        m.insert(567, "banyan-rpc");
-        m.insert(2708, "banyan-net");
-        m.insert(2131, "avantageb2b");
-        m.insert(2772, "auris");
-        m.insert(3643, "audiojuggler");
-        m.insert(1749, "aspen-sertvices");
-        m.insert(5042, "asnaacceler8db");
-        m.insert(1913, "armadp");
-        m.insert(2624, "aria");
-        m.insert(1826, "ardt");
-        m.insert(2136, "appworxsrv");
-        m.insert(2616, "appswitch-emp");
-        m.insert(9164, "apani5");
-        m.insert(9163, "apani4");
-        m.insert(9162, "apani3");
-        m.insert(1781, "answersoft-lm");
-        m.insert(2929, "amx-webadmin");
-        m.insert(1320, "amx-axbnet");
-        m.insert(2848, "amt-blc-port");
-        m.insert(2268, "amt");
-        m.insert(459, "ampr-rcmd");
-        m.insert(1536, "ampr-inter");
-        m.insert(2639, "aminet");
-        m.insert(6831, "ambit-lm");
-        m.insert(10080, "amanda");
-        m.insert(1845, "altalink");
-        m.insert(1653, "alphatech-lm");
-        m.insert(1849, "alpha-sms");
-        m.insert(463, "alpes");
-        m.insert(2740, "alarm");
-        m.insert(2473, "aker-cdp");
-        m.insert(2783, "aises");
-        m.insert(1481, "airs");
-        m.insert(2785, "aic-np");
-        m.insert(2331, "agentview");
-        m.insert(7107, "aes-x170");
-        m.insert(1219, "aeroflight-ret");
-        m.insert(3279, "admind");
-        m.insert(5411, "actnet");
-        m.insert(2796, "ac-tech");
-        m.insert(2149, "acptsys");
-        m.insert(7781, "accu-lmgr");
-        m.insert(1205, "accord-mgc");
-        m.insert(4108, "accel");
-        m.insert(4885, "abbs");
-        m.insert(1546, "abbaccuray");
-        m.insert(2894, "abacus-remote");
-        m.insert(1601, "aas");
-        m.insert(2878, "aap");
-        m.insert(5605, "a4-sdunode");
-        m.insert(5604, "a3-sdunode");
-        m.insert(5602, "a1-msc");
-        m.insert(5603, "a1-bs");
-        m.insert(3284, "4talk");
-        m.insert(1742, "3Com-nsd");
-        m
+// Nmap top-N ports generated from `data/nmap-services` by build.rs
+include!(concat!(env!("OUT_DIR"), "/services.rs"));
+
+static SERVICES_FILE: OnceLock<ServicesFile> = OnceLock::new();
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Protocol {
+    Tcp,
+    Udp,
+}
+
+impl FromStr for Protocol {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "tcp" => Ok(Protocol::Tcp),
+            "udp" => Ok(Protocol::Udp),
+            _ => Err(()),
+        }
+    }
+}
+
+impl std::fmt::Display for Protocol {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Protocol::Tcp => "tcp".fmt(f),
+            Protocol::Udp => "udp".fmt(f),
+        }
+    }
+}
+
+/// Services loaded from nmap-services file, replaces the embedded ones
+#[derive(Debug, Default)]
+pub struct ServicesFile {
+    tcp: ServiceTable,
+    udp: ServiceTable,
+}
+
+/// Services of a protocol
+#[derive(Debug, Default)]
+struct ServiceTable {
+    names: HashMap<u16, String>,
+    /// Ordered by open frequency
+    top_ports: Vec<u16>,
+    /// Open frequencies of `top_ports`
+    frequencies: Vec<f64>,
+}
+
+impl ServicesFile {
+    /// Load nmap-services file to be used for naming ports and ordering top ports
+    pub fn init(path: &Path) -> Result<(), String> {
+        let content = std::fs::read_to_string(path)
+            .map_err(|err| format!("Failed to read '{}', {err}", path.display()))?;
+        let services = Self::parse(&content)
+            .map_err(|err| format!("Failed to load '{}', {err}", path.display()))?;
+        let _ = SERVICES_FILE.set(services);
+        Ok(())
+    }
+
+    /// Parse lines like `http 80/tcp 0.484143 # World Wide Web HTTP`
+    fn parse(content: &str) -> Result<Self, String> {
+        let mut entries = vec![];
+        for (i, line) in content.lines().enumerate() {
+            let line = line.split('#').next().unwrap();
+            let mut fields = line.split_whitespace();
+            let (name, port_protocol) = match (fields.next(), fields.next()) {
+                (Some(name), Some(port_protocol)) => (name, port_protocol),
+                (None, _) => continue,
+                _ => return Err(format!("invalid line {}", i + 1)),
+            };
+            let frequency = match fields.next() {
+                Some(v) => v
+                    .parse::<f64>()
+                    .map_err(|_| format!("invalid frequency at line {}", i + 1))?,
+                None => 0.0,
+            };
+            let (port, protocol) = port_protocol
+                .split_once('/')
+                .ok_or_else(|| format!("invalid port at line {}", i + 1))?;
+            let port = port
+                .parse::<u16>()
+                .map_err(|_| format!("invalid port at line {}", i + 1))?;
+            if let Ok(protocol) = protocol.parse::<Protocol>() {
+                entries.push((protocol, port, name.to_string(), frequency));
+            }
+        }
+        entries.sort_by(|a, b| b.3.total_cmp(&a.3));
+        let mut services = Self::default();
+        for (protocol, port, name, frequency) in entries {
+            let table = match protocol {
+                Protocol::Tcp => &mut services.tcp,
+                Protocol::Udp => &mut services.udp,
+            };
+            if let Entry::Vacant(entry) = table.names.entry(port) {
+                entry.insert(name);
+                table.top_ports.push(port);
+                table.frequencies.push(frequency);
+            }
+        }
+        Ok(services)
+    }
+}
+
+/// Get the service name of tcp port
+pub fn port_name(port: u16) -> Option<&'static str> {
+    service_name(port, Protocol::Tcp)
+}
+
+/// Get the service name of port
+pub fn service_name(port: u16, protocol: Protocol) -> Option<&'static str> {
+    match (SERVICES_FILE.get(), protocol) {
+        (Some(services), Protocol::Tcp) => services.tcp.names.get(&port).map(|v| v.as_str()),
+        (Some(services), Protocol::Udp) => services.udp.names.get(&port).map(|v| v.as_str()),
+        (None, Protocol::Tcp) => TCP_SERVICES.get(&port).copied(),
+        (None, Protocol::Udp) => UDP_SERVICES.get(&port).copied(),
+    }
+}
+
+/// Ports ordered by open frequency
+fn top_ports(protocol: Protocol) -> &'static [u16] {
+    match (SERVICES_FILE.get(), protocol) {
+        (Some(services), Protocol::Tcp) => &services.tcp.top_ports,
+        (Some(services), Protocol::Udp) => &services.udp.top_ports,
+        (None, Protocol::Tcp) => &TCP_TOP_PORTS,
+        (None, Protocol::Udp) => &UDP_TOP_PORTS,
+    }
+}
+
+/// Open frequencies of `top_ports`, empty if unknown
+fn frequencies(protocol: Protocol) -> &'static [f64] {
+    let frequencies: &[f64] = match (SERVICES_FILE.get(), protocol) {
+        (Some(services), Protocol::Tcp) => &services.tcp.frequencies,
+        (Some(services), Protocol::Udp) => &services.udp.frequencies,
+        (None, Protocol::Tcp) => &TCP_FREQUENCIES,
+        (None, Protocol::Udp) => &UDP_FREQUENCIES,
     };
+    if frequencies.iter().any(|v| *v > 0.0) {
+        frequencies
+    } else {
+        &[]
+    }
+}
+
+/// Get ports whose open frequency is at least `ratio`
+pub fn ratio_ports(ratio: f64, protocol: Protocol) -> Result<Vec<u16>, String> {
+    let frequencies = frequencies(protocol);
+    if frequencies.is_empty() {
+        return Err(format!(
+            "No open frequencies of {protocol} ports, use --services-file to load them"
+        ));
+    }
+    Ok(top_ports(protocol)
+        .iter()
+        .zip(frequencies)
+        .take_while(|(_, v)| **v >= ratio)
+        .map(|(port, _)| *port)
+        .collect())
+}
 
-    pub static ref FULL_PORTS: Vec<u16> = (1..=65535).collect();
-    pub static ref TOP1000_PORTS: Vec<u16> = topn_ports(1000);
+/// Fraction of the historically-open ports covered by `ports`
+pub fn coverage(ports: &[u16], protocol: Protocol) -> Result<f64, String> {
+    let frequencies = frequencies(protocol);
+    if frequencies.is_empty() {
+        return Err(format!(
+            "No open frequencies of {protocol} ports, use --services-file to load them"
+        ));
+    }
+    let ports: HashSet<u16> = ports.iter().copied().collect();
+    let total: f64 = frequencies.iter().sum();
+    let covered: f64 = top_ports(protocol)
+        .iter()
+        .zip(frequencies)
+        .filter(|(port, _)| ports.contains(port))
+        // Unlike `sum`, 0 rather than -0 if no port is covered
+        .fold(0.0, |sum, (_, v)| sum + v);
+    Ok(covered / total)
 }
 
+/// Built-in named port groups
+const PORT_GROUPS: &[(&str, &[u16])] = &[
+    (
+        "web",
+        &[80, 443, 3000, 5000, 8000, 8008, 8080, 8081, 8443, 8888],
+    ),
+    (
+        "db",
+        &[1433, 1521, 3306, 5432, 5984, 6379, 9042, 9200, 11211, 27017],
+    ),
+    ("mail", &[25, 110, 143, 465, 587, 993, 995]),
+    (
+        "remote-admin",
+        &[22, 23, 512, 513, 514, 3389, 5800, 5900, 5985, 5986],
+    ),
+    (
+        "iot",
+        &[
+            23, 80, 102, 502, 554, 1883, 2323, 7547, 8080, 8883, 20000, 44818,
+        ],
+    ),
+];
+
+/// URI schemes whose names differ from the service names
+const SCHEME_SERVICES: &[(&str, &str)] = &[
+    ("postgres", "postgresql"),
+    ("mongodb", "mongod"),
+    ("memcached", "memcache"),
+    ("mssql", "ms-sql-s"),
+    ("sqlserver", "ms-sql-s"),
+    ("ws", "http"),
+    ("wss", "https"),
+    ("sftp", "ssh"),
+    ("scp", "ssh"),
+    ("rdp", "ms-wbt-server"),
+    ("smb", "microsoft-ds"),
+    ("cifs", "microsoft-ds"),
+    ("ldaps", "ldapssl"),
+    ("mqtts", "secure-mqtt"),
+    ("dns", "domain"),
+];
+
 #[derive(Debug, Clone)]
 pub enum PortValue {
     One(u16),
     Range(u16, u16),
-    Top(u16),
+    Top(u16, Protocol),
+    /// Ports whose open frequency is at least the ratio
+    Ratio(f64),
+    /// Service name or port group
+    Name(String),
+    /// Ports to be removed, e.g. `!22` or `^8000-8100`
+    Exclude(Box<PortValue>),
 }
 
 impl PortValue {
-    pub fn values(&self) -> Vec<u16> {
-        match self {
+    pub fn values(&self) -> Result<Vec<u16>, String> {
+        let values = match self {
             PortValue::One(v) => vec![*v],
             PortValue::Range(start, end) => ((*start.max(&1))..=*end).collect(),
-            PortValue::Top(n) => topn_ports(*n as usize),
-        }
+            PortValue::Top(n, protocol) => {
+                let ports = topn_ports(*n as usize, *protocol);
+                if ports.is_empty() && *n > 0 {
+                    return Err(format!(
+                        "No {protocol} services, use --services-file to load them"
+                    ));
+                }
+                ports.to_vec()
+            }
+            PortValue::Ratio(ratio) => ratio_ports(*ratio, Protocol::Tcp)?,
+            PortValue::Name(name) => named_ports(name)
+                .ok_or_else(|| format!("Unknown service or port group '{name}'"))?,
+            PortValue::Exclude(value) => value.values()?,
+        };
+        Ok(values)
+    }
+
+    pub fn is_exclude(&self) -> bool {
+        matches!(self, PortValue::Exclude(_))
     }
 }
 
@@ -8406,10 +275,23 @@ impl FromStr for PortValue {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if let Some(v) = s.strip_prefix(['!', '^']) {
+            return match v.parse()? {
+                PortValue::Exclude(_) => Err(()),
+                v => Ok(PortValue::Exclude(Box::new(v))),
+            };
+        }
         let ret = match s.split_once('-') {
             None => {
                 if let Some(n) = s.strip_prefix("top") {
-                    n.parse::<u16>().ok().map(PortValue::Top)
+                    let (n, protocol) = match n.split_once('/') {
+                        Some((n, protocol)) => (n, protocol.parse::<Protocol>().ok()),
+                        None => (n, Some(Protocol::Tcp)),
+                    };
+                    match (n.parse::<u16>(), protocol) {
+                        (Ok(n), Some(protocol)) => Some(PortValue::Top(n, protocol)),
+                        _ => None,
+                    }
                 } else {
                     s.parse::<u16>().ok().map(PortValue::One)
                 }
@@ -8418,15 +300,179 @@ impl FromStr for PortValue {
                 (Some(x), Some(y)) => match x.cmp(&y) {
                     Ordering::Less => Some(PortValue::Range(x, y)),
                     Ordering::Equal => Some(PortValue::One(x)),
-                    Ordering::Greater => None,
+                    Ordering::Greater => return Err(()),
                 },
                 _ => None,
             },
         };
-        ret.ok_or(())
+        // Names are resolved later since services file and port groups are not loaded yet
+        ret.or_else(|| {
+            (!s.is_empty() && !s.contains(char::is_whitespace))
+                .then(|| PortValue::Name(s.to_string()))
+        })
+        .ok_or(())
+    }
+}
+
+/// Get the ports of user-defined group, built-in group or service
+fn named_ports(name: &str) -> Option<Vec<u16>> {
+    if let Some(ports) = Config::group(name) {
+        return Some(ports.to_vec());
+    }
+    if let Some((_, ports)) = PORT_GROUPS
+        .iter()
+        .find(|(v, _)| v.eq_ignore_ascii_case(name))
+    {
+        return Some(ports.to_vec());
     }
+    service_port(name).map(|v| vec![v])
 }
 
-pub fn topn_ports(n: usize) -> Vec<u16> {
-    NAMP_TOP_PORTS.iter().map(|(v, _)| *v).take(n).collect()
+/// Parse comma separated port values e.g. 22,80-443,top100,ssh,web,!8080
+pub fn parse_ports(s: &str) -> Result<Vec<u16>, String> {
+    let values = s
+        .split(',')
+        .map(|v| v.parse::<PortValue>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|_| format!("Invalid ports '{s}'"))?;
+    let ports = collect_ports(&values)?;
+    if ports.is_empty() {
+        return Err(format!("No ports left in '{s}'"));
+    }
+    Ok(ports)
+}
+
+/// Collect ports of port values
+///
+/// Ports keep the order they first appear in, excluded ports are removed
+pub fn collect_ports(values: &[PortValue]) -> Result<Vec<u16>, String> {
+    let mut ports = vec![];
+    let mut excludes = HashSet::new();
+    for value in values {
+        if value.is_exclude() {
+            excludes.extend(value.values()?);
+        } else {
+            ports.extend(value.values()?);
+        }
+    }
+    let mut seen = HashSet::new();
+    ports.retain(|v| !excludes.contains(v) && seen.insert(*v));
+    Ok(ports)
+}
+
+/// Format ports as sorted comma separated ranges e.g. 22,80-443
+pub fn format_ports(ports: &[u16]) -> String {
+    let mut ports = ports.to_vec();
+    ports.sort_unstable();
+    ports.dedup();
+    let mut ranges: Vec<(u16, u16)> = vec![];
+    for port in ports {
+        match ranges.last_mut() {
+            Some((_, end)) if *end + 1 == port => *end = port,
+            _ => ranges.push((port, port)),
+        }
+    }
+    ranges
+        .iter()
+        .map(|(start, end)| match start == end {
+            true => start.to_string(),
+            false => format!("{start}-{end}"),
+        })
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Get the most common port of service
+pub fn service_port(name: &str) -> Option<u16> {
+    top_ports(Protocol::Tcp)
+        .iter()
+        .find(|v| port_name(**v).is_some_and(|v| v.eq_ignore_ascii_case(name)))
+        .copied()
+}
+
+/// Get the default port of URI scheme
+pub fn scheme_port(scheme: &str) -> Option<u16> {
+    let scheme = scheme.to_ascii_lowercase();
+    let name = SCHEME_SERVICES
+        .iter()
+        .find(|(v, _)| *v == scheme)
+        .map_or(scheme.as_str(), |(_, v)| v);
+    service_port(name)
+}
+
+pub fn topn_ports(n: usize, protocol: Protocol) -> &'static [u16] {
+    let ports = top_ports(protocol);
+    &ports[..n.min(ports.len())]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_ports() {
+        assert_eq!(parse_ports("22,80-82"), Ok(vec![22, 80, 81, 82]));
+        assert_eq!(parse_ports("ssh,http,mysql"), Ok(vec![22, 80, 3306]));
+        assert_eq!(parse_ports("ms-wbt-server"), Ok(vec![3389]));
+        assert_eq!(
+            parse_ports("mail"),
+            Ok(vec![25, 110, 143, 465, 587, 993, 995])
+        );
+        assert_eq!(parse_ports("top3"), Ok(vec![80, 23, 443]));
+        assert_eq!(parse_ports("top3/tcp"), Ok(vec![80, 23, 443]));
+        assert_eq!(
+            parse_ports("top10/udp"),
+            Ok(vec![631, 161, 137, 123, 138, 1434, 445, 135, 67, 53])
+        );
+        assert_eq!(parse_ports("22,53,!top10/udp"), Ok(vec![22]));
+        assert!(parse_ports("no-such-service").is_err());
+        assert!(parse_ports("80-22").is_err());
+        assert_eq!(
+            parse_ports("80,1-100,!1-78"),
+            Ok(vec![
+                80, 79, 81, 82, 83, 84, 85, 86, 87, 88, 89, 90, 91, 92, 93, 94, 95, 96, 97, 98, 99,
+                100
+            ])
+        );
+        assert_eq!(parse_ports("20-25,^22,!ssh,!24"), Ok(vec![20, 21, 23, 25]));
+        assert!(parse_ports("!22").is_err());
+        assert!(parse_ports("!!22").is_err());
+    }
+
+    #[test]
+    fn test_embedded_frequencies() {
+        assert_eq!(
+            ratio_ports(0.1, Protocol::Tcp),
+            Ok(vec![80, 23, 443, 21, 22, 25])
+        );
+        let top100 = coverage(topn_ports(100, Protocol::Tcp), Protocol::Tcp).unwrap();
+        let top1000 = coverage(topn_ports(1000, Protocol::Tcp), Protocol::Tcp).unwrap();
+        assert!(0.0 < top100 && top100 < top1000 && top1000 < 1.0);
+        assert!(!ratio_ports(0.1, Protocol::Udp).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_format_ports() {
+        assert_eq!(format_ports(&[]), "");
+        assert_eq!(
+            format_ports(&[443, 22, 80, 81, 82, 22, 65535]),
+            "22,80-82,443,65535"
+        );
+    }
+
+    #[test]
+    fn test_parse_services_file() {
+        let services = ServicesFile::parse(
+            "# comment\n\
+            http\t80/tcp\t0.484143\t# World Wide Web HTTP\n\
+            domain\t53/udp\t0.213496\n\
+            ssh\t22/tcp\t0.182286\n\
+            telnet\t23/tcp\t0.221265\n",
+        )
+        .unwrap();
+        assert_eq!(services.tcp.top_ports, [80, 23, 22]);
+        assert_eq!(services.tcp.names[&23], "telnet");
+        assert_eq!(services.udp.top_ports, [53]);
+        assert!(ServicesFile::parse("http 80/tcp x").is_err());
+    }
 }
@@ -0,0 +1,159 @@
+use std::{
+    io::Write,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Mutex, MutexGuard,
+    },
+    time::{Duration, Instant},
+};
+
+/// Redraw interval of the live progress line
+const REDRAW_INTERVAL: Duration = Duration::from_millis(200);
+
+/// How progress is reported on stderr
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProgressMode {
+    /// Redraw a status line in place, for terminals
+    Live,
+    /// Print a status line at every interval, for logs
+    Every(Duration),
+}
+
+/// Progress of a scan
+pub struct Progress {
+    mode: ProgressMode,
+    /// Estimated number of probes, hosts that fail to resolve are included
+    total: AtomicU64,
+    done: AtomicU64,
+    open: AtomicU64,
+    start: Instant,
+    /// Keeps results from being interleaved with the live status line
+    output: Mutex<()>,
+}
+
+impl Progress {
+    pub fn new(mode: ProgressMode, total: u64) -> Self {
+        Self {
+            mode,
+            total: AtomicU64::new(total),
+            done: AtomicU64::new(0),
+            open: AtomicU64::new(0),
+            start: Instant::now(),
+            output: Mutex::new(()),
+        }
+    }
+
+    /// Record a finished probe
+    pub fn inc(&self, open: bool) {
+        self.done.fetch_add(1, Ordering::Relaxed);
+        if open {
+            self.open.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Exclude probes done before resuming from the total
+    pub fn exclude(&self, probes: u64) {
+        let _ = self
+            .total
+            .fetch_update(Ordering::Relaxed, Ordering::Relaxed, |v| {
+                Some(v.saturating_sub(probes))
+            });
+    }
+
+    /// Print a result line to stdout without garbling the status line
+    pub fn println(&self, line: &str) {
+        let _guard = self.clear_line();
+        println!("{line}");
+    }
+
+    /// Print a warning line to stderr without garbling the status line
+    pub fn eprintln(&self, line: &str) {
+        let _guard = self.clear_line();
+        eprintln!("{line}");
+    }
+
+    fn clear_line(&self) -> MutexGuard<'_, ()> {
+        let guard = self.output.lock().unwrap();
+        if self.mode == ProgressMode::Live {
+            eprint!("\r\x1b[2K");
+        }
+        guard
+    }
+
+    /// Report progress until the future is dropped
+    pub async fn report(&self) {
+        let interval = match self.mode {
+            ProgressMode::Live => REDRAW_INTERVAL,
+            ProgressMode::Every(v) => v,
+        };
+        loop {
+            tokio::time::sleep(interval).await;
+            self.draw();
+        }
+    }
+
+    /// Report the final progress
+    pub fn finish(&self) {
+        let done = self.done.load(Ordering::Relaxed);
+        self.total.store(done, Ordering::Relaxed);
+        self.draw();
+        if self.mode == ProgressMode::Live {
+            eprintln!();
+        }
+    }
+
+    fn draw(&self) {
+        let _guard = self.output.lock().unwrap();
+        let status = self.status(self.start.elapsed());
+        let mut stderr = std::io::stderr().lock();
+        let _ = match self.mode {
+            ProgressMode::Live => write!(stderr, "\r\x1b[2K{status}"),
+            ProgressMode::Every(_) => writeln!(stderr, "{status}"),
+        };
+        let _ = stderr.flush();
+    }
+
+    fn status(&self, elapsed: Duration) -> String {
+        let done = self.done.load(Ordering::Relaxed);
+        let total = self.total.load(Ordering::Relaxed).max(done);
+        let open = self.open.load(Ordering::Relaxed);
+        let percent = match total {
+            0 => 100.0,
+            _ => done as f64 * 100.0 / total as f64,
+        };
+        let rate = done as f64 / elapsed.as_secs_f64().max(0.001);
+        let eta = match rate > 0.0 {
+            true => format_duration(Duration::from_secs_f64((total - done) as f64 / rate)),
+            false => "--:--".to_string(),
+        };
+        format!("{done}/{total} probes ({percent:.1}%), {rate:.0}/s, {open} open, ETA {eta}")
+    }
+}
+
+/// Format as `mm:ss` or `h:mm:ss`
+pub fn format_duration(duration: Duration) -> String {
+    let secs = duration.as_secs();
+    let (hours, minutes, seconds) = (secs / 3600, secs / 60 % 60, secs % 60);
+    match hours {
+        0 => format!("{minutes:02}:{seconds:02}"),
+        _ => format!("{hours}:{minutes:02}:{seconds:02}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_status() {
+        let progress = Progress::new(ProgressMode::Live, 1000);
+        for i in 0..250 {
+            progress.inc(i % 100 == 0);
+        }
+        assert_eq!(
+            progress.status(Duration::from_secs(5)),
+            "250/1000 probes (25.0%), 50/s, 3 open, ETA 00:15"
+        );
+        assert_eq!(format_duration(Duration::from_secs(3725)), "1:02:05");
+    }
+}
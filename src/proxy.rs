@@ -0,0 +1,413 @@
+use std::{fmt, io, net::SocketAddr, str::FromStr, sync::Arc};
+
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::TcpStream,
+    sync::OnceCell,
+};
+
+use crate::http;
+
+/// Proxy that probes are tunneled through
+#[derive(Debug, Clone)]
+pub struct Proxy {
+    pub kind: ProxyKind,
+    pub host: String,
+    pub port: u16,
+    pub auth: Option<(String, String)>,
+    /// Address of the proxy, resolved on first connect
+    addr: Arc<OnceCell<SocketAddr>>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProxyKind {
+    Socks5,
+    Http,
+}
+
+/// Where a tunnel leads to, host names are resolved by the proxy
+#[derive(Debug, Clone, Copy)]
+pub enum Destination<'a> {
+    Addr(SocketAddr),
+    Name(&'a str, u16),
+}
+
+impl fmt::Display for Destination<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Addr(addr) => addr.fmt(f),
+            Self::Name(name, port) => write!(f, "{name}:{port}"),
+        }
+    }
+}
+
+impl FromStr for Proxy {
+    type Err = String;
+
+    /// Parse `socks5://[user:pass@]host[:port]` or `http://[user:pass@]host[:port]`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || format!("Invalid proxy '{s}'");
+        let (scheme, rest) = s.split_once("://").ok_or_else(invalid)?;
+        let (kind, default_port) = match scheme.to_ascii_lowercase().as_str() {
+            "socks5" => (ProxyKind::Socks5, 1080),
+            "http" => (ProxyKind::Http, 80),
+            _ => return Err(format!("Unsupported proxy scheme '{scheme}'")),
+        };
+        let rest = rest.trim_end_matches('/');
+        let (auth, host) = match rest.rsplit_once('@') {
+            Some((auth, host)) => {
+                let (user, pass) = auth.split_once(':').unwrap_or((auth, ""));
+                (Some((user.to_string(), pass.to_string())), host)
+            }
+            None => (None, rest),
+        };
+        if host.is_empty() || host.contains('/') {
+            return Err(invalid());
+        }
+        let has_port = match host.rfind(']') {
+            Some(i) => host[i..].contains(':'),
+            None => host.contains(':'),
+        };
+        let (host, port) = match host.rsplit_once(':') {
+            Some((host, port)) if has_port => (host, port.parse().map_err(|_| invalid())?),
+            _ => (host, default_port),
+        };
+        let host = host.trim_start_matches('[').trim_end_matches(']');
+        if host.is_empty() {
+            return Err(invalid());
+        }
+        Ok(Self {
+            kind,
+            host: host.to_string(),
+            port,
+            auth,
+            addr: Default::default(),
+        })
+    }
+}
+
+impl fmt::Display for Proxy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.host.contains(':') {
+            true => write!(f, "[{}]:{}", self.host, self.port),
+            false => write!(f, "{}:{}", self.host, self.port),
+        }
+    }
+}
+
+impl Proxy {
+    /// Address of the proxy, its host is resolved once
+    ///
+    /// Errors are never `ConnectionRefused`, so they are not mistaken for closed ports.
+    pub async fn addr(&self) -> io::Result<SocketAddr> {
+        let addr = self
+            .addr
+            .get_or_try_init(|| async {
+                let mut addrs = tokio::net::lookup_host((self.host.as_str(), self.port))
+                    .await
+                    .map_err(|err| {
+                        io::Error::other(format!("Failed to resolve proxy '{self}', {err}"))
+                    })?;
+                addrs
+                    .next()
+                    .ok_or_else(|| io::Error::other(format!("Failed to resolve proxy '{self}'")))
+            })
+            .await?;
+        Ok(*addr)
+    }
+
+    /// Ask the proxy connected through `stream` to open a tunnel to `target`
+    ///
+    /// Replies meaning the target refused the connection are mapped to `ConnectionRefused`
+    /// and replies meaning it did not answer to `TimedOut`.
+    pub async fn handshake(
+        &self,
+        stream: &mut TcpStream,
+        target: Destination<'_>,
+    ) -> io::Result<()> {
+        match self.kind {
+            ProxyKind::Socks5 => socks5_handshake(stream, target, self.auth.as_ref()).await,
+            ProxyKind::Http => http_handshake(stream, target, self.auth.as_ref()).await,
+        }
+    }
+}
+
+async fn socks5_handshake<S: AsyncRead + AsyncWrite + Unpin>(
+    stream: &mut S,
+    target: Destination<'_>,
+    auth: Option<&(String, String)>,
+) -> io::Result<()> {
+    let methods: &[u8] = match auth {
+        Some(_) => &[0x00, 0x02],
+        None => &[0x00],
+    };
+    let mut greeting = vec![0x05, methods.len() as u8];
+    greeting.extend_from_slice(methods);
+    stream.write_all(&greeting).await?;
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply).await?;
+    match (reply, auth) {
+        ([0x05, 0x00], _) => {}
+        ([0x05, 0x02], Some((user, pass))) => {
+            if user.len() > 255 || pass.len() > 255 {
+                return Err(io::Error::other("socks5 credentials are too long"));
+            }
+            let mut request = vec![0x01, user.len() as u8];
+            request.extend_from_slice(user.as_bytes());
+            request.push(pass.len() as u8);
+            request.extend_from_slice(pass.as_bytes());
+            stream.write_all(&request).await?;
+            stream.read_exact(&mut reply).await?;
+            if reply[1] != 0x00 {
+                return Err(io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    "socks5 proxy rejected the credentials",
+                ));
+            }
+        }
+        _ => {
+            return Err(io::Error::other(
+                "socks5 proxy offered no acceptable auth method",
+            ))
+        }
+    }
+
+    let mut request = vec![0x05, 0x01, 0x00];
+    let port = match target {
+        Destination::Addr(SocketAddr::V4(v)) => {
+            request.push(0x01);
+            request.extend_from_slice(&v.ip().octets());
+            v.port()
+        }
+        Destination::Addr(SocketAddr::V6(v)) => {
+            request.push(0x04);
+            request.extend_from_slice(&v.ip().octets());
+            v.port()
+        }
+        Destination::Name(name, port) => {
+            if name.len() > 255 {
+                return Err(io::Error::other("host name is too long for socks5"));
+            }
+            request.push(0x03);
+            request.push(name.len() as u8);
+            request.extend_from_slice(name.as_bytes());
+            port
+        }
+    };
+    request.extend_from_slice(&port.to_be_bytes());
+    stream.write_all(&request).await?;
+
+    let mut head = [0u8; 4];
+    stream.read_exact(&mut head).await?;
+    if head[0] != 0x05 {
+        return Err(io::Error::other("invalid socks5 reply"));
+    }
+    match head[1] {
+        0x00 => {}
+        0x03 => return Err(io::ErrorKind::NetworkUnreachable.into()),
+        0x04 => return Err(io::ErrorKind::HostUnreachable.into()),
+        0x05 => return Err(io::ErrorKind::ConnectionRefused.into()),
+        0x06 => return Err(io::ErrorKind::TimedOut.into()),
+        code => {
+            return Err(io::Error::other(format!(
+                "socks5 proxy replied {code:#04x}"
+            )))
+        }
+    }
+    // Skip the bound address
+    let len = match head[3] {
+        0x01 => 4,
+        0x04 => 16,
+        0x03 => stream.read_u8().await? as usize,
+        _ => return Err(io::Error::other("invalid socks5 reply")),
+    };
+    let mut bound = vec![0u8; len + 2];
+    stream.read_exact(&mut bound).await?;
+    Ok(())
+}
+
+async fn http_handshake<S: AsyncRead + AsyncWrite + Unpin>(
+    stream: &mut S,
+    target: Destination<'_>,
+    auth: Option<&(String, String)>,
+) -> io::Result<()> {
+    let mut request = format!("CONNECT {target} HTTP/1.1\r\nHost: {target}\r\n");
+    if let Some((user, pass)) = auth {
+        let credentials = base64(format!("{user}:{pass}").as_bytes());
+        request.push_str(&format!("Proxy-Authorization: Basic {credentials}\r\n"));
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await?;
+
+    match http::read_status(stream).await? {
+        200..=299 => Ok(()),
+        407 => Err(io::Error::new(
+            io::ErrorKind::PermissionDenied,
+            "http proxy requires authentication",
+        )),
+        504 => Err(io::ErrorKind::TimedOut.into()),
+        // Proxies report a failed upstream connect as 502 or 503
+        502 | 503 => Err(io::ErrorKind::ConnectionRefused.into()),
+        code => Err(io::Error::other(format!("http proxy replied {code}"))),
+    }
+}
+
+fn base64(input: &[u8]) -> String {
+    const CHARS: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut output = String::with_capacity(input.len().div_ceil(3) * 4);
+    for chunk in input.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, v)| n | (*v as u32) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                output.push(CHARS[(n >> (18 - 6 * i) & 0x3f) as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+    output
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{exchange, runtime};
+    use tokio::net::TcpListener;
+
+    /// Accept one connection, then for each step expect its request and answer its reply
+    async fn stand_in(listener: TcpListener, steps: Vec<(Vec<u8>, Vec<u8>)>) {
+        let (mut stream, _) = listener.accept().await.unwrap();
+        for (request, reply) in steps {
+            let received = exchange(&mut stream, |v| v.len() >= request.len(), &reply).await;
+            assert_eq!(
+                String::from_utf8_lossy(&received),
+                String::from_utf8_lossy(&request)
+            );
+        }
+    }
+
+    fn connect(proxy: &str, target: &str, steps: Vec<(Vec<u8>, Vec<u8>)>) -> io::Result<()> {
+        runtime().block_on(async {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let proxy: Proxy = proxy
+                .replace("PORT", &listener.local_addr().unwrap().port().to_string())
+                .parse()
+                .unwrap();
+            let server = tokio::spawn(stand_in(listener, steps));
+            let mut stream = TcpStream::connect(proxy.addr().await?).await?;
+            let target = match target.parse() {
+                Ok(addr) => Destination::Addr(addr),
+                Err(_) => {
+                    let (name, port) = target.rsplit_once(':').unwrap();
+                    Destination::Name(name, port.parse().unwrap())
+                }
+            };
+            let result = proxy.handshake(&mut stream, target).await;
+            server.await.unwrap();
+            result
+        })
+    }
+
+    #[test]
+    fn test_parse_proxy() {
+        let proxy: Proxy = "socks5://127.0.0.1".parse().unwrap();
+        assert_eq!(proxy.kind, ProxyKind::Socks5);
+        assert_eq!(proxy.to_string(), "127.0.0.1:1080");
+        let proxy: Proxy = "http://user:p@ss@[::1]:3128/".parse().unwrap();
+        assert_eq!(proxy.kind, ProxyKind::Http);
+        assert_eq!(proxy.to_string(), "[::1]:3128");
+        assert_eq!(proxy.auth, Some(("user".into(), "p@ss".into())));
+        // Host names are resolved when connecting
+        let proxy: Proxy = "socks5://bastion.invalid:1081".parse().unwrap();
+        assert_eq!((proxy.host.as_str(), proxy.port), ("bastion.invalid", 1081));
+        assert!("socks5://bastion:port".parse::<Proxy>().is_err());
+        assert!("ftp://127.0.0.1".parse::<Proxy>().is_err());
+        assert!("127.0.0.1:1080".parse::<Proxy>().is_err());
+    }
+
+    #[test]
+    fn test_base64() {
+        assert_eq!(base64(b""), "");
+        assert_eq!(base64(b"f"), "Zg==");
+        assert_eq!(base64(b"fo"), "Zm8=");
+        assert_eq!(base64(b"foo"), "Zm9v");
+        assert_eq!(base64(b"user:pass"), "dXNlcjpwYXNz");
+    }
+
+    #[test]
+    fn test_socks5_handshake() {
+        let connect_step = |code: u8| {
+            (
+                vec![5, 1, 0, 1, 10, 0, 0, 1, 0, 22],
+                vec![5, code, 0, 1, 0, 0, 0, 0, 0, 0],
+            )
+        };
+        let steps = |code: u8| vec![(vec![5, 1, 0], vec![5, 0]), connect_step(code)];
+        let proxy = "socks5://127.0.0.1:PORT";
+        connect(proxy, "10.0.0.1:22", steps(0)).unwrap();
+        let auth_steps = vec![
+            (vec![5, 2, 0, 2], vec![5, 2]),
+            (vec![1, 1, b'u', 1, b'p'], vec![1, 0]),
+            connect_step(0),
+        ];
+        connect("socks5://u:p@127.0.0.1:PORT", "10.0.0.1:22", auth_steps).unwrap();
+        let err = connect(proxy, "10.0.0.1:22", steps(5)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::ConnectionRefused);
+        let err = connect(proxy, "10.0.0.1:22", steps(6)).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+        // Host names are sent for the proxy to resolve
+        let name_steps = vec![
+            (vec![5, 1, 0], vec![5, 0]),
+            (
+                [&[5, 1, 0, 3, 3][..], b"db1", &[0, 22]].concat(),
+                vec![5, 0, 0, 1, 0, 0, 0, 0, 0, 0],
+            ),
+        ];
+        connect(proxy, "db1:22", name_steps).unwrap();
+    }
+
+    #[test]
+    fn test_http_handshake() {
+        let step = |target: &str, auth: bool, status: &str| {
+            let mut request = format!("CONNECT {target} HTTP/1.1\r\nHost: {target}\r\n");
+            if auth {
+                request.push_str("Proxy-Authorization: Basic dTpw\r\n");
+            }
+            request.push_str("\r\n");
+            let reply = format!("HTTP/1.1 {status}\r\n\r\n");
+            vec![(request.into_bytes(), reply.into_bytes())]
+        };
+        let proxy = "http://127.0.0.1:PORT";
+        let target = "10.0.0.1:22";
+        connect(
+            proxy,
+            target,
+            step(target, false, "200 Connection established"),
+        )
+        .unwrap();
+        connect(
+            "http://u:p@127.0.0.1:PORT",
+            target,
+            step(target, true, "200 OK"),
+        )
+        .unwrap();
+        connect(proxy, "db1:22", step("db1:22", false, "200 OK")).unwrap();
+        let err = connect(
+            proxy,
+            target,
+            step(target, false, "503 Service Unavailable"),
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::ConnectionRefused);
+        let err = connect(
+            proxy,
+            target,
+            step(target, false, "407 Proxy Authentication Required"),
+        )
+        .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+    }
+}
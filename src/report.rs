@@ -0,0 +1,22 @@
+use serde::Serialize;
+
+use crate::{baseline::Change, policy::Violation, scanner::OpenPort, summary::Summary};
+
+/// Results of a scan, printed as JSON with `--json`
+#[derive(Debug, Serialize)]
+pub struct Report<'a> {
+    /// Kept with `--diff-only`, so the report can be the baseline of the next scan
+    pub open: &'a [OpenPort],
+    /// Changes from `--baseline`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<&'a [Change]>,
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub violations: &'a [Violation],
+    /// Failed `--expect-open` and `--expect-closed` assertions
+    #[serde(skip_serializing_if = "<[_]>::is_empty")]
+    pub failures: &'a [String],
+    pub summary: &'a Summary,
+    /// The scan is stopped by a signal before it finished
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub interrupted: bool,
+}
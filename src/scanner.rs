@@ -1,65 +1,981 @@
-use std::{net::SocketAddr, time::Duration};
+use std::{
+    collections::{BTreeMap, HashSet},
+    fmt, fs, io,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+    path::PathBuf,
+    sync::{
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering},
+        Arc, Mutex,
+    },
+    time::{Duration, Instant, SystemTime},
+};
 
-use futures::{stream::FuturesUnordered, StreamExt};
-use tokio::net::TcpStream;
+use futures::{future, future::BoxFuture, pin_mut, stream, Future, FutureExt, Stream, StreamExt};
+use serde::{Deserialize, Serialize};
+use tokio::{
+    net::{TcpSocket, TcpStream},
+    sync::oneshot,
+    task::JoinHandle,
+};
 
-use crate::ports::NAMP_TOP_PORTS;
+use crate::{
+    addresses::{AddrCategory, Host, ReverseResolver, Slot, Target},
+    baseline::{Baseline, Change},
+    checkpoint::{Checkpoint, Tracker},
+    event::Event,
+    expect::Assertions,
+    hooks::Hooks,
+    policy::{Policy, Violation},
+    ports,
+    progress::{Progress, ProgressMode},
+    proxy::{Destination, Proxy},
+    summary::{error_name, Summary},
+};
 
-pub struct Scanner<'a> {
-    addrs: &'a [(SocketAddr, String)],
+/// Attempts per probe when local ports are exhausted
+const MAX_ATTEMPTS: usize = 4;
+/// Interval of saving checkpoints
+const CHECKPOINT_INTERVAL: Duration = Duration::from_secs(10);
+/// Wait before retrying a probe once every source address is exhausted
+const EXHAUSTED_BACKOFF: Duration = Duration::from_millis(100);
+/// Category of hosts resolved by the proxy
+const UNRESOLVED: &str = "unresolved";
+
+/// Local endpoint that probes are sent from
+#[derive(Debug, Default)]
+pub struct Bind {
+    /// Source addresses, probes are distributed across them in turn
+    pub ips: Vec<IpAddr>,
+    pub port: Option<u16>,
+    /// Network interface, Linux only
+    pub interface: Option<String>,
+}
+
+/// Outcome of probing a port
+#[derive(Debug)]
+pub enum Status {
+    Open,
+    /// Connection refused
+    Closed,
+    /// No response before the timeout
+    Filtered,
+    Error(io::Error),
+}
+
+impl Status {
+    fn of(result: io::Result<TcpStream>) -> Self {
+        match result {
+            Ok(_) => Self::Open,
+            Err(err) => match err.kind() {
+                io::ErrorKind::ConnectionRefused => Self::Closed,
+                io::ErrorKind::TimedOut
+                | io::ErrorKind::HostUnreachable
+                | io::ErrorKind::NetworkUnreachable => Self::Filtered,
+                _ => Self::Error(err),
+            },
+        }
+    }
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Status::Open => write!(f, "open"),
+            Status::Closed => write!(f, "closed"),
+            Status::Filtered => write!(f, "filtered"),
+            Status::Error(_) => write!(f, "error"),
+        }
+    }
+}
+
+/// An open port found by a scan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OpenPort {
+    pub host: String,
+    pub ip: IpAddr,
+    pub port: u16,
+    pub service: String,
+    pub category: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hostname: Option<String>,
+}
+
+pub struct Scanner {
+    ports: Arc<[u16]>,
     timeout: Duration,
     concurrent: usize,
+    bind: Bind,
+    proxy: Option<Proxy>,
+    next_source: AtomicUsize,
+    hosts_up: AtomicUsize,
+    open: AtomicU64,
+    closed: AtomicU64,
+    filtered: AtomicU64,
+    retries: AtomicU64,
+    errors: Mutex<BTreeMap<String, u64>>,
+    exhausted_warned: AtomicBool,
+    error_warned: AtomicBool,
+    reverse_resolver: Option<ReverseResolver>,
+    /// Estimated number of probes
+    probes: u64,
+    progress: Option<Arc<Progress>>,
+    assertions: Option<Assertions>,
+    policy: Option<Policy>,
+    baseline: Option<Baseline>,
+    hooks: Option<Hooks>,
+    /// File to save progress to and the checkpoint resumed from
+    checkpoint: Option<(PathBuf, Checkpoint)>,
+    /// Progress is saved periodically if true, otherwise only when interrupted
+    checkpoint_periodic: bool,
+    /// Done probes, tracked if checkpointing
+    tracker: Mutex<Tracker>,
+    checkpoint_warned: AtomicBool,
+    /// Stops the scan once it completes, taken by `run`
+    interrupt: Mutex<Option<BoxFuture<'static, ()>>>,
+    /// Exit on a signal once the scan is stopped or finished
+    exit_on_signal: bool,
+    interrupted: AtomicBool,
+    /// Open ports are collected if some
+    open_ports: Option<Mutex<Vec<OpenPort>>>,
+    /// Open ports are not printed if true
+    quiet: bool,
+    /// Labels and IPs of up hosts are collected if some
+    up_hosts: Option<Mutex<HashSet<(String, IpAddr)>>>,
+    max_addr_len: usize,
+    max_port_len: usize,
+    max_name_len: usize,
 }
 
-impl<'a> Scanner<'a> {
-    pub fn new(addrs: &'a [(SocketAddr, String)], timeout: Duration, concurrent: usize) -> Self {
+impl Scanner {
+    pub fn new(ports: Vec<u16>, timeout: Duration, concurrent: usize, targets: &[Target]) -> Self {
+        let max_addr_len = targets.iter().map(|v| v.width()).max().unwrap_or_default();
+        let all_ports = || {
+            targets
+                .iter()
+                .flat_map(|v| v.ports.iter().flat_map(|v| v.iter()))
+                .chain(ports.iter())
+        };
+        let max_port_len = all_ports()
+            .map(|v| v.to_string().len())
+            .max()
+            .unwrap_or_default();
+        let max_name_len = all_ports()
+            .map(|v| port_name(*v).len())
+            .max()
+            .unwrap_or_default();
+        let probes = targets
+            .iter()
+            .map(|v| v.host_count() * v.ports.as_ref().map_or(ports.len(), |v| v.len()) as u64)
+            .sum();
+        let ports = ports.into();
         Self {
-            addrs,
+            ports,
             timeout,
             concurrent,
+            bind: Bind::default(),
+            proxy: None,
+            next_source: AtomicUsize::new(0),
+            hosts_up: AtomicUsize::new(0),
+            open: AtomicU64::new(0),
+            closed: AtomicU64::new(0),
+            filtered: AtomicU64::new(0),
+            retries: AtomicU64::new(0),
+            errors: Mutex::new(BTreeMap::new()),
+            exhausted_warned: AtomicBool::new(false),
+            error_warned: AtomicBool::new(false),
+            reverse_resolver: None,
+            probes,
+            progress: None,
+            assertions: None,
+            policy: None,
+            baseline: None,
+            hooks: None,
+            checkpoint: None,
+            checkpoint_periodic: false,
+            tracker: Mutex::new(Tracker::default()),
+            checkpoint_warned: AtomicBool::new(false),
+            interrupt: Mutex::new(None),
+            exit_on_signal: false,
+            interrupted: AtomicBool::new(false),
+            open_ports: None,
+            quiet: false,
+            up_hosts: None,
+            max_addr_len,
+            max_port_len,
+            max_name_len,
+        }
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
+    }
+
+    pub fn concurrency(&self) -> usize {
+        self.concurrent
+    }
+
+    /// Send probes from the local endpoint
+    pub fn bind(mut self, bind: Bind) -> Self {
+        self.bind = bind;
+        self
+    }
+
+    /// Tunnel probes through the proxy
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Lookup hostnames of IPs that have open ports
+    pub fn reverse_dns(mut self, resolver: ReverseResolver) -> Self {
+        self.reverse_resolver = Some(resolver);
+        self
+    }
+
+    /// Check probe results against the assertions
+    pub fn assertions(mut self, assertions: Assertions) -> Self {
+        self.assertions = Some(assertions);
+        self
+    }
+
+    /// Failed assertions, available after the scan
+    pub fn failures(&self) -> Vec<String> {
+        self.assertions
+            .as_ref()
+            .map(|v| v.failures())
+            .unwrap_or_default()
+    }
+
+    /// Check probe results against the policy
+    pub fn policy(mut self, policy: Policy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Policy violations, available after the scan
+    pub fn violations(&self) -> Vec<Violation> {
+        self.policy
+            .as_ref()
+            .map(|v| v.violations())
+            .unwrap_or_default()
+    }
+
+    /// Compare probe results with the baseline
+    pub fn baseline(mut self, baseline: Baseline) -> Self {
+        self.baseline = Some(baseline);
+        self
+    }
+
+    /// Notify hooks of open ports
+    pub fn hooks(mut self, hooks: Hooks) -> Self {
+        self.hooks = Some(hooks);
+        self
+    }
+
+    /// Save progress to `path` periodically and resume from `checkpoint`
+    ///
+    /// Open ports and up hosts are collected to be saved.
+    pub fn checkpoint(mut self, path: PathBuf, checkpoint: Checkpoint) -> Self {
+        self.tracker = Mutex::new(Tracker::new(checkpoint.next, &checkpoint.done));
+        self.checkpoint = Some((path, checkpoint));
+        self.checkpoint_periodic = true;
+        self.open_ports.get_or_insert_with(Default::default);
+        self.up_hosts.get_or_insert_with(Default::default);
+        self
+    }
+
+    /// Save progress to `path` only if the scan is interrupted
+    pub fn checkpoint_on_interrupt(self, path: PathBuf, checkpoint: Checkpoint) -> Self {
+        let mut scanner = self.checkpoint(path, checkpoint);
+        scanner.checkpoint_periodic = false;
+        scanner
+    }
+
+    /// Stop the scan once `interrupt` completes
+    pub fn interrupt_on(self, interrupt: impl Future<Output = ()> + Send + 'static) -> Self {
+        *self.interrupt.lock().unwrap() = Some(interrupt.boxed());
+        self
+    }
+
+    /// Stop the scan on SIGINT or SIGTERM, a second signal exits immediately
+    pub fn interruptible(mut self) -> Self {
+        self.exit_on_signal = true;
+        self.interrupt_on(wait_for_signal())
+    }
+
+    /// Whether the scan was stopped by a signal or by the future of `interrupt_on`
+    pub fn interrupted(&self) -> bool {
+        self.interrupted.load(Ordering::Relaxed)
+    }
+
+    /// Changes from the baseline, available after the scan
+    pub fn changes(&self) -> Vec<Change> {
+        self.baseline
+            .as_ref()
+            .map(|v| v.changes())
+            .unwrap_or_default()
+    }
+
+    /// Collect open ports
+    pub fn collect_open_ports(mut self) -> Self {
+        self.open_ports = Some(Mutex::new(vec![]));
+        self
+    }
+
+    /// Do not print open ports
+    pub fn quiet(mut self) -> Self {
+        self.quiet = true;
+        self
+    }
+
+    /// Collected open ports, sorted by IP and port
+    pub fn open_ports(&self) -> Vec<OpenPort> {
+        let mut open_ports = match &self.open_ports {
+            Some(v) => v.lock().unwrap().clone(),
+            None => vec![],
+        };
+        open_ports.sort_by(|a, b| (a.ip, a.port, &a.host).cmp(&(b.ip, b.port, &b.host)));
+        open_ports
+    }
+
+    /// Collect the hosts that are up
+    pub fn collect_up_hosts(mut self) -> Self {
+        self.up_hosts = Some(Mutex::new(HashSet::new()));
+        self
+    }
+
+    /// Labels and IPs of collected up hosts
+    pub fn up_hosts(&self) -> HashSet<(String, IpAddr)> {
+        match &self.up_hosts {
+            Some(v) => v.lock().unwrap().clone(),
+            None => HashSet::new(),
+        }
+    }
+
+    /// Report progress on stderr
+    pub fn progress(mut self, mode: ProgressMode) -> Self {
+        self.progress = Some(Arc::new(Progress::new(mode, self.probes)));
+        self
+    }
+
+    /// Scan ports of the hosts yielded by `slots`
+    pub async fn run(&self, slots: impl Stream<Item = Slot>) -> Summary {
+        let start = Instant::now();
+        let resumed = match &self.checkpoint {
+            Some((_, checkpoint)) => {
+                self.resume(checkpoint);
+                checkpoint.summary.elapsed
+            }
+            None => Duration::ZERO,
+        };
+        let mut count = 0;
+        let scan = slots
+            .flat_map(|slot| {
+                let (host, ports) = match slot {
+                    Slot::Host(host) => {
+                        count += 1;
+                        let up = Arc::new(AtomicBool::new(self.was_up(&host)));
+                        let ports = host.ports.clone().unwrap_or_else(|| self.ports.clone());
+                        (Some((Arc::new(host), up)), ports)
+                    }
+                    Slot::Unresolved(ports) => (None, ports.unwrap_or_else(|| self.ports.clone())),
+                };
+                stream::iter((0..ports.len()).map(move |i| (host.clone(), ports[i])))
+            })
+            .enumerate()
+            .filter_map(|(index, (host, port))| {
+                let index = index as u64;
+                let probe = match host {
+                    _ if self.is_done(index) => None,
+                    Some((host, up)) => Some((index, host, up, port)),
+                    None => {
+                        self.skip(index);
+                        None
+                    }
+                };
+                future::ready(probe)
+            })
+            .map(|(index, host, up, port)| async move {
+                let status = self.scan_addr(&host, port).await;
+                // Still in the poll that recorded the result, see `scan_addr`
+                if matches!(status, Status::Open | Status::Closed)
+                    && !up.swap(true, Ordering::Relaxed)
+                {
+                    self.hosts_up.fetch_add(1, Ordering::Relaxed);
+                    if let Some(up_hosts) = &self.up_hosts {
+                        up_hosts
+                            .lock()
+                            .unwrap()
+                            .insert((host.label.clone(), host.ip));
+                    }
+                }
+                if self.checkpoint.is_some() {
+                    self.tracker.lock().unwrap().complete(index);
+                }
+            })
+            .buffer_unordered(self.concurrent)
+            .collect::<()>();
+        let report = async {
+            match &self.progress {
+                Some(progress) => progress.report().await,
+                None => future::pending().await,
+            }
+        };
+        // Probes are all polled by this task, so checkpoints are saved between results
+        let save = async {
+            match &self.checkpoint {
+                Some(_) if self.checkpoint_periodic => loop {
+                    tokio::time::sleep(CHECKPOINT_INTERVAL).await;
+                    self.save_checkpoint(resumed + start.elapsed());
+                },
+                _ => future::pending().await,
+            }
+        };
+        // The first signal stops the scan, dropping the probes in flight, which are not
+        // marked done and so are probed again on resume. Once the scan is stopped or
+        // finished, a signal exits without waiting for hooks.
+        let (interrupt_tx, interrupt_rx) = oneshot::channel();
+        let exit_on_signal = self.exit_on_signal;
+        let _listener = self.interrupt.lock().unwrap().take().map(|interrupt| {
+            AbortOnDrop(tokio::spawn(async move {
+                interrupt.await;
+                let stopped = interrupt_tx.send(()).is_ok();
+                if exit_on_signal {
+                    if stopped {
+                        wait_for_signal().await;
+                    }
+                    std::process::exit(crate::EXIT_INTERRUPTED);
+                }
+            }))
+        });
+        let interrupt = async {
+            match interrupt_rx.await {
+                Ok(()) => {
+                    self.interrupted.store(true, Ordering::Relaxed);
+                    self.eprintln(match self.exit_on_signal {
+                        true => "Interrupted, press Ctrl-C again to exit immediately",
+                        false => "Interrupted",
+                    });
+                }
+                Err(_) => future::pending().await,
+            }
+        };
+        {
+            let background = future::join(report, save);
+            pin_mut!(scan, background, interrupt);
+            // Polled before the scan, which may use up the budget of tokio resources
+            future::select(future::select(background, interrupt), scan).await;
+        }
+        let elapsed = resumed + start.elapsed();
+        if let Some((path, _)) = &self.checkpoint {
+            if self.interrupted() {
+                if self.save_checkpoint(elapsed) {
+                    self.eprintln(&format!(
+                        "Saved progress to '{}', continue the scan with --resume",
+                        path.display()
+                    ));
+                }
+            } else if self.checkpoint_periodic {
+                if let Err(err) = fs::remove_file(path) {
+                    if err.kind() != io::ErrorKind::NotFound {
+                        self.eprintln(&format!(
+                            "warning: Failed to remove checkpoint '{}', {err}",
+                            path.display()
+                        ));
+                    }
+                }
+            }
+        }
+        if let Some(progress) = &self.progress {
+            progress.finish();
+        }
+        let summary = self.summary(count, elapsed);
+        if let Some(hooks) = &self.hooks {
+            hooks.finish().await;
+        }
+        if self.exit_on_signal {
+            // Signals while the results are saved and printed exit as usual
+            restore_default_signals();
+        }
+        summary
+    }
+
+    fn summary(&self, hosts: usize, elapsed: Duration) -> Summary {
+        Summary {
+            hosts,
+            hosts_up: self.hosts_up.load(Ordering::Relaxed),
+            open: self.open.load(Ordering::Relaxed),
+            closed: self.closed.load(Ordering::Relaxed),
+            filtered: self.filtered.load(Ordering::Relaxed),
+            retries: self.retries.load(Ordering::Relaxed),
+            errors: self.errors.lock().unwrap().clone(),
+            elapsed,
         }
     }
 
-    pub async fn run(&self) {
-        let mut max_addr_len = 0;
-        let mut max_port_len = 0;
-        for (socket_addr, raw_addr) in self.addrs.iter() {
-            max_port_len = max_port_len.max(socket_addr.port().to_string().len());
-            max_addr_len = max_addr_len.max(raw_addr.len());
+    /// Restore the results of the probes done before the checkpoint
+    fn resume(&self, checkpoint: &Checkpoint) {
+        let summary = &checkpoint.summary;
+        self.open.store(summary.open, Ordering::Relaxed);
+        self.closed.store(summary.closed, Ordering::Relaxed);
+        self.filtered.store(summary.filtered, Ordering::Relaxed);
+        self.retries.store(summary.retries, Ordering::Relaxed);
+        *self.errors.lock().unwrap() = summary.errors.clone();
+        self.hosts_up
+            .store(checkpoint.up_hosts.len(), Ordering::Relaxed);
+        if let Some(up_hosts) = &self.up_hosts {
+            up_hosts
+                .lock()
+                .unwrap()
+                .extend(checkpoint.up_hosts.iter().cloned());
         }
-        let mut addr_iter = self.addrs.iter();
-        let mut ftrs = FuturesUnordered::new();
+        if let Some(progress) = &self.progress {
+            progress.exclude(checkpoint.done_count());
+        }
+        for open_port in &checkpoint.open_ports {
+            if !self.quiet {
+                self.print_open_port(open_port);
+            }
+            if let Some(open_ports) = &self.open_ports {
+                open_ports.lock().unwrap().push(open_port.clone());
+            }
+        }
+    }
+
+    /// Whether `host` was up before the checkpoint
+    fn was_up(&self, host: &Host) -> bool {
+        match (&self.checkpoint, &self.up_hosts) {
+            (Some(_), Some(up_hosts)) => up_hosts
+                .lock()
+                .unwrap()
+                .contains(&(host.label.clone(), host.ip)),
+            _ => false,
+        }
+    }
+
+    fn is_done(&self, index: u64) -> bool {
+        self.checkpoint.is_some() && self.tracker.lock().unwrap().is_done(index)
+    }
 
-        for _ in 0..self.concurrent {
-            if let Some((socket_addr, raw_addr)) = addr_iter.next() {
-                ftrs.push(self.scan_addr(socket_addr, raw_addr, max_addr_len, max_port_len))
-            } else {
-                break;
+    /// Skip the probe of a host that failed to resolve, it is done as far as resuming goes
+    fn skip(&self, index: u64) {
+        if let Some(progress) = &self.progress {
+            progress.exclude(1);
+        }
+        if self.checkpoint.is_some() {
+            self.tracker.lock().unwrap().complete(index);
+        }
+    }
+
+    /// Save progress, returning whether it is saved
+    fn save_checkpoint(&self, elapsed: Duration) -> bool {
+        let Some((path, resumed)) = &self.checkpoint else {
+            return false;
+        };
+        let (next, done) = self.tracker.lock().unwrap().position();
+        let checkpoint = Checkpoint {
+            targets: resumed.targets.clone(),
+            files: resumed.files.clone(),
+            ports: resumed.ports.clone(),
+            fingerprint: resumed.fingerprint,
+            next,
+            done,
+            summary: self.summary(0, elapsed),
+            up_hosts: self.up_hosts().into_iter().collect(),
+            open_ports: self.open_ports(),
+        };
+        // Without --checkpoint, the file is not overwritten as it was not asked for
+        let result = match self.checkpoint_periodic {
+            true => checkpoint.save(path),
+            false => checkpoint.save_new(path),
+        };
+        match result {
+            Ok(()) => true,
+            Err(err) if err.kind() == io::ErrorKind::AlreadyExists => {
+                self.eprintln(&format!(
+                    "warning: Not saving progress, '{}' already exists",
+                    path.display()
+                ));
+                false
+            }
+            Err(err) => {
+                if !self.checkpoint_warned.swap(true, Ordering::Relaxed) {
+                    self.eprintln(&format!(
+                        "warning: Failed to save checkpoint '{}', {err}",
+                        path.display()
+                    ));
+                }
+                false
             }
         }
+    }
 
-        while (ftrs.next().await).is_some() {
-            if let Some((socket_addr, raw_addr)) = addr_iter.next() {
-                ftrs.push(self.scan_addr(socket_addr, raw_addr, max_addr_len, max_port_len))
+    /// Probe `port` of `host` and record the result
+    ///
+    /// Nothing is awaited while recording, so results are never seen half recorded.
+    pub async fn scan_addr(&self, host: &Host, port: u16) -> Status {
+        let addr = host.destination(port);
+        let status = self.probe(addr).await;
+        let hostname = match (&status, &self.reverse_resolver) {
+            (Status::Open, Some(reverse_resolver)) if !host.by_name => {
+                reverse_resolver.lookup(host.ip).await
+            }
+            _ => None,
+        };
+        match &status {
+            Status::Open => {
+                self.open.fetch_add(1, Ordering::Relaxed);
+            }
+            Status::Closed => {
+                self.closed.fetch_add(1, Ordering::Relaxed);
+            }
+            Status::Filtered => {
+                self.filtered.fetch_add(1, Ordering::Relaxed);
+            }
+            Status::Error(err) => {
+                if err.kind() != io::ErrorKind::AddrNotAvailable
+                    && !self.error_warned.swap(true, Ordering::Relaxed)
+                {
+                    self.eprintln(&format!("warning: probing {addr} failed: {err}"));
+                }
+                *self
+                    .errors
+                    .lock()
+                    .unwrap()
+                    .entry(error_name(err))
+                    .or_default() += 1;
             }
         }
+        if let Some(progress) = &self.progress {
+            progress.inc(matches!(status, Status::Open));
+        }
+        if let Some(assertions) = &self.assertions {
+            assertions.record(host, port, &status);
+        }
+        if let Some(policy) = &self.policy {
+            policy.record(host, port, &status);
+        }
+        if let Some(baseline) = &self.baseline {
+            baseline.record(host, port, &status, port_name(port));
+        }
+        if let (Status::Open, Some(hooks)) = (&status, &self.hooks) {
+            let event = Event::open(host, port, port_name(port), SystemTime::now());
+            hooks.fire(event, self.printer());
+        }
+        if let Status::Open = &status {
+            let open_port = OpenPort {
+                host: host.label.clone(),
+                ip: host.ip,
+                port,
+                service: port_name(port).to_string(),
+                category: match host.by_name {
+                    true => UNRESOLVED.to_string(),
+                    false => AddrCategory::of(host.ip).to_string(),
+                },
+                hostname,
+            };
+            if !self.quiet {
+                self.print_open_port(&open_port);
+            }
+            if let Some(open_ports) = &self.open_ports {
+                open_ports.lock().unwrap().push(open_port);
+            }
+        }
+        status
+    }
+
+    /// Probe a port, retrying from other source addresses when local ports are exhausted
+    pub async fn probe(&self, addr: Destination<'_>) -> Status {
+        let sources = match addr {
+            Destination::Addr(addr) => self.sources(&addr).len(),
+            Destination::Name(..) => self.bind.ips.len(),
+        }
+        .max(1);
+        for attempt in 1..=MAX_ATTEMPTS {
+            match self.connect_addr(addr).await {
+                Err(err) if err.kind() == io::ErrorKind::AddrNotAvailable => {
+                    if !self.exhausted_warned.swap(true, Ordering::Relaxed) {
+                        self.eprintln(
+                            "warning: local ports exhausted, retrying probes; \
+                             lower -c or add --source-ip addresses",
+                        );
+                    }
+                    if attempt == MAX_ATTEMPTS {
+                        return Status::Error(err);
+                    }
+                    self.retries.fetch_add(1, Ordering::Relaxed);
+                    if attempt % sources == 0 {
+                        tokio::time::sleep(EXHAUSTED_BACKOFF).await;
+                    }
+                }
+                result => return Status::of(result),
+            }
+        }
+        unreachable!()
+    }
+
+    pub async fn connect_addr(&self, addr: Destination<'_>) -> tokio::io::Result<TcpStream> {
+        let proxy = match (&self.proxy, addr) {
+            (Some(v), _) => v,
+            (None, Destination::Addr(addr)) => {
+                let socket = self.new_socket(&addr)?;
+                return tokio::time::timeout(self.timeout, socket.connect(addr)).await?;
+            }
+            (None, Destination::Name(..)) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("{addr} is not resolved"),
+                ))
+            }
+        };
+        tokio::time::timeout(self.timeout, async move {
+            let proxy_addr = proxy.addr().await?;
+            let socket = self.new_socket(&proxy_addr)?;
+            let mut stream = socket.connect(proxy_addr).await.map_err(|err| {
+                // Keep exhaustion retryable, otherwise do not mistake proxy errors for closed ports
+                let kind = match err.kind() {
+                    io::ErrorKind::AddrNotAvailable => err.kind(),
+                    _ => io::ErrorKind::Other,
+                };
+                io::Error::new(kind, format!("proxy {proxy} is unreachable, {err}"))
+            })?;
+            proxy.handshake(&mut stream, addr).await?;
+            Ok(stream)
+        })
+        .await?
+    }
+
+    /// Source addresses of the same family as `addr`
+    fn sources(&self, addr: &SocketAddr) -> Vec<IpAddr> {
+        self.bind
+            .ips
+            .iter()
+            .filter(|v| v.is_ipv4() == addr.is_ipv4())
+            .copied()
+            .collect()
     }
 
-    pub async fn scan_addr(
-        &self,
-        socket_addr: &SocketAddr,
-        raw_addr: &str,
-        max_addr_len: usize,
-        max_port_len: usize,
-    ) {
-        if self.connect_addr(socket_addr).await.is_ok() {
-            let port = socket_addr.port();
-            let name = NAMP_TOP_PORTS.get(&port).unwrap_or(&"unknown");
-            println!("{raw_addr:max_addr_len$} {port:<max_port_len$} {name}");
+    /// Create a socket bound to the local endpoint
+    fn new_socket(&self, addr: &SocketAddr) -> io::Result<TcpSocket> {
+        let socket = match addr {
+            SocketAddr::V4(_) => TcpSocket::new_v4()?,
+            SocketAddr::V6(_) => TcpSocket::new_v6()?,
+        };
+        #[cfg(any(target_os = "android", target_os = "fuchsia", target_os = "linux"))]
+        if let Some(interface) = &self.bind.interface {
+            socket.bind_device(Some(interface.as_bytes()))?;
         }
+        // Reset instead of lingering in TIME_WAIT, which holds on to the local port
+        socket.set_linger(Some(Duration::ZERO))?;
+        if !self.bind.ips.is_empty() || self.bind.port.is_some() {
+            let sources = self.sources(addr);
+            let ip = match (sources.is_empty(), addr) {
+                (false, _) => {
+                    sources[self.next_source.fetch_add(1, Ordering::Relaxed) % sources.len()]
+                }
+                (true, _) if !self.bind.ips.is_empty() => {
+                    return Err(io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("no source IP of the same family as {addr}"),
+                    ))
+                }
+                (true, SocketAddr::V4(_)) => IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+                (true, SocketAddr::V6(_)) => IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+            };
+            if self.bind.port.is_some() {
+                // Probes to different targets share the source port
+                socket.set_reuseaddr(true)?;
+            }
+            socket.bind(SocketAddr::new(ip, self.bind.port.unwrap_or_default()))?;
+        }
+        Ok(socket)
+    }
+
+    fn print_open_port(&self, open_port: &OpenPort) {
+        let OpenPort {
+            host,
+            port,
+            service,
+            category,
+            hostname,
+            ..
+        } = open_port;
+        let max_addr_len = self.max_addr_len;
+        let max_port_len = self.max_port_len;
+        let max_name_len = self.max_name_len;
+        match &self.reverse_resolver {
+            Some(_) => {
+                let hostname = hostname.as_deref().unwrap_or_default();
+                let max_category_len = AddrCategory::MAX_WIDTH;
+                self.println(&format!(
+                    "{host:max_addr_len$} {port:<max_port_len$} {service:max_name_len$} {category:max_category_len$} {hostname}"
+                ));
+            }
+            None => self.println(&format!(
+                "{host:max_addr_len$} {port:<max_port_len$} {service:max_name_len$} {category}"
+            )),
+        }
+    }
+
+    fn println(&self, line: &str) {
+        match &self.progress {
+            Some(progress) => progress.println(line),
+            None => println!("{line}"),
+        }
+    }
+
+    /// Print a line on stderr without breaking the progress line
+    pub fn eprintln(&self, line: &str) {
+        self.printer()(line)
+    }
+
+    /// Same as `eprintln`, for tasks that outlive the borrow of the scanner
+    fn printer(&self) -> impl Fn(&str) + Clone + Send + 'static {
+        let progress = self.progress.clone();
+        move |line: &str| match &progress {
+            Some(progress) => progress.eprintln(line),
+            None => eprintln!("{line}"),
+        }
+    }
+}
+
+/// Aborts the task when dropped, so it does not outlive the scan
+struct AbortOnDrop(JoinHandle<()>);
+
+impl Drop for AbortOnDrop {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+/// Wait for SIGINT, or SIGTERM on Unix
+async fn wait_for_signal() {
+    let ctrl_c = async {
+        if tokio::signal::ctrl_c().await.is_err() {
+            future::pending::<()>().await
+        }
+    };
+    #[cfg(unix)]
+    let terminate = async {
+        use tokio::signal::unix::{signal, SignalKind};
+        match signal(SignalKind::terminate()) {
+            Ok(mut terminate) => {
+                terminate.recv().await;
+            }
+            Err(_) => future::pending().await,
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = future::pending::<()>();
+    pin_mut!(ctrl_c, terminate);
+    future::select(ctrl_c, terminate).await;
+}
+
+/// Restore the default actions of the signals handled by `wait_for_signal`
+fn restore_default_signals() {
+    #[cfg(unix)]
+    // SAFETY: the default actions replace the handlers of tokio, which are no longer waited on
+    unsafe {
+        libc::signal(libc::SIGINT, libc::SIG_DFL);
+        libc::signal(libc::SIGTERM, libc::SIG_DFL);
+    }
+}
+
+fn port_name(port: u16) -> &'static str {
+    ports::port_name(port).unwrap_or("unknown")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::runtime;
+
+    fn scanner(bind: Bind) -> Scanner {
+        Scanner::new(vec![], Duration::from_millis(1000), 1, &[]).bind(bind)
+    }
+
+    #[test]
+    fn test_probe_status() {
+        let runtime = runtime();
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let bind = Bind {
+            ips: vec!["127.0.0.1".parse().unwrap()],
+            ..Default::default()
+        };
+        let scanner = scanner(bind);
+        assert!(matches!(
+            runtime.block_on(scanner.probe(Destination::Addr(addr))),
+            Status::Open
+        ));
+        drop(listener);
+        assert!(matches!(
+            runtime.block_on(scanner.probe(Destination::Addr(addr))),
+            Status::Closed
+        ));
+    }
+
+    #[test]
+    fn test_interrupt() {
+        let runtime = runtime();
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let open = listener.local_addr().unwrap().port();
+        let closed = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let path = std::env::temp_dir().join(format!("opscan-interrupt-{}", std::process::id()));
+        let new_scanner = |checkpoint| {
+            Scanner::new(vec![open, closed], Duration::from_millis(1000), 1, &[])
+                .checkpoint_on_interrupt(path.clone(), checkpoint)
+                .quiet()
+        };
+        let hosts = || {
+            [
+                Slot::Host(Host::fixture("127.0.0.1", "a")),
+                Slot::Host(Host::fixture("127.0.0.1", "b")),
+            ]
+        };
+
+        // Interrupt once the ports of `a` are probed, when the scan asks for the next host
+        let (interrupt_tx, interrupt_rx) = oneshot::channel();
+        let [a, b] = hosts();
+        let slots = stream::iter([a]).chain(stream::once(async move {
+            interrupt_tx.send(()).unwrap();
+            b
+        }));
+        let scanner = new_scanner(Checkpoint::default()).interrupt_on(async {
+            let _ = interrupt_rx.await;
+        });
+        let summary = runtime.block_on(scanner.run(slots));
+        assert!(scanner.interrupted());
+        assert_eq!((summary.open, summary.closed), (1, 1));
+        let checkpoint = Checkpoint::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(checkpoint.done_count(), 2);
+        assert_eq!(checkpoint.open_ports[0].host, "a");
+
+        // Resume with the port closed, `a` would be found closed if it were probed again
+        drop(listener);
+        let scanner = new_scanner(checkpoint);
+        let summary = runtime.block_on(scanner.run(stream::iter(hosts())));
+        assert!(!scanner.interrupted());
+        assert_eq!((summary.open, summary.closed), (1, 3));
+        assert_eq!(scanner.open_ports()[0].host, "a");
+        // Only saved when interrupted
+        assert!(!path.exists());
     }
 
-    pub async fn connect_addr(&self, addr: &SocketAddr) -> tokio::io::Result<TcpStream> {
-        tokio::time::timeout(self.timeout, async move { TcpStream::connect(addr).await }).await?
+    #[test]
+    fn test_source_family() {
+        let bind = Bind {
+            ips: vec!["::1".parse().unwrap()],
+            ..Default::default()
+        };
+        let err = scanner(bind)
+            .new_socket(&"127.0.0.1:80".parse().unwrap())
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
     }
 }
@@ -0,0 +1,145 @@
+use std::{collections::BTreeMap, fmt, io, time::Duration};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::progress::format_duration;
+
+/// Totals of a finished scan
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Summary {
+    pub hosts: usize,
+    /// Hosts that accepted or refused at least one connection
+    pub hosts_up: usize,
+    pub open: u64,
+    pub closed: u64,
+    pub filtered: u64,
+    /// Probes retried because local ports were exhausted
+    pub retries: u64,
+    /// Failed probes by error name
+    pub errors: BTreeMap<String, u64>,
+    /// In seconds
+    #[serde(
+        serialize_with = "serialize_secs",
+        deserialize_with = "deserialize_secs"
+    )]
+    pub elapsed: Duration,
+}
+
+fn serialize_secs<S: Serializer>(duration: &Duration, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.serialize_f64(duration.as_secs_f64())
+}
+
+fn deserialize_secs<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Duration, D::Error> {
+    let secs = f64::deserialize(deserializer)?;
+    Duration::try_from_secs_f64(secs).map_err(serde::de::Error::custom)
+}
+
+impl Summary {
+    pub fn probes(&self) -> u64 {
+        self.open + self.closed + self.filtered + self.errors.values().sum::<u64>()
+    }
+
+    /// Probes per second
+    pub fn rate(&self) -> f64 {
+        self.probes() as f64 / self.elapsed.as_secs_f64().max(0.001)
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Scanned {} hosts ({} up) in {}, {} probes at {:.0}/s",
+            self.hosts,
+            self.hosts_up,
+            format_duration(self.elapsed),
+            self.probes(),
+            self.rate()
+        )?;
+        write!(
+            f,
+            "Ports: {} open, {} closed, {} filtered",
+            self.open, self.closed, self.filtered
+        )?;
+        if self.retries > 0 {
+            write!(f, "\nRetries: {}", self.retries)?;
+        }
+        if !self.errors.is_empty() {
+            let errors = self
+                .errors
+                .iter()
+                .map(|(name, count)| format!("{name} {count}"))
+                .collect::<Vec<_>>()
+                .join(", ");
+            write!(f, "\nErrors: {errors}")?;
+        }
+        Ok(())
+    }
+}
+
+/// Name of an error for grouping, e.g. `EMFILE`
+pub fn error_name(err: &io::Error) -> String {
+    #[cfg(unix)]
+    if let Some(name) = err.raw_os_error().and_then(errno_name) {
+        return name.to_string();
+    }
+    match err.kind() {
+        io::ErrorKind::AddrNotAvailable => "EADDRNOTAVAIL".to_string(),
+        io::ErrorKind::AddrInUse => "EADDRINUSE".to_string(),
+        io::ErrorKind::PermissionDenied => "EACCES".to_string(),
+        io::ErrorKind::ConnectionReset => "ECONNRESET".to_string(),
+        kind => format!("{kind:?}"),
+    }
+}
+
+#[cfg(unix)]
+fn errno_name(code: i32) -> Option<&'static str> {
+    let name = match code {
+        libc::EMFILE => "EMFILE",
+        libc::ENFILE => "ENFILE",
+        libc::EADDRNOTAVAIL => "EADDRNOTAVAIL",
+        libc::EADDRINUSE => "EADDRINUSE",
+        libc::EACCES => "EACCES",
+        libc::EPERM => "EPERM",
+        libc::ECONNRESET => "ECONNRESET",
+        libc::ENOBUFS => "ENOBUFS",
+        libc::ENOMEM => "ENOMEM",
+        _ => return None,
+    };
+    Some(name)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summary() {
+        let mut summary = Summary {
+            hosts: 4,
+            hosts_up: 2,
+            open: 3,
+            closed: 90,
+            filtered: 5,
+            elapsed: Duration::from_secs(2),
+            ..Default::default()
+        };
+        assert_eq!(
+            summary.to_string(),
+            "Scanned 4 hosts (2 up) in 00:02, 98 probes at 49/s\nPorts: 3 open, 90 closed, 5 filtered"
+        );
+        summary.retries = 7;
+        summary.errors.insert("EMFILE".into(), 2);
+        summary.errors.insert("EADDRNOTAVAIL".into(), 1);
+        assert!(summary
+            .to_string()
+            .ends_with("\nRetries: 7\nErrors: EADDRNOTAVAIL 1, EMFILE 2"));
+        let err = io::Error::from(io::ErrorKind::AddrNotAvailable);
+        assert_eq!(error_name(&err), "EADDRNOTAVAIL");
+        #[cfg(unix)]
+        assert_eq!(
+            error_name(&io::Error::from_raw_os_error(libc::EMFILE)),
+            "EMFILE"
+        );
+    }
+}
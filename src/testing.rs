@@ -0,0 +1,30 @@
+//! Helpers shared by tests
+
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    runtime::Runtime,
+};
+
+pub fn runtime() -> Runtime {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .unwrap()
+}
+
+/// Read a request until `complete` accepts it, then answer `reply`, returning the request
+pub async fn exchange<S: AsyncRead + AsyncWrite + Unpin>(
+    stream: &mut S,
+    complete: impl Fn(&[u8]) -> bool,
+    reply: &[u8],
+) -> Vec<u8> {
+    let mut request = vec![];
+    let mut buf = [0u8; 1024];
+    while !complete(&request) {
+        let n = stream.read(&mut buf).await.unwrap();
+        assert!(n > 0, "connection closed before the request completed");
+        request.extend_from_slice(&buf[..n]);
+    }
+    stream.write_all(reply).await.unwrap();
+    request
+}
@@ -0,0 +1,148 @@
+use std::{
+    collections::{hash_map::RandomState, HashSet},
+    hash::{BuildHasher, Hasher},
+    net::IpAddr,
+    time::{Duration, SystemTime},
+};
+
+use crate::{
+    baseline::{Baseline, Change, ChangeKind},
+    event::{format_time, Event, EventKind},
+    scanner::OpenPort,
+};
+
+/// Open ports and up hosts found by a round of watch
+#[derive(Debug)]
+pub struct State {
+    open_ports: Vec<OpenPort>,
+    up_hosts: HashSet<(String, IpAddr)>,
+}
+
+impl State {
+    pub fn new(open_ports: Vec<OpenPort>, up_hosts: HashSet<(String, IpAddr)>) -> Self {
+        Self {
+            open_ports,
+            up_hosts,
+        }
+    }
+
+    pub fn parts(&self) -> (&[OpenPort], &HashSet<(String, IpAddr)>) {
+        (&self.open_ports, &self.up_hosts)
+    }
+
+    /// Baseline to find the port changes of the next round
+    pub fn baseline(&self) -> Baseline {
+        Baseline::new(self.open_ports.clone())
+    }
+
+    /// Events from this round to the next one, whose port changes are `changes`
+    pub fn events(&self, next: &State, changes: Vec<Change>, time: SystemTime) -> Vec<Event> {
+        let time = format_time(time);
+        let host_event = |kind, (host, ip): &(String, IpAddr)| Event {
+            time: time.clone(),
+            kind,
+            host: host.clone(),
+            ip: *ip,
+            port: None,
+            service: None,
+            previous_service: None,
+        };
+        let mut appeared = next.up_hosts.difference(&self.up_hosts).collect::<Vec<_>>();
+        appeared.sort();
+        let mut disappeared = self.up_hosts.difference(&next.up_hosts).collect::<Vec<_>>();
+        disappeared.sort();
+        let port_events = changes.into_iter().map(|v| Event {
+            time: time.clone(),
+            kind: match v.kind {
+                ChangeKind::Opened => EventKind::PortOpened,
+                ChangeKind::Closed => EventKind::PortClosed,
+                ChangeKind::ServiceChanged => EventKind::ServiceChanged,
+            },
+            host: v.host,
+            ip: v.ip,
+            port: Some(v.port),
+            service: Some(v.service),
+            previous_service: v.previous_service,
+        });
+        appeared
+            .into_iter()
+            .map(|v| host_event(EventKind::HostUp, v))
+            .chain(port_events)
+            .chain(
+                disappeared
+                    .into_iter()
+                    .map(|v| host_event(EventKind::HostDown, v)),
+            )
+            .collect()
+    }
+}
+
+/// Random delay of up to `max`
+pub fn jitter(max: Duration) -> Duration {
+    if max.is_zero() {
+        return max;
+    }
+    let random = RandomState::new().build_hasher().finish();
+    Duration::from_millis(random % (max.as_millis() as u64 + 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{addresses::Host, scanner::Status};
+    use std::time::UNIX_EPOCH;
+
+    fn open_port(host: &str, ip: &str, port: u16, service: &str) -> OpenPort {
+        OpenPort {
+            host: host.to_string(),
+            ip: ip.parse().unwrap(),
+            port,
+            service: service.to_string(),
+            category: "private".to_string(),
+            hostname: None,
+        }
+    }
+
+    #[test]
+    fn test_events() {
+        let up = |hosts: &[(&str, &str)]| {
+            hosts
+                .iter()
+                .map(|(host, ip)| (host.to_string(), ip.parse().unwrap()))
+                .collect::<HashSet<_>>()
+        };
+        let previous = State::new(
+            vec![open_port("web1", "10.0.0.5", 22, "ssh")],
+            up(&[("web1", "10.0.0.5"), ("web2", "10.0.0.6")]),
+        );
+        let baseline = previous.baseline();
+        let web1 = Host::fixture("10.0.0.5", "web1");
+        baseline.record(&web1, 22, &Status::Closed, "ssh");
+        baseline.record(&web1, 443, &Status::Open, "https");
+        let next = State::new(
+            vec![open_port("web1", "10.0.0.5", 443, "https")],
+            up(&[("web1", "10.0.0.5"), ("db1", "10.0.0.7")]),
+        );
+        let time = UNIX_EPOCH + Duration::from_secs(1675240200);
+        let events = previous
+            .events(&next, baseline.changes(), time)
+            .iter()
+            .map(|v| v.to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            events,
+            [
+                "2023-02-01T08:30:00Z host-up db1 10.0.0.7",
+                "2023-02-01T08:30:00Z port-closed web1 22 ssh",
+                "2023-02-01T08:30:00Z port-opened web1 443 https",
+                "2023-02-01T08:30:00Z host-down web2 10.0.0.6",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_jitter() {
+        assert!(jitter(Duration::from_secs(2)) <= Duration::from_secs(2));
+        assert_eq!(jitter(Duration::ZERO), Duration::ZERO);
+    }
+}